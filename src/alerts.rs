@@ -0,0 +1,113 @@
+use std::fmt;
+use std::sync::atomic::Ordering;
+
+use chrono::{DateTime, Local};
+
+use crate::state::AppState;
+
+/// Ticks the footer stays red after an alert fires
+pub const ALERT_FLASH_TICKS: u64 = 10;
+/// An active alert clears once its value falls below this fraction of the threshold,
+/// so a value hovering around the threshold doesn't fire repeatedly
+const ALERT_CLEAR_RATIO: f64 = 0.9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertKind {
+    Latency,
+    SkipRate,
+}
+
+impl fmt::Display for AlertKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlertKind::Latency => write!(f, "Slot arrival delay"),
+            AlertKind::SkipRate => write!(f, "Skip rate"),
+        }
+    }
+}
+
+/// A threshold currently being exceeded
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub message: String,
+    pub raised_at: DateTime<Local>,
+}
+
+/// Limits set with `--alert-latency-ms` and `--alert-skip-pct`; `None` disables the check
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlertThresholds {
+    pub latency_ms: Option<f64>,
+    pub skip_pct: Option<f64>,
+}
+
+/// Run once per tick: compare the watched metrics against their thresholds, raising and clearing alerts
+pub fn check_alerts(state: &AppState) {
+    let _ = state.alert_flash_ticks.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |t| t.checked_sub(1));
+
+    let thresholds = state.alert_thresholds;
+
+    if let Some(limit) = thresholds.latency_ms {
+        let value = state.latency_stats.avg_latency_ms();
+        evaluate(state, AlertKind::Latency, value, limit, format!("{:+.1} ms (limit {} ms)", value, limit));
+    }
+    if let Some(limit) = thresholds.skip_pct {
+        let value = state.leader_tracker.overall_skip_rate();
+        evaluate(state, AlertKind::SkipRate, value, limit, format!("{:.1}% (limit {}%)", value, limit));
+    }
+}
+
+fn evaluate(state: &AppState, kind: AlertKind, value: f64, limit: f64, detail: String) {
+    let mut active = state.active_alerts.write();
+    let existing = active.iter().position(|a| a.kind == kind);
+
+    match existing {
+        None if value > limit => {
+            let message = format!("{} {}", kind, detail);
+            active.push(Alert { kind, message: message.clone(), raised_at: Local::now() });
+            drop(active);
+            state.alert_flash_ticks.store(ALERT_FLASH_TICKS, Ordering::Relaxed);
+            state.log_warn(format!("Alert: {}", message));
+        }
+        Some(i) if value < limit * ALERT_CLEAR_RATIO => {
+            active.remove(i);
+            drop(active);
+            state.log_info(format!("Alert cleared: {} {}", kind, detail));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed `value` through `evaluate` against a limit of 100; returns whether the alert is active
+    fn tick(state: &AppState, value: f64) -> bool {
+        evaluate(state, AlertKind::Latency, value, 100.0, format!("{}", value));
+        state.active_alerts.read().iter().any(|a| a.kind == AlertKind::Latency)
+    }
+
+    fn raised(state: &AppState) -> usize {
+        state.logs.read().iter().filter(|log| log.message.starts_with("Alert: ")).count()
+    }
+
+    #[test]
+    fn alerts_fire_once_per_crossing_with_hysteresis() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        assert!(!tick(&state, 100.0));
+        assert!(tick(&state, 120.0));
+        assert_eq!(raised(&state), 1);
+        assert_eq!(state.alert_flash_ticks.load(Ordering::Relaxed), ALERT_FLASH_TICKS);
+
+        // Staying above, or dipping just under the limit, doesn't fire again or clear
+        assert!(tick(&state, 150.0));
+        assert!(tick(&state, 95.0));
+        assert_eq!(raised(&state), 1);
+
+        assert!(!tick(&state, 80.0));
+        assert!(tick(&state, 110.0));
+        assert_eq!(raised(&state), 2);
+        assert_eq!(state.active_alerts.read().len(), 1);
+    }
+}
@@ -0,0 +1,486 @@
+use solana_sdk::{instruction::CompiledInstruction, message::VersionedMessage, pubkey::Pubkey, transaction::VersionedTransaction};
+
+use crate::programs::KnownPrograms;
+
+const TOKEN_PROGRAM_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::TOKEN_PROGRAM);
+const TOKEN_2022_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::TOKEN_2022);
+const ASSOCIATED_TOKEN_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::ASSOCIATED_TOKEN);
+const NATIVE_MINT_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::NATIVE_MINT);
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::SYSTEM_PROGRAM);
+const COMPUTE_BUDGET_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::COMPUTE_BUDGET);
+const VOTE_PROGRAM_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::VOTE_PROGRAM);
+
+// SPL Token instruction tags
+const TOKEN_IX_INITIALIZE_ACCOUNT: u8 = 1;
+const TOKEN_IX_CLOSE_ACCOUNT: u8 = 9;
+const TOKEN_IX_TRANSFER_CHECKED: u8 = 12;
+const TOKEN_IX_INITIALIZE_ACCOUNT_2: u8 = 16;
+const TOKEN_IX_SYNC_NATIVE: u8 = 17;
+const TOKEN_IX_INITIALIZE_ACCOUNT_3: u8 = 18;
+
+// Associated Token Account instruction tags; an empty payload is also a Create
+const ATA_IX_CREATE: u8 = 0;
+const ATA_IX_CREATE_IDEMPOTENT: u8 = 1;
+
+// System program instructions are tagged with a little-endian u32
+const SYSTEM_IX_TRANSFER: [u8; 4] = [2, 0, 0, 0];
+// Compute Budget instruction tags
+const COMPUTE_IX_SET_UNIT_LIMIT: u8 = 2;
+const COMPUTE_IX_SET_UNIT_PRICE: u8 = 3;
+
+// Compute unit limits the runtime applies when a transaction doesn't set one
+const DEFAULT_UNITS_PER_INSTRUCTION: u32 = 200_000;
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+// ============================================================================
+// Invoked Programs
+// ============================================================================
+
+/// Programs called by a transaction's top-level instructions, without duplicates.
+///
+/// A program that only appears in the account keys (e.g. passed as an account
+/// to another program) is not included. CPI calls aren't visible in shreds.
+pub fn invoked_programs(txn: &VersionedTransaction) -> Vec<Pubkey> {
+    let keys = txn.message.static_account_keys();
+    let mut programs: Vec<Pubkey> = Vec::new();
+    for ix in txn.message.instructions() {
+        if let Some(program) = keys.get(ix.program_id_index as usize) {
+            if !programs.contains(program) {
+                programs.push(*program);
+            }
+        }
+    }
+    programs
+}
+
+// ============================================================================
+// Address Lookup Tables
+// ============================================================================
+
+/// Accounts a v0 transaction loads through address lookup tables
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LookupTableUsage {
+    pub tables: usize,
+    pub writable: usize,
+    pub readonly: usize,
+}
+
+impl LookupTableUsage {
+    pub fn accounts(&self) -> usize {
+        self.writable + self.readonly
+    }
+}
+
+/// Lookup table references in a v0 message, or `None` for legacy messages and
+/// v0 messages without any.
+///
+/// The addresses themselves live on-chain, so these accounts can't be matched
+/// against known programs or the monitored wallet. Program IDs are always
+/// static keys, so instruction-level program detection is unaffected.
+pub fn lookup_table_usage(message: &VersionedMessage) -> Option<LookupTableUsage> {
+    let lookups = message.address_table_lookups()?;
+    if lookups.is_empty() {
+        return None;
+    }
+    Some(LookupTableUsage {
+        tables: lookups.len(),
+        writable: lookups.iter().map(|l| l.writable_indexes.len()).sum(),
+        readonly: lookups.iter().map(|l| l.readonly_indexes.len()).sum(),
+    })
+}
+
+/// Static account keys the message may write to.
+///
+/// Uses the header's split of signed/unsigned and writable/read-only keys. Keys invoked as
+/// programs are left out, since the runtime loads them read-only. Writable accounts loaded
+/// from lookup tables can't be resolved, so they aren't included.
+pub fn writable_accounts(message: &VersionedMessage) -> Vec<Pubkey> {
+    let header = message.header();
+    let keys = message.static_account_keys();
+    let signed = header.num_required_signatures as usize;
+    let writable_signed = signed.saturating_sub(header.num_readonly_signed_accounts as usize);
+    let writable_unsigned = keys.len().saturating_sub(header.num_readonly_unsigned_accounts as usize);
+    let programs: Vec<usize> = message.instructions().iter().map(|ix| ix.program_id_index as usize).collect();
+
+    keys.iter()
+        .enumerate()
+        .filter(|(i, _)| *i < writable_signed || (signed..writable_unsigned).contains(i))
+        .filter(|(i, _)| !programs.contains(i))
+        .map(|(_, key)| *key)
+        .collect()
+}
+
+// ============================================================================
+// Wrapped SOL
+// ============================================================================
+
+/// wSOL wrap/unwrap activity found in a transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WsolActivity {
+    pub wrap: bool,
+    pub unwrap: bool,
+}
+
+/// Detect wSOL wrap/unwrap from Token and Associated Token Account instructions.
+///
+/// Heuristic: a wrap is a `SyncNative`, an ATA `Create`/`CreateIdempotent` for the native mint,
+/// or an `InitializeAccount*` whose mint is the native mint. An unwrap is a `CloseAccount` of a
+/// wSOL account, since `CloseAccount` itself doesn't carry the mint: an account set up for the
+/// native mint earlier in the transaction, or the closing owner's native-mint ATA.
+pub fn detect_wsol(message: &VersionedMessage) -> WsolActivity {
+    let keys = message.static_account_keys();
+    let account = |ix: &CompiledInstruction, position: usize| {
+        ix.accounts.get(position).and_then(|&index| keys.get(index as usize))
+    };
+
+    let mut activity = WsolActivity::default();
+    let mut wsol_accounts: Vec<&Pubkey> = Vec::new();
+    for ix in message.instructions() {
+        match keys.get(ix.program_id_index as usize) {
+            // The new account is second and the mint fourth for both Create variants
+            Some(program) if *program == ASSOCIATED_TOKEN_ID => {
+                let create = matches!(ix.data.first(), None | Some(&ATA_IX_CREATE) | Some(&ATA_IX_CREATE_IDEMPOTENT));
+                if create && account(ix, 3) == Some(&NATIVE_MINT_ID) {
+                    activity.wrap = true;
+                    wsol_accounts.extend(account(ix, 1));
+                }
+            }
+            Some(program) if *program == TOKEN_PROGRAM_ID => match ix.data.first() {
+                // SyncNative names only the token account, so it counts without the mint
+                Some(&TOKEN_IX_SYNC_NATIVE) => activity.wrap = true,
+                // The mint is the second account for every InitializeAccount variant
+                Some(&TOKEN_IX_INITIALIZE_ACCOUNT)
+                | Some(&TOKEN_IX_INITIALIZE_ACCOUNT_2)
+                | Some(&TOKEN_IX_INITIALIZE_ACCOUNT_3)
+                    if account(ix, 1) == Some(&NATIVE_MINT_ID) =>
+                {
+                    activity.wrap = true;
+                    wsol_accounts.extend(account(ix, 0));
+                }
+                // The closed account is first and its owner third
+                Some(&TOKEN_IX_CLOSE_ACCOUNT) => {
+                    if let (Some(closed), Some(owner)) = (account(ix, 0), account(ix, 2)) {
+                        if wsol_accounts.contains(&closed) || *closed == native_mint_ata(owner) {
+                            activity.unwrap = true;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    activity
+}
+
+/// `owner`'s Associated Token Account for the native mint
+fn native_mint_ata(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), NATIVE_MINT_ID.as_ref()],
+        &ASSOCIATED_TOKEN_ID,
+    )
+    .0
+}
+
+// ============================================================================
+// Jito Tips
+// ============================================================================
+
+/// Lamports sent to any of `tip_accounts` by top-level System transfers
+pub fn tip_lamports(message: &VersionedMessage, tip_accounts: &[Pubkey]) -> u64 {
+    let keys = message.static_account_keys();
+    message
+        .instructions()
+        .iter()
+        .filter(|ix| keys.get(ix.program_id_index as usize) == Some(&SYSTEM_PROGRAM_ID))
+        .filter(|ix| ix.data.starts_with(&SYSTEM_IX_TRANSFER))
+        .filter(|ix| {
+            ix.accounts
+                .get(1)
+                .and_then(|&to| keys.get(to as usize))
+                .is_some_and(|to| tip_accounts.contains(to))
+        })
+        .filter_map(|ix| ix.data.get(4..12))
+        .map(|lamports| u64::from_le_bytes(lamports.try_into().unwrap()))
+        .sum()
+}
+
+// ============================================================================
+// Token Mints
+// ============================================================================
+
+/// Token mints a transaction names in instructions with a fixed mint position, without duplicates.
+///
+/// Heuristic: covers Associated Token Account creation (the usual way a swap sets up its output
+/// account), Token `InitializeAccount*` and `TransferChecked`, plus the native mint wherever it
+/// appears. Mints passed straight to a DEX program aren't recognized, since every DEX orders
+/// its accounts differently, and mints loaded from lookup tables can't be resolved.
+pub fn referenced_mints(message: &VersionedMessage) -> Vec<Pubkey> {
+    let keys = message.static_account_keys();
+    let mut mints: Vec<Pubkey> = Vec::new();
+    if keys.contains(&NATIVE_MINT_ID) {
+        mints.push(NATIVE_MINT_ID);
+    }
+
+    for ix in message.instructions() {
+        let mint_position = match keys.get(ix.program_id_index as usize) {
+            Some(program) if *program == ASSOCIATED_TOKEN_ID => match ix.data.first() {
+                None | Some(&ATA_IX_CREATE) | Some(&ATA_IX_CREATE_IDEMPOTENT) => 3,
+                _ => continue,
+            },
+            Some(program) if *program == TOKEN_PROGRAM_ID || *program == TOKEN_2022_ID => match ix.data.first() {
+                Some(&TOKEN_IX_INITIALIZE_ACCOUNT)
+                | Some(&TOKEN_IX_INITIALIZE_ACCOUNT_2)
+                | Some(&TOKEN_IX_INITIALIZE_ACCOUNT_3)
+                | Some(&TOKEN_IX_TRANSFER_CHECKED) => 1,
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        let mint = ix.accounts.get(mint_position).and_then(|&index| keys.get(index as usize));
+        if let Some(mint) = mint {
+            if !mints.contains(mint) {
+                mints.push(*mint);
+            }
+        }
+    }
+
+    mints
+}
+
+// ============================================================================
+// Compute Budget
+// ============================================================================
+
+/// Compute unit limit and price a transaction requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComputeBudget {
+    /// From `SetComputeUnitLimit`, or the runtime default for the instruction count
+    pub unit_limit: u32,
+    /// From `SetComputeUnitPrice` in micro-lamports per unit; zero if not set
+    pub unit_price: u64,
+}
+
+impl ComputeBudget {
+    /// Priority fee in lamports, charged on the requested limit rather than the units used
+    pub fn priority_fee_lamports(&self) -> u64 {
+        let micro_lamports = self.unit_price as u128 * self.unit_limit as u128;
+        micro_lamports.div_ceil(MICRO_LAMPORTS_PER_LAMPORT) as u64
+    }
+}
+
+/// Read the `SetComputeUnitLimit` and `SetComputeUnitPrice` instructions of a message.
+/// Malformed Compute Budget instructions are ignored, as are other Compute Budget instructions.
+pub fn compute_budget(message: &VersionedMessage) -> ComputeBudget {
+    let keys = message.static_account_keys();
+    let mut unit_limit = None;
+    let mut unit_price = 0;
+    let mut other_instructions: u32 = 0;
+
+    for ix in message.instructions() {
+        if keys.get(ix.program_id_index as usize) != Some(&COMPUTE_BUDGET_ID) {
+            other_instructions += 1;
+            continue;
+        }
+        match ix.data.split_first() {
+            Some((&COMPUTE_IX_SET_UNIT_LIMIT, rest)) => {
+                if let Some(bytes) = rest.get(..4) {
+                    unit_limit = Some(u32::from_le_bytes(bytes.try_into().unwrap()));
+                }
+            }
+            Some((&COMPUTE_IX_SET_UNIT_PRICE, rest)) => {
+                if let Some(bytes) = rest.get(..8) {
+                    unit_price = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let unit_limit = unit_limit
+        .unwrap_or_else(|| other_instructions.saturating_mul(DEFAULT_UNITS_PER_INSTRUCTION))
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+    ComputeBudget { unit_limit, unit_price }
+}
+
+// ============================================================================
+// Vote Transactions
+// ============================================================================
+
+/// True if every instruction in the message invokes the Vote program
+pub fn is_vote(message: &VersionedMessage) -> bool {
+    let keys = message.static_account_keys();
+    let instructions = message.instructions();
+
+    !instructions.is_empty()
+        && instructions
+            .iter()
+            .all(|ix| keys.get(ix.program_id_index as usize) == Some(&VOTE_PROGRAM_ID))
+}
+
+// ============================================================================
+// Probe Transactions
+// ============================================================================
+
+/// Guess whether a transaction is a probe that does no real work.
+///
+/// Heuristic only: shreds carry landed transactions, but some bots land cheap
+/// ones to test inclusion. Flags a transaction as a probe if it has only
+/// Compute Budget instructions, sets a compute unit limit of zero, or contains
+/// a System transfer from an account to itself. Legitimate transactions can
+/// occasionally match, so counts should be read as an estimate.
+pub fn is_probe(message: &VersionedMessage) -> bool {
+    let keys = message.static_account_keys();
+    let instructions = message.instructions();
+
+    let only_compute_budget = instructions
+        .iter()
+        .all(|ix| keys.get(ix.program_id_index as usize) == Some(&COMPUTE_BUDGET_ID));
+    if only_compute_budget {
+        return true;
+    }
+
+    instructions.iter().any(|ix| match keys.get(ix.program_id_index as usize) {
+        Some(program) if *program == COMPUTE_BUDGET_ID => {
+            ix.data.first() == Some(&COMPUTE_IX_SET_UNIT_LIMIT) && ix.data.get(1..5) == Some(&[0, 0, 0, 0][..])
+        }
+        Some(program) if *program == SYSTEM_PROGRAM_ID => {
+            ix.data.starts_with(&SYSTEM_IX_TRANSFER)
+                && ix.accounts.len() >= 2
+                && ix.accounts[0] == ix.accounts[1]
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::message::{Message, MessageHeader};
+
+    const RAYDIUM_V4_ID: Pubkey = Pubkey::from_str_const(KnownPrograms::RAYDIUM_V4);
+
+    /// A legacy message from a fresh fee payer at key index 0, followed by `keys`. Instructions
+    /// are (program key index, account key indexes, data).
+    fn message(keys: &[Pubkey], instructions: &[(u8, &[u8], &[u8])]) -> VersionedMessage {
+        let mut account_keys = vec![Pubkey::new_unique()];
+        account_keys.extend_from_slice(keys);
+        VersionedMessage::Legacy(Message {
+            header: MessageHeader { num_required_signatures: 1, num_readonly_signed_accounts: 0, num_readonly_unsigned_accounts: 0 },
+            account_keys,
+            recent_blockhash: Default::default(),
+            instructions: instructions.iter()
+                .map(|&(program_id_index, accounts, data)| CompiledInstruction {
+                    program_id_index,
+                    accounts: accounts.to_vec(),
+                    data: data.to_vec(),
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn only_all_vote_transactions_are_votes() {
+        let vote_account = Pubkey::new_unique();
+        let vote = message(&[vote_account, VOTE_PROGRAM_ID], &[(2, &[1, 0], &[12, 0, 0, 0])]);
+        let swap = message(&[Pubkey::new_unique(), RAYDIUM_V4_ID], &[(2, &[0, 1], &[9])]);
+        let mixed = message(&[vote_account, VOTE_PROGRAM_ID, RAYDIUM_V4_ID], &[(2, &[1, 0], &[12]), (3, &[0], &[9])]);
+
+        assert!(is_vote(&vote));
+        assert!(!is_vote(&swap));
+        assert!(!is_vote(&mixed));
+        assert!(!is_vote(&message(&[], &[])));
+    }
+
+    #[test]
+    fn compute_budget_instructions_are_decoded() {
+        let mut set_limit = vec![COMPUTE_IX_SET_UNIT_LIMIT];
+        set_limit.extend_from_slice(&300_000u32.to_le_bytes());
+        let mut set_price = vec![COMPUTE_IX_SET_UNIT_PRICE];
+        set_price.extend_from_slice(&25_000u64.to_le_bytes());
+        let budgeted = message(
+            &[COMPUTE_BUDGET_ID, RAYDIUM_V4_ID],
+            &[(1, &[], &set_limit), (1, &[], &set_price), (2, &[0], &[9])],
+        );
+
+        let budget = compute_budget(&budgeted);
+        assert_eq!((budget.unit_limit, budget.unit_price), (300_000, 25_000));
+        // 25,000 micro-lamports on each of 300,000 units
+        assert_eq!(budget.priority_fee_lamports(), 7_500);
+
+        // Without a limit the runtime default per instruction applies, and a truncated price is ignored
+        let defaulted = message(&[COMPUTE_BUDGET_ID, RAYDIUM_V4_ID], &[(1, &[], &set_price[..5]), (2, &[0], &[9]), (2, &[0], &[9])]);
+        let budget = compute_budget(&defaulted);
+        assert_eq!((budget.unit_limit, budget.unit_price), (2 * DEFAULT_UNITS_PER_INSTRUCTION, 0));
+        assert_eq!(budget.priority_fee_lamports(), 0);
+    }
+
+    #[test]
+    fn only_writable_non_program_keys_are_writable_accounts() {
+        let (signer, writable, readonly) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut msg = message(&[signer, writable, RAYDIUM_V4_ID, readonly], &[(3, &[0, 1, 2, 4], &[9])]);
+        if let VersionedMessage::Legacy(legacy) = &mut msg {
+            // Payer and `signer` sign, `signer` read-only; the last two unsigned keys are read-only
+            legacy.header = MessageHeader { num_required_signatures: 2, num_readonly_signed_accounts: 1, num_readonly_unsigned_accounts: 2 };
+        }
+        let payer = msg.static_account_keys()[0];
+
+        assert_eq!(writable_accounts(&msg), vec![payer, writable]);
+    }
+
+    #[test]
+    fn wsol_wrap_and_unwrap_are_detected() {
+        let (owner, account, other_mint) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let ata = native_mint_ata(&owner);
+        // 1 owner, 2 account, 3 ata, 4 native mint, 5 other mint, 6 Token, 7 ATA program, 8 System
+        let keys = [owner, account, ata, NATIVE_MINT_ID, other_mint, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_ID, SYSTEM_PROGRAM_ID];
+        let detect = |instructions: &[(u8, &[u8], &[u8])]| {
+            let activity = detect_wsol(&message(&keys, instructions));
+            (activity.wrap, activity.unwrap)
+        };
+        let mut transfer = SYSTEM_IX_TRANSFER.to_vec();
+        transfer.extend_from_slice(&1_000_000u64.to_le_bytes());
+
+        // Topping up an existing wSOL account names no mint at all
+        let top_up = message(&[owner, account, TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID], &[(4, &[1, 2], &transfer), (3, &[2], &[TOKEN_IX_SYNC_NATIVE])]);
+        assert!(detect_wsol(&top_up).wrap);
+
+        assert_eq!(detect(&[(7, &[0, 3, 1, 4, 8, 6], &[ATA_IX_CREATE_IDEMPOTENT])]), (true, false));
+        assert_eq!(detect(&[(7, &[0, 2, 1, 5, 8, 6], &[])]), (false, false));
+        assert_eq!(detect(&[(6, &[2, 4, 1], &[TOKEN_IX_INITIALIZE_ACCOUNT_3])]), (true, false));
+        assert_eq!(detect(&[(6, &[2, 5, 1], &[TOKEN_IX_INITIALIZE_ACCOUNT])]), (false, false));
+
+        // Closing a wSOL account opened in the same transaction, or the owner's wSOL ATA
+        assert_eq!(detect(&[(6, &[2, 4, 1], &[TOKEN_IX_INITIALIZE_ACCOUNT_3]), (6, &[2, 1, 1], &[TOKEN_IX_CLOSE_ACCOUNT])]), (true, true));
+        assert_eq!(detect(&[(6, &[3, 1, 1], &[TOKEN_IX_CLOSE_ACCOUNT])]), (false, true));
+        // A swap closing a temporary account of another mint is not an unwrap, wherever the native mint appears
+        assert_eq!(detect(&[(6, &[2, 5, 1], &[TOKEN_IX_INITIALIZE_ACCOUNT_3]), (6, &[2, 1, 1], &[TOKEN_IX_CLOSE_ACCOUNT])]), (false, false));
+        assert_eq!(detect(&[(6, &[2, 1, 1], &[TOKEN_IX_CLOSE_ACCOUNT])]), (false, false));
+    }
+
+    #[test]
+    fn probes_are_flagged_and_real_work_is_not() {
+        let (from, to) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut zero_limit = vec![COMPUTE_IX_SET_UNIT_LIMIT];
+        zero_limit.extend_from_slice(&0u32.to_le_bytes());
+        let mut real_limit = vec![COMPUTE_IX_SET_UNIT_LIMIT];
+        real_limit.extend_from_slice(&200_000u32.to_le_bytes());
+        let mut transfer = SYSTEM_IX_TRANSFER.to_vec();
+        transfer.extend_from_slice(&5_000u64.to_le_bytes());
+
+        let budget_only = message(&[COMPUTE_BUDGET_ID], &[(1, &[], &real_limit)]);
+        let zero_limit_swap = message(&[COMPUTE_BUDGET_ID, RAYDIUM_V4_ID], &[(1, &[], &zero_limit), (2, &[0], &[9])]);
+        let self_transfer = message(&[from, SYSTEM_PROGRAM_ID], &[(2, &[1, 1], &transfer)]);
+        let transfer_out = message(&[from, to, SYSTEM_PROGRAM_ID], &[(3, &[1, 2], &transfer)]);
+        let budgeted_swap = message(&[COMPUTE_BUDGET_ID, RAYDIUM_V4_ID], &[(1, &[], &real_limit), (2, &[0], &[9])]);
+
+        assert!(is_probe(&budget_only));
+        assert!(is_probe(&zero_limit_swap));
+        assert!(is_probe(&self_transfer));
+        assert!(!is_probe(&transfer_out));
+        assert!(!is_probe(&budgeted_swap));
+    }
+}
@@ -1,20 +1,46 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
+use bincode::Options;
 use chrono::Local;
+use clap::ValueEnum;
 use jito_protos::shredstream::{
+    shredstream_client::ShredstreamClient as HeartbeatClient,
     shredstream_proxy_client::ShredstreamProxyClient,
-    SubscribeEntriesRequest,
+    Heartbeat, SubscribeEntriesRequest,
 };
+use rand::Rng;
 use solana_entry::entry::Entry;
+use solana_sdk::clock::Slot;
 use solana_sdk::pubkey::Pubkey;
 use tokio::sync::mpsc;
 use tokio_stream::StreamExt;
-use tonic::transport::Channel;
+use tokio_util::sync::CancellationToken;
+use tonic::transport::{Channel, ClientTlsConfig};
+use tonic::Streaming;
 
-use crate::programs::{JITO_TIP_ACCOUNTS, KnownPrograms};
-use crate::state::{AppState, BundleInfo, ConnectionState};
+use crate::analysis::{compute_budget, detect_wsol, invoked_programs, is_probe, is_vote, lookup_table_usage, referenced_mints, tip_lamports, writable_accounts};
+use crate::programs::{KnownPrograms, JITO_TIP_ACCOUNTS};
+use crate::recorder::{CsvRecorder, RecordRow};
+use crate::replay::{FileReplay, RawRecorder};
+use crate::state::{AppState, BundleInfo, BundleStatus, ConnectionState, DuplicateInfo, LeaderSlotInfo, TurbineInfo, TxnStatus};
+
+/// Reconnect backoff bounds
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// A connection that stays up this long resets the backoff
+const BACKOFF_RESET_AFTER: Duration = Duration::from_secs(10);
+/// Random jitter applied to each delay (±20%)
+const BACKOFF_JITTER: f64 = 0.2;
+
+/// Consecutive payloads the configured entry format must fail before the other format is tried
+const ENTRY_FORMAT_FALLBACK_FAILURES: u32 = 3;
+
+/// Arrival delay (ms after a slot was first seen) at which each estimated turbine layer ends
+const TURBINE_LAYER_DELAYS_MS: [u128; 3] = [20, 100, 250];
 
 /// Message types from the client to the main app
 #[derive(Debug, Clone)]
@@ -23,67 +49,637 @@ pub enum ClientMessage {
         slot: u64,
         entry_count: usize,
         txn_count: usize,
+        /// When the client queued the message, for measuring UI queueing delay
+        sent_at: Instant,
     },
     ConnectionChanged(ConnectionState),
     Error(String),
 }
 
+/// One message from the proxy: a slot and its entries, still bincode-encoded
+#[derive(Debug, Clone)]
+pub struct RawEntries {
+    pub slot: Slot,
+    pub entries: Vec<u8>,
+}
+
+/// Where entry batches come from: the proxy's gRPC stream, or a `--record-raw` file when replaying
+pub trait EntrySource {
+    /// The next batch, or `None` once the source has ended
+    fn next_batch(&mut self) -> impl Future<Output = Result<Option<RawEntries>>> + Send;
+}
+
+/// The stream stayed open but no entries arrived within the stall timeout
+#[derive(Debug)]
+struct StreamStalled(Duration);
+
+impl std::fmt::Display for StreamStalled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No entries received for {}s", self.0.as_secs())
+    }
+}
+
+impl std::error::Error for StreamStalled {}
+
+/// `next`, or a `StreamStalled` error if it takes longer than `limit`
+async fn within_stall_timeout<T>(limit: Option<Duration>, next: impl Future<Output = T>) -> Result<T> {
+    match limit {
+        Some(limit) => tokio::time::timeout(limit, next)
+            .await
+            .map_err(|_| StreamStalled(limit).into()),
+        None => Ok(next.await),
+    }
+}
+
+/// The live stream from `SubscribeEntries`
+struct GrpcEntries {
+    stream: Streaming<jito_protos::shredstream::Entry>,
+    /// Give up on the stream if it's silent this long
+    stall_timeout: Option<Duration>,
+}
+
+impl EntrySource for GrpcEntries {
+    async fn next_batch(&mut self) -> Result<Option<RawEntries>> {
+        match within_stall_timeout(self.stall_timeout, self.stream.next()).await? {
+            Some(Ok(entry)) => Ok(Some(RawEntries { slot: entry.slot, entries: entry.entries })),
+            Some(Err(e)) => Err(anyhow::anyhow!("Stream error: {}", e)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Options controlling how the client connects and processes entries
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Proxy endpoints in failover order
+    pub proxy_urls: Vec<String>,
+    /// Region label for each endpoint in `proxy_urls`
+    pub regions: Vec<Option<String>>,
+    /// TLS server name override for `https://` endpoints
+    pub tls_domain: Option<String>,
+    /// How long a signature is remembered for duplicate detection
+    pub dedup_window: Duration,
+    /// Derive turbine position from arrival order, since the proxy doesn't expose it
+    pub estimate_turbine: bool,
+    /// Parse Token program instructions for wSOL wrap/unwrap
+    pub detect_wsol: bool,
+    /// Per-slot and bundle rows are queued here when `--record-csv` is set
+    pub recorder: Option<CsvRecorder>,
+    /// Every received batch is queued here when `--record-raw` is set
+    pub raw_recorder: Option<RawRecorder>,
+    /// Reconnect when a connected stream sends nothing for this long
+    pub stall_timeout: Option<Duration>,
+    /// Payload layout tried first; the other is used if this one keeps failing
+    pub entry_format: EntryFormat,
+    /// Send a heartbeat RPC this often while connected
+    pub heartbeat_interval: Option<Duration>,
+    /// Stop retrying after this many failed attempts in a row, until the user asks again
+    pub max_reconnects: Option<u32>,
+}
+
+/// Heuristic turbine position estimator.
+///
+/// The proxy doesn't expose per-shred turbine data, so this uses the order and
+/// delay at which entry batches for a slot arrive relative to the first batch
+/// seen for that slot. Later batches are assumed to come from deeper layers.
+#[derive(Debug, Default)]
+struct TurbineEstimator {
+    /// slot -> (first seen, batches seen, entries seen)
+    slots: HashMap<u64, (Instant, u32, u32)>,
+}
+
+impl TurbineEstimator {
+    fn observe(&mut self, slot: u64, entry_count: u32) -> TurbineInfo {
+        let now = Instant::now();
+        let (first_seen, batches, entries) = self.slots.entry(slot).or_insert((now, 0, 0));
+
+        let delay_ms = now.duration_since(*first_seen).as_millis();
+        let layer = TURBINE_LAYER_DELAYS_MS
+            .iter()
+            .position(|&limit| delay_ms <= limit)
+            .unwrap_or(TURBINE_LAYER_DELAYS_MS.len()) as u32;
+
+        let info = TurbineInfo {
+            slot,
+            shred_index: *entries,
+            turbine_index: *batches,
+            layer,
+            timestamp: Local::now(),
+            source_ip: None,
+        };
+
+        *batches += 1;
+        *entries += entry_count;
+
+        // Keep only recent slots
+        if self.slots.len() > 64 {
+            self.slots.retain(|&s, _| s + 32 >= slot);
+        }
+
+        info
+    }
+}
+
+/// Exponential reconnect backoff, doubling from a base delay up to a cap
+#[derive(Debug, Clone)]
+struct Backoff {
+    base: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Backoff {
+    fn new(base: Duration, max: Duration) -> Self {
+        Self { base, max, current: base }
+    }
+
+    /// Return the current delay and double it for the next attempt
+    fn next_delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = (self.current * 2).min(self.max);
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.current = self.base;
+    }
+}
+
+/// Consecutive failed connection attempts, counted against `--max-reconnects`
+#[derive(Debug, Clone)]
+struct RetryBudget {
+    max: Option<u32>,
+    failures: u32,
+}
+
+impl RetryBudget {
+    fn new(max: Option<u32>) -> Self {
+        Self { max, failures: 0 }
+    }
+
+    /// Count one attempt; returns true once `max` attempts in a row have failed to connect
+    fn record(&mut self, connected: bool) -> bool {
+        if connected {
+            self.failures = 0;
+            return false;
+        }
+        self.failures += 1;
+        self.max.is_some_and(|max| self.failures >= max)
+    }
+
+    fn reset(&mut self) {
+        self.failures = 0;
+    }
+}
+
+/// Proxy endpoints tried in order, wrapping around after the last
+#[derive(Debug, Clone)]
+struct EndpointRotation {
+    urls: Vec<String>,
+    regions: Vec<Option<String>>,
+    index: usize,
+}
+
+impl EndpointRotation {
+    fn new(urls: Vec<String>, regions: Vec<Option<String>>) -> Self {
+        assert!(!urls.is_empty(), "at least one proxy endpoint is required");
+        assert_eq!(urls.len(), regions.len(), "one region entry per proxy endpoint");
+        Self { urls, regions, index: 0 }
+    }
+
+    fn current(&self) -> &str {
+        &self.urls[self.index]
+    }
+
+    fn current_region(&self) -> Option<&str> {
+        self.regions[self.index].as_deref()
+    }
+
+    fn len(&self) -> usize {
+        self.urls.len()
+    }
+
+    /// Move to the next endpoint; returns true if that wrapped back to the first
+    fn advance(&mut self) -> bool {
+        self.index = (self.index + 1) % self.urls.len();
+        self.index == 0
+    }
+
+    /// Pick the endpoint for the next attempt and return whether to back off first.
+    ///
+    /// An attempt that failed to connect moves on to the next endpoint straight away, backing off
+    /// only once every endpoint has failed in turn. One that connected stays on its endpoint, or
+    /// goes back to the first if the connection was stable.
+    fn next_attempt(&mut self, connected: bool, stable: bool) -> bool {
+        if !connected {
+            return self.advance();
+        }
+        if stable {
+            self.index = 0;
+        }
+        true
+    }
+}
+
+/// Jito block engine regions, as they appear in proxy host names
+const KNOWN_REGIONS: [&str; 8] = ["amsterdam", "dublin", "frankfurt", "london", "ny", "slc", "singapore", "tokyo"];
+
+/// Region named by a label in the URL's host, e.g. `ny` for `http://ny.shredstream.example.com:50051`
+fn region_from_url(url: &str) -> Option<String> {
+    let host = url.split("://").nth(1).unwrap_or(url);
+    let host = host.split(['/', ':']).next().unwrap_or(host);
+    host.split(['.', '-'])
+        .map(str::to_ascii_lowercase)
+        .find(|label| KNOWN_REGIONS.contains(&label.as_str()))
+}
+
+/// Region label for each endpoint: from `--region` when given (one label for all endpoints or
+/// one per endpoint), otherwise derived from the endpoint's host
+pub fn endpoint_regions(urls: &[String], labels: &[String]) -> Vec<Option<String>> {
+    urls.iter()
+        .enumerate()
+        .map(|(i, url)| match labels {
+            [] => region_from_url(url),
+            [label] => Some(label.clone()),
+            labels => labels.get(i).cloned(),
+        })
+        .collect()
+}
+
+fn with_jitter(delay: Duration) -> Duration {
+    let factor = rand::thread_rng().gen_range(1.0 - BACKOFF_JITTER..=1.0 + BACKOFF_JITTER);
+    delay.mul_f64(factor)
+}
+
+/// Bincode layout of an entries payload, selected with `--entry-format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum EntryFormat {
+    /// bincode 1 with fixed-width integers, as the proxy sends today
+    Legacy,
+    /// bincode 1's `DefaultOptions`: varint-encoded integers, trailing bytes rejected
+    Varint,
+}
+
+impl EntryFormat {
+    fn other(self) -> Self {
+        match self {
+            EntryFormat::Legacy => EntryFormat::Varint,
+            EntryFormat::Varint => EntryFormat::Legacy,
+        }
+    }
+}
+
+impl std::fmt::Display for EntryFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntryFormat::Legacy => write!(f, "legacy"),
+            EntryFormat::Varint => write!(f, "varint"),
+        }
+    }
+}
+
+/// Decode an entries payload in `format`, which is normally a `Vec<Entry>`.
+///
+/// In the legacy format a lone `Entry` that fills the whole payload is accepted too so a payload
+/// in that shape isn't dropped; the error from the `Vec` decode is returned if neither works.
+fn decode_entries(bytes: &[u8], format: EntryFormat) -> bincode::Result<Vec<Entry>> {
+    match format {
+        EntryFormat::Legacy => bincode::deserialize::<Vec<Entry>>(bytes)
+            .or_else(|e| {
+                bincode::DefaultOptions::new()
+                    .with_fixint_encoding()
+                    .reject_trailing_bytes()
+                    .deserialize::<Entry>(bytes)
+                    .map(|entry| vec![entry])
+                    .map_err(|_| e)
+            }),
+        EntryFormat::Varint => bincode::DefaultOptions::new().deserialize::<Vec<Entry>>(bytes),
+    }
+}
+
+/// Decodes payloads in the configured format, moving to the other format if the configured one
+/// keeps failing and the other one works
+#[derive(Debug)]
+struct EntryDecoder {
+    format: EntryFormat,
+    /// Consecutive payloads `format` failed to decode
+    failures: u32,
+    /// The format last reported by `newly_confirmed`
+    announced: Option<EntryFormat>,
+}
+
+impl EntryDecoder {
+    fn new(format: EntryFormat) -> Self {
+        Self { format, failures: 0, announced: None }
+    }
+
+    fn decode(&mut self, bytes: &[u8]) -> bincode::Result<Vec<Entry>> {
+        let error = match decode_entries(bytes, self.format) {
+            Ok(entries) => {
+                self.failures = 0;
+                return Ok(entries);
+            }
+            Err(e) => e,
+        };
+
+        self.failures += 1;
+        if self.failures < ENTRY_FORMAT_FALLBACK_FAILURES {
+            return Err(error);
+        }
+        let entries = decode_entries(bytes, self.format.other()).map_err(|_| error)?;
+        self.format = self.format.other();
+        self.failures = 0;
+        Ok(entries)
+    }
+
+    /// The format in use, if it has decoded a payload since it was last reported
+    fn newly_confirmed(&mut self) -> Option<EntryFormat> {
+        (self.announced != Some(self.format)).then(|| {
+            self.announced = Some(self.format);
+            self.format
+        })
+    }
+}
+
+/// Time-bounded set of recently seen signatures for duplicate detection
+#[derive(Debug)]
+struct SignatureDedup {
+    window: Duration,
+    order: VecDeque<(String, Instant)>,
+    /// Signature to the slot it was first seen in
+    seen: HashMap<String, Slot>,
+}
+
+impl SignatureDedup {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            order: VecDeque::new(),
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Record a signature, returning the slot it was first seen in if it was already seen within the window
+    fn check_and_insert(&mut self, sig: &str, slot: Slot, now: Instant) -> Option<Slot> {
+        self.evict(now);
+        if let Some(&first_slot) = self.seen.get(sig) {
+            return Some(first_slot);
+        }
+        self.seen.insert(sig.to_string(), slot);
+        self.order.push_back((sig.to_string(), now));
+        None
+    }
+
+    fn evict(&mut self, now: Instant) {
+        while let Some((_, seen_at)) = self.order.front() {
+            if now.duration_since(*seen_at) <= self.window {
+                break;
+            }
+            if let Some((sig, _)) = self.order.pop_front() {
+                self.seen.remove(&sig);
+            }
+        }
+    }
+}
+
 /// ShredStream client for connecting to the proxy's gRPC service
 pub struct ShredstreamClient {
-    proxy_url: String,
+    config: ClientConfig,
     state: Arc<AppState>,
+    backoff: Backoff,
+    retry_budget: RetryBudget,
+    endpoints: EndpointRotation,
 }
 
 impl ShredstreamClient {
-    pub fn new(proxy_url: String, state: Arc<AppState>) -> Self {
-        Self { proxy_url, state }
+    pub fn new(config: ClientConfig, state: Arc<AppState>) -> Self {
+        Self {
+            endpoints: EndpointRotation::new(config.proxy_urls.clone(), config.regions.clone()),
+            retry_budget: RetryBudget::new(config.max_reconnects),
+            config,
+            state,
+            backoff: Backoff::new(BACKOFF_BASE, BACKOFF_MAX),
+        }
     }
 
     async fn create_channel(&self) -> Result<Channel> {
-        let endpoint = tonic::transport::Endpoint::from_shared(self.proxy_url.clone())
+        let url = self.endpoints.current();
+        let mut endpoint = tonic::transport::Endpoint::from_shared(url.to_string())
             .context("Invalid proxy URL")?
             .connect_timeout(Duration::from_secs(10))
             .timeout(Duration::from_secs(60));
+
+        if url.starts_with("https://") {
+            let mut tls = ClientTlsConfig::new().with_enabled_roots();
+            if let Some(domain) = &self.config.tls_domain {
+                tls = tls.domain_name(domain.clone());
+            }
+            endpoint = endpoint.tls_config(tls).context("Invalid TLS configuration")?;
+        }
         
         endpoint.connect().await.context("Failed to connect to proxy")
     }
 
-    pub async fn subscribe(&self, tx: mpsc::Sender<ClientMessage>) -> Result<()> {
+    /// Connect and stream until `cancel` fires, reconnecting with backoff in between
+    pub async fn subscribe(&mut self, tx: mpsc::Sender<ClientMessage>, cancel: CancellationToken) -> Result<()> {
+        let mut last_error = String::new();
         loop {
-            self.state.set_connection_state(ConnectionState::Connecting);
+            // Stay in Connecting until the first successful connection
+            if self.state.connected_at.read().is_none() {
+                self.state.set_connection_state(ConnectionState::Connecting);
+            }
+            let attempt_started = Instant::now();
             
-            match self.try_subscribe(&tx).await {
+            let result = tokio::select! {
+                result = self.try_subscribe(&tx) => result,
+                _ = cancel.cancelled() => return Ok(()),
+            };
+            let stalled = result.as_ref().err().and_then(|e| e.downcast_ref::<StreamStalled>()).is_some();
+            match result {
                 Ok(_) => {
                     self.state.log_info("Stream ended, reconnecting...");
                 }
+                Err(e) if stalled => {
+                    self.state.log_warn(format!("{}, forcing a reconnect", e));
+                    last_error = e.to_string();
+                    self.state.set_connection_state(ConnectionState::Stalled);
+                }
                 Err(e) => {
                     self.state.log_error(format!("Connection error: {}", e));
+                    last_error = e.to_string();
                     let _ = tx.send(ClientMessage::Error(e.to_string())).await;
                 }
             }
 
-            self.state.set_connection_state(ConnectionState::Reconnecting);
-            self.state.reconnect_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-            
-            tokio::time::sleep(Duration::from_secs(2)).await;
+            self.state.network_health
+                .mark_disconnected(self.state.current_slot.load(std::sync::atomic::Ordering::Relaxed));
+
+            let ever_connected = self.state.connected_at.read().is_some();
+            if ever_connected {
+                // A stall stays on screen until the next connection succeeds
+                if !stalled {
+                    self.state.set_connection_state(ConnectionState::Reconnecting);
+                }
+                self.state.reconnect_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
+
+            // Start over from the base delay if this attempt held a stable connection
+            let was_stable = self.state.connected_at.read()
+                .filter(|connected| *connected >= attempt_started)
+                .is_some_and(|connected| connected.elapsed() >= BACKOFF_RESET_AFTER);
+            if was_stable {
+                self.backoff.reset();
+            }
+
+            let connected = self.state.connected_at.read().is_some_and(|connected| connected >= attempt_started);
+            if self.retry_budget.record(connected) {
+                self.state.log_error(format!(
+                    "Giving up after {} failed connection attempts; press p to retry",
+                    self.retry_budget.failures
+                ));
+                self.state.set_connection_state(ConnectionState::Error(last_error.clone()));
+                tokio::select! {
+                    _ = self.state.reconnect_requested.notified() => {}
+                    _ = cancel.cancelled() => return Ok(()),
+                }
+                self.state.log_info("Retrying connection");
+                self.retry_budget.reset();
+                self.backoff.reset();
+                if ever_connected {
+                    self.state.set_connection_state(ConnectionState::Reconnecting);
+                }
+            }
+
+            // Fail over only when this attempt couldn't connect
+            let attempted_url = self.endpoints.current().to_string();
+            let back_off = self.endpoints.next_attempt(connected, was_stable);
+            let next_url = self.endpoints.current().to_string();
+            if next_url != attempted_url {
+                if connected {
+                    self.state.log_info(format!("Returning to {} after a stable connection", next_url));
+                } else {
+                    self.state.log_warn(format!("Failing over from {} to {}", attempted_url, next_url));
+                }
+                *self.state.proxy_url.write() = next_url;
+                *self.state.region.write() = self.endpoints.current_region().map(str::to_string);
+            }
+            if !back_off {
+                continue;
+            }
+
+            let delay = with_jitter(self.backoff.next_delay());
+            if ever_connected {
+                self.state.log_info(format!("Reconnecting in {} ms", delay.as_millis()));
+            } else if self.endpoints.len() > 1 {
+                self.state.log_info(format!(
+                    "No proxy endpoint reachable yet, retrying in {} ms",
+                    delay.as_millis()
+                ));
+            } else {
+                self.state.log_info(format!(
+                    "Proxy at {} not reachable yet, retrying in {} ms",
+                    attempted_url,
+                    delay.as_millis()
+                ));
+            }
+
+            *self.state.next_retry_at.write() = Some(Instant::now() + delay);
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = cancel.cancelled() => return Ok(()),
+            }
+            *self.state.next_retry_at.write() = None;
         }
     }
 
     async fn try_subscribe(&self, tx: &mpsc::Sender<ClientMessage>) -> Result<()> {
         let channel = self.create_channel().await?;
-        let mut client = ShredstreamProxyClient::new(channel);
+        let mut client = ShredstreamProxyClient::new(channel.clone());
 
-        self.state.log_info(format!("Connected to proxy at {}", self.proxy_url));
+        // Only a subscription the proxy accepted counts as connected, since an endpoint can
+        // accept the channel and still reject the RPC
+        let request = tonic::Request::new(SubscribeEntriesRequest {});
+        let response = client.subscribe_entries(request).await?;
+        self.state.log_info(format!("Connected to proxy at {}", self.endpoints.current()));
         self.state.set_connection_state(ConnectionState::Connected);
         let _ = tx.send(ClientMessage::ConnectionChanged(ConnectionState::Connected)).await;
 
-        let request = tonic::Request::new(SubscribeEntriesRequest {});
-        let response = client.subscribe_entries(request).await?;
-        let mut stream = response.into_inner();
+        let mut source = GrpcEntries {
+            stream: response.into_inner(),
+            stall_timeout: self.config.stall_timeout,
+        };
+
+        // Heartbeats stop when the stream does, since leaving the select drops them
+        tokio::select! {
+            result = self.process(&mut source, tx) => result,
+            _ = self.send_heartbeats(channel) => unreachable!("send_heartbeats never returns"),
+        }
+    }
 
+    /// Send a heartbeat every `heartbeat_interval`, counting successes and failures in
+    /// `NetworkHealth`. Never returns; stops sending if heartbeats are off or the proxy
+    /// doesn't implement them.
+    async fn send_heartbeats(&self, channel: Channel) {
+        let Some(interval) = self.config.heartbeat_interval else {
+            return std::future::pending().await;
+        };
+        let mut client = HeartbeatClient::new(channel);
+        let health = &self.state.network_health;
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            let heartbeat = Heartbeat {
+                socket: None,
+                regions: self.endpoints.current_region().map(str::to_string).into_iter().collect(),
+            };
+            match tokio::time::timeout(interval, client.send_heartbeat(heartbeat)).await {
+                Ok(Ok(_)) => {
+                    health.heartbeat_success.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                }
+                Ok(Err(status)) if status.code() == tonic::Code::Unimplemented => {
+                    self.state.log_warn("Proxy doesn't accept heartbeats; no longer sending them");
+                    return std::future::pending().await;
+                }
+                Ok(Err(status)) => {
+                    tracing::debug!("Heartbeat failed: {}", status);
+                    health.heartbeat_fail.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                }
+                Err(_) => {
+                    tracing::debug!("Heartbeat timed out after {}s", interval.as_secs());
+                    health.heartbeat_fail.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    /// Feed a recorded stream through the same processing as a live one, then stop
+    pub async fn replay<S: EntrySource>(
+        &self,
+        mut source: S,
+        tx: mpsc::Sender<ClientMessage>,
+        cancel: CancellationToken,
+    ) -> Result<()> {
+        self.state.set_connection_state(ConnectionState::Connected);
+        let _ = tx.send(ClientMessage::ConnectionChanged(ConnectionState::Connected)).await;
+
+        let result = tokio::select! {
+            result = self.process(&mut source, &tx) => result,
+            _ = cancel.cancelled() => return Ok(()),
+        };
+
+        self.state.set_connection_state(ConnectionState::Disconnected);
+        let _ = tx.send(ClientMessage::ConnectionChanged(ConnectionState::Disconnected)).await;
+        result?;
+        self.state.log_info("Replay finished");
+        Ok(())
+    }
+
+    /// Decode and analyze every batch from `source` until it ends
+    async fn process<S: EntrySource>(&self, source: &mut S, tx: &mpsc::Sender<ClientMessage>) -> Result<()> {
         // Track seen signatures for duplicate detection
-        let mut recent_sigs: std::collections::HashSet<String> = std::collections::HashSet::new();
-        let mut sig_cleanup_counter = 0u64;
+        let mut recent_sigs = SignatureDedup::new(self.config.dedup_window);
 
         // Jito tip accounts as pubkeys
         let jito_tip_pubkeys: Vec<Pubkey> = JITO_TIP_ACCOUNTS
@@ -91,151 +687,296 @@ impl ShredstreamClient {
             .filter_map(|s| s.parse().ok())
             .collect();
 
-        // Known program lookup
-        let known_programs = KnownPrograms::get_all();
+        // Swaps invoking Jupiter are counted as routed, whatever pools it routes through
+        let jupiter_v6: Pubkey = KnownPrograms::JUPITER_V6.parse().expect("valid Jupiter program ID");
 
-        while let Some(result) = stream.next().await {
-            match result {
-                Ok(entry_pb) => {
-                    match bincode::deserialize::<Vec<Entry>>(&entry_pb.entries) {
-                        Ok(entries) => {
-                            let slot = entry_pb.slot;
-                            let entry_count = entries.len();
-                            let txn_count: usize = entries.iter()
-                                .map(|e| e.transactions.len())
-                                .sum();
-
-                            // Track DEX and bundle activity
-                            let mut dex_count = 0u64;
-                            let mut bundle_count = 0u64;
-                            let mut bundle_txns: Vec<String> = Vec::new();
-                            let mut bundle_tip: u64 = 0;
-                            let mut bundle_tip_account = String::new();
-
-                            for entry in &entries {
-                                for txn in &entry.transactions {
-                                    if txn.signatures.is_empty() {
-                                        continue;
-                                    }
-                                    
-                                    let sig = txn.signatures[0].to_string();
-                                    
-                                    // Duplicate detection
-                                    if recent_sigs.contains(&sig) {
-                                        self.state.competition_stats.duplicate_count
-                                            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-                                    } else {
-                                        recent_sigs.insert(sig.clone());
-                                    }
+        let mut turbine_estimator = TurbineEstimator::default();
+        let mut decoder = EntryDecoder::new(self.config.entry_format);
 
-                                    // Extract program IDs from transaction
-                                    let mut program_names: Vec<String> = Vec::new();
-                                    let mut is_dex = false;
-                                    let mut is_jito_tip = false;
-                                    let mut tip_amount: Option<u64> = None;
-
-                                    // Check account keys for programs and tip accounts
-                                    let account_keys = txn.message.static_account_keys();
-                                    for key in account_keys.iter() {
-                                        // Check if it's a Jito tip account
-                                        if jito_tip_pubkeys.contains(key) {
-                                            is_jito_tip = true;
-                                            bundle_tip_account = key.to_string();
-                                            // Note: Would need to parse instruction data for actual tip amount
-                                        }
+        while let Some(batch) = source.next_batch().await? {
+            let received_at = Instant::now();
+            if let Some(raw) = &self.config.raw_recorder {
+                if !raw.record(batch.clone()) {
+                    tracing::warn!("Raw entry recorder is behind, dropped slot {}", batch.slot);
+                }
+            }
+            self.state.record_payload(batch.entries.len() as u64);
+            match decoder.decode(&batch.entries) {
+                Ok(entries) => {
+                    let decoded_at = Instant::now();
+                    if let Some(format) = decoder.newly_confirmed() {
+                        tracing::info!("Decoding entries in the {} format", format);
+                        if format == self.config.entry_format {
+                            self.state.log_info(format!("Decoding entries as {}", format));
+                        } else {
+                            self.state.log_warn(format!(
+                                "Entries kept failing to decode as {}; switched to {}",
+                                self.config.entry_format, format
+                            ));
+                        }
+                    }
+                    // Only decoded messages reach the UI, so only they count towards UI lag
+                    self.state.last_received_slot
+                        .fetch_max(batch.slot, std::sync::atomic::Ordering::Relaxed);
+                    let slot = batch.slot;
+                    let entry_count = entries.len();
+                    let txn_count: usize = entries.iter()
+                        .map(|e| e.transactions.len())
+                        .sum();
 
-                                        // Check if it's a known program
-                                        if let Some(info) = known_programs.get(key) {
-                                            program_names.push(info.name.clone());
-                                            self.state.program_stats.record_program(*key);
-                                            
-                                            if matches!(info.category, crate::programs::ProgramCategory::Dex) {
-                                                is_dex = true;
-                                            }
-                                        }
-                                    }
+                    // Track DEX and bundle activity
+                    let mut dex_count = 0u64;
+                    let mut bundle_count = 0u64;
+                    let mut bundle_txns: Vec<String> = Vec::new();
+                    let mut bundle_tip: u64 = 0;
+                    let mut bundle_tip_account = String::new();
+                    let mut vote_count = 0usize;
+                    let mut unsigned_count = 0u64;
+                    let mut sampled_count = 0u64;
 
-                                    if is_dex {
-                                        dex_count += 1;
-                                    }
+                    for entry in &entries {
+                        for txn in &entry.transactions {
+                            // Malformed data: a transaction always carries at least the fee payer's signature
+                            let Some(first_signature) = txn.signatures.first() else {
+                                unsigned_count += 1;
+                                continue;
+                            };
 
-                                    if is_jito_tip {
-                                        bundle_count += 1;
-                                        bundle_txns.push(sig.clone());
-                                    }
+                            // Votes carry no MEV signal; count them and move on
+                            if is_vote(&txn.message) {
+                                vote_count += 1;
+                                continue;
+                            }
 
-                                    // Sample transactions (prioritize interesting ones)
-                                    let should_sample = is_dex || is_jito_tip || 
-                                        self.state.txn_samples.read().len() < 10;
-                                    
-                                    if should_sample {
-                                        self.state.add_txn_sample(
-                                            slot,
-                                            sig,
-                                            program_names,
-                                            is_jito_tip,
-                                            tip_amount,
-                                        );
-                                    }
+                            let sig = first_signature.to_string();
+
+                            // Duplicate detection
+                            if let Some(first_slot) = recent_sigs.check_and_insert(&sig, slot, Instant::now()) {
+                                self.state.competition_stats.add_duplicate(DuplicateInfo {
+                                    signature: sig.clone(),
+                                    first_slot,
+                                    dup_slot: slot,
+                                    timestamp: Local::now(),
+                                });
+                            }
+
+                            self.state.account_stats.record_writes(&writable_accounts(&txn.message));
+
+                            let budget = compute_budget(&txn.message);
+                            if budget.unit_price > 0 {
+                                self.state.competition_stats.record_priority_fee(slot, budget.priority_fee_lamports());
+                            }
+
+                            // Likely probes are kept out of the program activity metrics
+                            let probe = is_probe(&txn.message);
+                            if probe {
+                                self.state.program_stats.probe_txn_count
+                                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                            }
+
+                            // Extract program IDs from transaction
+                            let mut program_names: Vec<String> = Vec::new();
+                            let mut is_dex = false;
+                            let mut is_jito_tip = false;
+                            let mut tip_amount: Option<u64> = None;
 
-                                    // Check if transaction involves monitored wallet
-                                    if let Some(wallet) = *self.state.wallet_monitor.wallet.read() {
-                                        for key in account_keys.iter() {
-                                            if key == &wallet {
-                                                self.state.wallet_monitor.add_txn(
-                                                    crate::state::WalletTxn {
-                                                        slot,
-                                                        signature: txn.signatures[0].to_string(),
-                                                        timestamp: Local::now(),
-                                                        success: true, // Can't determine from shred data
-                                                        programs: Vec::new(),
-                                                    }
-                                                );
-                                                break;
-                                            }
+                            // Lookup table accounts aren't in the static keys and can't be resolved here
+                            if let Some(lut) = lookup_table_usage(&txn.message) {
+                                self.state.program_stats.lut_txn_count
+                                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                                self.state.program_stats.lut_unresolved_accounts
+                                    .fetch_add(lut.accounts() as u64, std::sync::atomic::Ordering::Relaxed);
+                                tracing::debug!(
+                                    "Transaction {} loads {} accounts ({} writable, {} readonly) from {} lookup tables; not resolved",
+                                    sig, lut.accounts(), lut.writable, lut.readonly, lut.tables
+                                );
+                            }
+
+                            // Check account keys for tip accounts
+                            let account_keys = txn.message.static_account_keys();
+                            for key in account_keys.iter() {
+                                if jito_tip_pubkeys.contains(key) {
+                                    is_jito_tip = true;
+                                    bundle_tip_account = key.to_string();
+                                }
+                            }
+                            if is_jito_tip {
+                                let tip = tip_lamports(&txn.message, &jito_tip_pubkeys);
+                                bundle_tip += tip;
+                                tip_amount = (tip > 0).then_some(tip);
+                            }
+
+                            // Only count programs the transaction actually invokes
+                            let mut dex_programs = 0usize;
+                            let mut routed = false;
+                            if !probe {
+                                for program in invoked_programs(txn) {
+                                    routed |= program == jupiter_v6;
+                                    if let Some(info) = self.state.program_stats.known_program(&program) {
+                                        program_names.push(info.name.clone());
+                                        self.state.program_stats.record_program(program, slot);
+
+                                        if matches!(info.category, crate::programs::ProgramCategory::Dex) {
+                                            is_dex = true;
+                                            dex_programs += 1;
                                         }
                                     }
                                 }
                             }
 
-                            // Record bundle if detected
-                            if bundle_count > 0 && !bundle_txns.is_empty() {
-                                self.state.competition_stats.add_bundle(BundleInfo {
-                                    slot,
-                                    txn_count: bundle_txns.len() as u32,
-                                    tip_amount: bundle_tip,
-                                    tip_account: bundle_tip_account,
-                                    signatures: bundle_txns,
-                                    timestamp: Local::now(),
-                                });
+                            if is_dex {
+                                dex_count += 1;
+                                self.state.program_stats.record_mints(&referenced_mints(&txn.message));
+                                self.state.program_stats.record_swap(routed);
+                            }
+
+                            // Atomic arbitrage usually routes through several DEXes in one transaction
+                            if dex_programs >= 2 {
+                                self.state.competition_stats.add_arbitrage(sig.clone());
                             }
 
-                            // Update slot info
-                            self.state.add_slot(slot, entry_count as u64, txn_count as u64);
+                            if self.config.detect_wsol {
+                                let wsol = detect_wsol(&txn.message);
+                                if wsol.wrap {
+                                    self.state.program_stats.wsol_wrap_count
+                                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                                    program_names.push("wSOL wrap".to_string());
+                                }
+                                if wsol.unwrap {
+                                    self.state.program_stats.wsol_unwrap_count
+                                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                                    program_names.push("wSOL unwrap".to_string());
+                                }
+                            }
 
-                            // Send to main app
-                            let _ = tx.send(ClientMessage::EntriesReceived {
+                            if is_jito_tip {
+                                bundle_count += 1;
+                                bundle_txns.push(sig.clone());
+                            }
+
+                            // Every transaction is offered; DEX and bundle ones are weighted up
+                            let sampled = self.state.add_txn_sample(
                                 slot,
-                                entry_count,
-                                txn_count,
-                            }).await;
-
-                            // Periodic cleanup of seen signatures (every 1000 entries)
-                            sig_cleanup_counter += 1;
-                            if sig_cleanup_counter % 1000 == 0 && recent_sigs.len() > 50000 {
-                                recent_sigs.clear();
+                                sig,
+                                program_names,
+                                is_dex,
+                                is_jito_tip,
+                                tip_amount,
+                            );
+                            if sampled {
+                                sampled_count += 1;
+                            }
+
+                            // Check if transaction involves monitored wallets
+                            // Collected first so the wallet list isn't read-locked while the stats are written
+                            let matched: Vec<Pubkey> = {
+                                let watched = self.state.wallet_monitor.wallets.read();
+                                account_keys.iter().filter(|key| watched.contains(key)).copied().collect()
+                            };
+                            for wallet in matched {
+                                self.state.wallet_monitor.add_txn(
+                                    crate::state::WalletTxn {
+                                        wallet,
+                                        slot,
+                                        signature: first_signature.to_string(),
+                                        timestamp: Local::now(),
+                                        status: TxnStatus::Unknown, // Shreds don't carry execution results
+                                        programs: Vec::new(),
+                                    }
+                                );
                             }
                         }
-                        Err(e) => {
-                            self.state.log_warn(format!(
-                                "Failed to deserialize entries for slot {}: {}",
-                                entry_pb.slot, e
-                            ));
+                    }
+
+                    // Record bundle if detected
+                    if bundle_count > 0 && !bundle_txns.is_empty() {
+                        let bundle = BundleInfo {
+                            slot,
+                            txn_count: bundle_txns.len() as u32,
+                            tip_amount: bundle_tip,
+                            tip_account: bundle_tip_account,
+                            signatures: bundle_txns,
+                            timestamp: Local::now(),
+                            status: BundleStatus::Pending,
+                        };
+                        if let Some(recorder) = &self.config.recorder {
+                            if !recorder.record(RecordRow::Bundle {
+                                slot,
+                                txn_count: bundle.txn_count,
+                                tip_lamports: bundle.tip_amount,
+                                tip_account: bundle.tip_account.clone(),
+                                timestamp: bundle.timestamp,
+                            }) {
+                                tracing::warn!("CSV recorder is behind, dropped bundle row");
+                            }
+                        }
+                        self.state.competition_stats.add_bundle(bundle);
+                    }
+
+                    self.state.metrics.add_votes(vote_count as u64);
+                    self.state.coverage.record_batch(txn_count as u64, unsigned_count, vote_count as u64, sampled_count);
+                    let txn_count = if self.state.metrics.count_votes {
+                        txn_count
+                    } else {
+                        txn_count - vote_count
+                    };
+
+                    if self.config.estimate_turbine {
+                        self.state.turbine_stats.add_sample(
+                            turbine_estimator.observe(slot, entry_count as u32),
+                        );
+                    }
+
+                    for skipped in self.state.leader_tracker.observe_slot(slot) {
+                        tracing::debug!("Slot {} skipped by its scheduled leader", skipped);
+                    }
+
+                    // Update slot info
+                    let first_shred_delay_ms =
+                        self.state.add_slot(slot, entry_count as u64, txn_count as u64, dex_count, bundle_count);
+
+                    self.state.leader_tracker.record_slot(LeaderSlotInfo {
+                        slot,
+                        leader: self.state.leader_tracker.leader_for_slot(slot),
+                        entry_count: entry_count as u64,
+                        txn_count: txn_count as u64,
+                        skip: false,
+                        first_shred_delay_ms,
+                        timestamp: Local::now(),
+                    });
+
+                    if let Some(recorder) = &self.config.recorder {
+                        if !recorder.record(RecordRow::Slot {
+                            slot,
+                            entry_count: entry_count as u64,
+                            txn_count: txn_count as u64,
+                            dex_count,
+                            timestamp: Local::now(),
+                        }) {
+                            tracing::warn!("CSV recorder is behind, dropped slot row");
                         }
                     }
+
+                    self.state.latency_stats.budget.record_processing(
+                        decoded_at - received_at,
+                        decoded_at.elapsed(),
+                    );
+
+                    // Send to main app
+                    let _ = tx.send(ClientMessage::EntriesReceived {
+                        slot,
+                        entry_count,
+                        txn_count,
+                        sent_at: Instant::now(),
+                    }).await;
                 }
                 Err(e) => {
-                    return Err(anyhow::anyhow!("Stream error: {}", e));
+                    self.state.metrics.deserialize_failures
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    self.state.log_warn(format!(
+                        "Failed to deserialize entries for slot {}: {}",
+                        batch.slot, e
+                    ));
                 }
             }
         }
@@ -246,14 +987,625 @@ impl ShredstreamClient {
 
 /// Start the client in a background task
 pub fn start_client(
-    proxy_url: String,
+    config: ClientConfig,
     state: Arc<AppState>,
     tx: mpsc::Sender<ClientMessage>,
+    cancel: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        let client = ShredstreamClient::new(proxy_url, state);
-        if let Err(e) = client.subscribe(tx).await {
+        let mut client = ShredstreamClient::new(config, state);
+        if let Err(e) = client.subscribe(tx, cancel).await {
             tracing::error!("Client fatal error: {}", e);
         }
     })
 }
+
+/// Replay a `--record-raw` file in a background task instead of connecting to the proxy
+pub fn start_replay(
+    source: FileReplay,
+    config: ClientConfig,
+    state: Arc<AppState>,
+    tx: mpsc::Sender<ClientMessage>,
+    cancel: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let client = ShredstreamClient::new(config, Arc::clone(&state));
+        if let Err(e) = client.replay(source, tx, cancel).await {
+            state.log_error(format!("Replay failed: {:#}", e));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::message::{Message, MessageHeader, VersionedMessage};
+    use solana_sdk::message::v0::MessageAddressTableLookup;
+    use solana_sdk::signature::Signature;
+    use solana_sdk::transaction::VersionedTransaction;
+
+    #[test]
+    fn endpoint_regions_prefer_labels_then_host() {
+        let urls = vec!["http://ny.proxy.example.com:50051".to_string(), "http://10.0.0.2:50051".to_string()];
+        assert_eq!(endpoint_regions(&urls, &[]), [Some("ny".to_string()), None]);
+        assert_eq!(endpoint_regions(&urls, &["tokyo".to_string()]), [Some("tokyo".to_string()), Some("tokyo".to_string())]);
+    }
+
+    fn entry(num_hashes: u64) -> Entry {
+        Entry { num_hashes, hash: solana_sdk::hash::Hash::new_unique(), transactions: Vec::new() }
+    }
+
+    #[test]
+    fn entry_formats_decode_their_own_layout_only() {
+        let entries = vec![entry(12_345), entry(678)];
+        let legacy = bincode::serialize(&entries).unwrap();
+        let varint = bincode::DefaultOptions::new().serialize(&entries).unwrap();
+
+        assert_eq!(decode_entries(&legacy, EntryFormat::Legacy).unwrap(), entries);
+        assert_eq!(decode_entries(&varint, EntryFormat::Varint).unwrap(), entries);
+        assert!(decode_entries(&legacy, EntryFormat::Varint).is_err());
+        assert!(decode_entries(&varint, EntryFormat::Legacy).is_err());
+    }
+
+    #[test]
+    fn entry_decoder_falls_back_after_repeated_failures() {
+        let entries = vec![entry(42)];
+        let varint = bincode::DefaultOptions::new().serialize(&entries).unwrap();
+        let mut decoder = EntryDecoder::new(EntryFormat::Legacy);
+
+        for _ in 1..ENTRY_FORMAT_FALLBACK_FAILURES {
+            assert!(decoder.decode(&varint).is_err());
+            assert_eq!(decoder.format, EntryFormat::Legacy);
+        }
+        assert_eq!(decoder.decode(&varint).unwrap(), entries);
+        assert_eq!(decoder.format, EntryFormat::Varint);
+        assert_eq!(decoder.newly_confirmed(), Some(EntryFormat::Varint));
+        assert_eq!(decoder.newly_confirmed(), None);
+    }
+
+    #[test]
+    fn legacy_format_accepts_a_lone_entry_filling_the_payload() {
+        let lone = entry(7);
+        let bytes = bincode::serialize(&lone).unwrap();
+        assert_eq!(decode_entries(&bytes, EntryFormat::Legacy).unwrap(), vec![lone]);
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(decode_entries(&trailing, EntryFormat::Legacy).is_err());
+    }
+
+    /// Batches handed out in order, then the end of the stream
+    struct Batches(VecDeque<RawEntries>);
+
+    impl EntrySource for Batches {
+        async fn next_batch(&mut self) -> Result<Option<RawEntries>> {
+            Ok(self.0.pop_front())
+        }
+    }
+
+    fn test_config() -> ClientConfig {
+        ClientConfig {
+            proxy_urls: vec!["http://127.0.0.1:50051".to_string()],
+            regions: vec![None],
+            tls_domain: None,
+            dedup_window: Duration::from_secs(60),
+            estimate_turbine: false,
+            detect_wsol: false,
+            recorder: None,
+            raw_recorder: None,
+            stall_timeout: None,
+            entry_format: EntryFormat::Legacy,
+            heartbeat_interval: None,
+            max_reconnects: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn malformed_payloads_are_counted_and_skipped() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let client = ShredstreamClient::new(test_config(), state.clone());
+        let vec_payload = bincode::serialize(&vec![entry(1), entry(2)]).unwrap();
+        let mut truncated = vec_payload.clone();
+        truncated.truncate(vec_payload.len() - 5);
+        let mut source = Batches(VecDeque::from([
+            RawEntries { slot: 100, entries: vec_payload },
+            RawEntries { slot: 101, entries: truncated },
+            RawEntries { slot: 102, entries: vec![0xff; 7] },
+            RawEntries { slot: 103, entries: bincode::serialize(&entry(3)).unwrap() },
+        ]));
+        let (tx, mut rx) = mpsc::channel(16);
+
+        client.process(&mut source, &tx).await.unwrap();
+
+        assert_eq!(state.metrics.deserialize_failures.load(std::sync::atomic::Ordering::Relaxed), 2);
+        let mut decoded = Vec::new();
+        while let Ok(message) = rx.try_recv() {
+            if let ClientMessage::EntriesReceived { slot, entry_count, .. } = message {
+                decoded.push((slot, entry_count));
+            }
+        }
+        assert_eq!(decoded, vec![(100, 2), (103, 1)]);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap_and_resets() {
+        let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(5));
+        let delays: Vec<u128> = (0..6).map(|_| backoff.next_delay().as_millis()).collect();
+        assert_eq!(delays, vec![500, 1_000, 2_000, 4_000, 5_000, 5_000]);
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn jitter_stays_within_its_bounds() {
+        let delay = Duration::from_secs(10);
+        for _ in 0..100 {
+            let jittered = with_jitter(delay).as_secs_f64();
+            assert!((10.0 * (1.0 - BACKOFF_JITTER)..=10.0 * (1.0 + BACKOFF_JITTER)).contains(&jittered), "{}", jittered);
+        }
+    }
+
+    #[test]
+    fn dedup_forgets_signatures_once_the_window_passes() {
+        let mut dedup = SignatureDedup::new(Duration::from_secs(10));
+        let start = Instant::now();
+        assert_eq!(dedup.check_and_insert("sig", 100, start), None);
+        assert_eq!(dedup.check_and_insert("sig", 101, start + Duration::from_secs(10)), Some(100));
+
+        let later = start + Duration::from_secs(11);
+        assert_eq!(dedup.check_and_insert("other", 102, later), None);
+        assert!(!dedup.seen.contains_key("sig"));
+        assert_eq!(dedup.order.len(), 1);
+        assert_eq!(dedup.check_and_insert("sig", 103, later), None);
+        assert_eq!(dedup.seen.len(), dedup.order.len());
+    }
+
+    fn program(id: &str) -> Pubkey {
+        id.parse().unwrap()
+    }
+
+    /// A legacy transaction from a fresh fee payer at key index 0, followed by `keys`. Instructions
+    /// are (program key index, account key indexes, data).
+    fn txn(keys: &[Pubkey], instructions: &[(u8, &[u8], &[u8])]) -> VersionedTransaction {
+        let mut account_keys = vec![Pubkey::new_unique()];
+        account_keys.extend_from_slice(keys);
+        let message = Message {
+            header: MessageHeader { num_required_signatures: 1, num_readonly_signed_accounts: 0, num_readonly_unsigned_accounts: 0 },
+            account_keys,
+            recent_blockhash: solana_sdk::hash::Hash::default(),
+            instructions: instructions.iter()
+                .map(|&(program_id_index, accounts, data)| CompiledInstruction {
+                    program_id_index,
+                    accounts: accounts.to_vec(),
+                    data: data.to_vec(),
+                })
+                .collect(),
+        };
+        VersionedTransaction { signatures: vec![Signature::new_unique()], message: VersionedMessage::Legacy(message) }
+    }
+
+    /// Run `txns` through the client as one entry in slot 100
+    async fn process_txns(state: &Arc<AppState>, config: ClientConfig, txns: Vec<VersionedTransaction>) {
+        let entry = Entry { num_hashes: 1, hash: solana_sdk::hash::Hash::new_unique(), transactions: txns };
+        let mut source = Batches(VecDeque::from([RawEntries { slot: 100, entries: bincode::serialize(&vec![entry]).unwrap() }]));
+        let (tx, _rx) = mpsc::channel(16);
+        ShredstreamClient::new(config, Arc::clone(state)).process(&mut source, &tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn dex_programs_count_only_when_invoked() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let raydium = program(KnownPrograms::RAYDIUM_V4);
+        let system = program(KnownPrograms::SYSTEM_PROGRAM);
+        // Raydium passed as an account to a System transfer, then a transaction that calls it
+        let referenced = txn(&[raydium, system], &[(2, &[0, 1], &[2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0])]);
+        let invoked = txn(&[raydium], &[(1, &[0], &[9])]);
+
+        process_txns(&state, test_config(), vec![referenced]).await;
+        assert_eq!(state.slot_history.read().back().unwrap().dex_txn_count, 0);
+        assert!(state.program_stats.get_top_programs(10).iter().all(|p| p.program_id != raydium));
+
+        process_txns(&state, test_config(), vec![invoked]).await;
+        assert_eq!(state.slot_history.read().back().unwrap().dex_txn_count, 1);
+        let top = state.program_stats.get_top_programs(10);
+        assert_eq!(top.iter().find(|p| p.program_id == raydium).map(|p| p.txn_count), Some(1));
+    }
+
+    #[tokio::test]
+    async fn lookup_table_accounts_are_counted_as_unresolved() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let legacy = txn(&[program(KnownPrograms::RAYDIUM_V4)], &[(1, &[0], &[9])]);
+        let VersionedMessage::Legacy(message) = legacy.message.clone() else { unreachable!() };
+        let v0 = VersionedTransaction {
+            signatures: vec![Signature::new_unique()],
+            message: VersionedMessage::V0(solana_sdk::message::v0::Message {
+                header: message.header,
+                account_keys: message.account_keys,
+                recent_blockhash: message.recent_blockhash,
+                instructions: message.instructions,
+                address_table_lookups: vec![
+                    MessageAddressTableLookup { account_key: Pubkey::new_unique(), writable_indexes: vec![0, 3], readonly_indexes: vec![1] },
+                    MessageAddressTableLookup { account_key: Pubkey::new_unique(), writable_indexes: vec![], readonly_indexes: vec![7] },
+                ],
+            }),
+        };
+        assert_eq!(
+            crate::analysis::lookup_table_usage(&v0.message),
+            Some(crate::analysis::LookupTableUsage { tables: 2, writable: 2, readonly: 2 })
+        );
+        assert_eq!(crate::analysis::lookup_table_usage(&legacy.message), None);
+
+        process_txns(&state, test_config(), vec![legacy, v0]).await;
+        assert_eq!(state.program_stats.lut_txn_count.load(std::sync::atomic::Ordering::Relaxed), 1);
+        assert_eq!(state.program_stats.lut_unresolved_accounts.load(std::sync::atomic::Ordering::Relaxed), 4);
+        // Program IDs are static keys, so both still count as DEX
+        assert_eq!(state.slot_history.read().back().unwrap().dex_txn_count, 2);
+    }
+
+    #[tokio::test]
+    async fn subscribe_returns_promptly_once_cancelled() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:1".to_string()));
+        let config = ClientConfig { proxy_urls: vec!["http://127.0.0.1:1".to_string()], ..test_config() };
+        let mut client = ShredstreamClient::new(config, Arc::clone(&state));
+        let (tx, _rx) = mpsc::channel(16);
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { client.subscribe(tx, cancel).await }
+        });
+
+        // Let it fail to connect and settle into its backoff sleep
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancel.cancel();
+        let result = tokio::time::timeout(Duration::from_secs(1), task).await;
+        assert!(matches!(result, Ok(Ok(Ok(())))), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn multi_dex_transactions_count_as_arbitrage() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let raydium = program(KnownPrograms::RAYDIUM_V4);
+        let orca = program(KnownPrograms::ORCA_WHIRLPOOL);
+        let cycle = txn(&[raydium, orca], &[(1, &[0], &[9]), (2, &[0], &[1])]);
+        let single = txn(&[raydium], &[(1, &[0], &[9]), (1, &[0], &[9])]);
+
+        process_txns(&state, test_config(), vec![cycle, single]).await;
+        assert_eq!(state.competition_stats.arbitrage_count.load(std::sync::atomic::Ordering::Relaxed), 1);
+        assert_eq!(state.slot_history.read().back().unwrap().dex_txn_count, 2);
+    }
+
+    #[test]
+    fn endpoint_rotation_tries_each_endpoint_before_wrapping() {
+        let urls = vec!["http://ny.example:1".to_string(), "http://tokyo.example:1".to_string(), "http://c:1".to_string()];
+        let regions = endpoint_regions(&urls, &[]);
+        let mut endpoints = EndpointRotation::new(urls, regions);
+        assert_eq!((endpoints.current(), endpoints.current_region()), ("http://ny.example:1", Some("ny")));
+
+        // Each failure moves on; only the failure of the last endpoint wraps back to the first
+        assert!(!endpoints.advance());
+        assert_eq!((endpoints.current(), endpoints.current_region()), ("http://tokyo.example:1", Some("tokyo")));
+        assert!(!endpoints.advance());
+        assert_eq!((endpoints.current(), endpoints.current_region()), ("http://c:1", None));
+        assert!(endpoints.advance());
+        assert_eq!(endpoints.current(), "http://ny.example:1");
+
+        // A single endpoint wraps on every failure, so every retry backs off
+        let mut single = EndpointRotation::new(vec!["http://a:1".to_string()], vec![None]);
+        assert!(single.advance());
+        assert!(single.advance());
+        assert_eq!(single.current(), "http://a:1");
+    }
+
+    #[test]
+    fn endpoints_fail_over_only_when_an_attempt_fails_to_connect() {
+        let urls = vec!["http://a:1".to_string(), "http://b:1".to_string(), "http://c:1".to_string()];
+        let mut endpoints = EndpointRotation::new(urls, vec![None; 3]);
+
+        // A routine reconnect (stream end or stall) keeps the endpoint and backs off
+        assert!(endpoints.next_attempt(true, false));
+        assert_eq!(endpoints.current(), "http://a:1");
+
+        // Failures move on at once until every endpoint has failed
+        assert!(!endpoints.next_attempt(false, false));
+        assert_eq!(endpoints.current(), "http://b:1");
+        assert!(!endpoints.next_attempt(false, false));
+        assert!(endpoints.next_attempt(false, false));
+        assert!(!endpoints.next_attempt(false, false));
+        assert_eq!(endpoints.current(), "http://b:1");
+
+        // A short-lived connection to a secondary stays there; a stable one returns to the primary
+        assert!(endpoints.next_attempt(true, false));
+        assert_eq!(endpoints.current(), "http://b:1");
+        assert!(endpoints.next_attempt(true, true));
+        assert_eq!(endpoints.current(), "http://a:1");
+    }
+
+    #[tokio::test]
+    async fn same_signature_in_two_slots_is_recorded_as_duplicate() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let payer = Pubkey::new_unique();
+        let resent = txn(&[payer, program(KnownPrograms::SYSTEM_PROGRAM)], &[(1, &[0], &[2])]);
+        let other = txn(&[payer, program(KnownPrograms::SYSTEM_PROGRAM)], &[(1, &[0], &[2])]);
+        let batch = |slot, txns: Vec<VersionedTransaction>| {
+            let entry = Entry { num_hashes: 1, hash: solana_sdk::hash::Hash::new_unique(), transactions: txns };
+            RawEntries { slot, entries: bincode::serialize(&vec![entry]).unwrap() }
+        };
+        let mut source = Batches(VecDeque::from([
+            batch(100, vec![resent.clone(), other]),
+            batch(102, vec![resent.clone()]),
+        ]));
+        let (tx, _rx) = mpsc::channel(16);
+        ShredstreamClient::new(test_config(), Arc::clone(&state)).process(&mut source, &tx).await.unwrap();
+
+        let duplicates = state.competition_stats.duplicate_txns.read();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].signature, resent.signatures[0].to_string());
+        assert_eq!((duplicates[0].first_slot, duplicates[0].dup_slot), (100, 102));
+    }
+
+    #[tokio::test]
+    async fn swap_mints_are_aggregated_per_dex_transaction() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let (ata, mint_a, mint_b, source) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let keys = [
+            ata,
+            mint_a,
+            mint_b,
+            program(KnownPrograms::RAYDIUM_V4),
+            program(KnownPrograms::ASSOCIATED_TOKEN),
+            program(KnownPrograms::TOKEN_PROGRAM),
+            source,
+            program(KnownPrograms::SYSTEM_PROGRAM),
+        ];
+        // Creates the output account for mint_b, moves mint_a in with TransferChecked, then swaps
+        let create_ata: (u8, &[u8], &[u8]) = (5, &[0, 1, 0, 3, 8, 6], &[1]);
+        let transfer_checked: (u8, &[u8], &[u8]) = (6, &[7, 2, 1, 0], &[12]);
+        let swap: (u8, &[u8], &[u8]) = (4, &[7, 1], &[9]);
+        let two_mints = txn(&keys, &[create_ata, transfer_checked, swap]);
+        let one_mint = txn(&keys, &[transfer_checked, swap]);
+        // Not a swap, so its mint isn't counted
+        let no_dex = txn(&keys, &[create_ata]);
+
+        process_txns(&state, test_config(), vec![two_mints, one_mint, no_dex]).await;
+        assert_eq!(state.program_stats.get_top_mints(10), vec![(mint_a, 2), (mint_b, 1)]);
+    }
+
+    #[tokio::test]
+    async fn payload_bytes_are_counted_before_decoding() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let payload = bincode::serialize(&vec![entry(1), entry(2)]).unwrap();
+        let sizes = [payload.len() as u64, 7];
+        let mut source = Batches(VecDeque::from([
+            RawEntries { slot: 100, entries: payload },
+            // Undecodable bytes still crossed the wire
+            RawEntries { slot: 101, entries: vec![0xff; 7] },
+        ]));
+        let (tx, _rx) = mpsc::channel(16);
+        ShredstreamClient::new(test_config(), Arc::clone(&state)).process(&mut source, &tx).await.unwrap();
+
+        let total: u64 = sizes.iter().sum();
+        assert_eq!(state.metrics.totals().bytes, total);
+        assert_eq!(state.metrics.bytes.load(std::sync::atomic::Ordering::Relaxed), total);
+
+        // A window reset clears the windowed count like the other rate counters, but not the total
+        state.reset_metrics_window();
+        assert_eq!(state.metrics.bytes.load(std::sync::atomic::Ordering::Relaxed), 0);
+        assert_eq!(state.metrics.totals().bytes, total);
+    }
+
+    #[tokio::test]
+    async fn jupiter_swaps_count_as_routed_even_through_raydium() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let pool = Pubkey::new_unique();
+        let jupiter = program(KnownPrograms::JUPITER_V6);
+        let raydium = program(KnownPrograms::RAYDIUM_V4);
+        let direct = txn(&[pool, raydium], &[(2, &[1], &[9])]);
+        // Invoking both Jupiter and Raydium still counts once, as routed
+        let routed = txn(&[pool, jupiter, raydium], &[(2, &[1, 3], &[1]), (3, &[1], &[9])]);
+
+        process_txns(&state, test_config(), vec![direct, routed]).await;
+        let stats = &state.program_stats;
+        assert_eq!(stats.direct_swap_count.load(std::sync::atomic::Ordering::Relaxed), 1);
+        assert_eq!(stats.routed_swap_count.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn recorded_stream_replays_to_identical_metrics() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("raw.bin");
+        let raydium = program(KnownPrograms::RAYDIUM_V4);
+        let batch = |slot, txns: Vec<VersionedTransaction>| {
+            let entries = vec![entry(1), Entry { num_hashes: 1, hash: solana_sdk::hash::Hash::new_unique(), transactions: txns }];
+            RawEntries { slot, entries: bincode::serialize(&entries).unwrap() }
+        };
+        let batches = VecDeque::from([
+            batch(100, vec![txn(&[raydium], &[(1, &[0], &[9])])]),
+            batch(101, vec![txn(&[raydium], &[(1, &[0], &[9])]), txn(&[], &[])]),
+            RawEntries { slot: 102, entries: vec![0xff; 7] },
+            batch(104, Vec::new()),
+        ]);
+
+        // Record while processing as if live
+        let live = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let (recorder, writer) = crate::replay::start_raw_recorder(path.clone()).await.unwrap();
+        let config = ClientConfig { raw_recorder: Some(recorder), ..test_config() };
+        let (tx, _rx) = mpsc::channel(16);
+        ShredstreamClient::new(config, Arc::clone(&live)).process(&mut Batches(batches), &tx).await.unwrap();
+        writer.await.unwrap();
+
+        let replayed = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let source = FileReplay::open(&path).await.unwrap();
+        ShredstreamClient::new(test_config(), Arc::clone(&replayed))
+            .replay(source, tx, CancellationToken::new())
+            .await
+            .unwrap();
+
+        let slots = |state: &AppState| state.slot_history.read().iter()
+            .map(|s| (s.slot, s.entry_count, s.txn_count, s.dex_txn_count))
+            .collect::<Vec<_>>();
+        assert_eq!(live.metrics.totals().entries, 6);
+        assert_eq!(replayed.metrics.totals(), live.metrics.totals());
+        assert_eq!(slots(&replayed), slots(&live));
+        assert_eq!(replayed.current_slot.load(std::sync::atomic::Ordering::Relaxed), 104);
+        // The undecodable slot 102 and the absent 103
+        assert_eq!(replayed.network_health.missed_slot_count.load(std::sync::atomic::Ordering::Relaxed), 2);
+        assert_eq!(
+            replayed.program_stats.get_top_programs(5).iter().map(|p| (p.program_id, p.txn_count)).collect::<Vec<_>>(),
+            live.program_stats.get_top_programs(5).iter().map(|p| (p.program_id, p.txn_count)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[tokio::test]
+    async fn a_gap_longer_than_the_stall_timeout_is_a_stall() {
+        let limit = Some(Duration::from_millis(50));
+        let late = async {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            1
+        };
+        let stalled = within_stall_timeout(limit, late).await.unwrap_err();
+        // subscribe tells a stall apart from other errors by its type
+        assert!(stalled.downcast_ref::<StreamStalled>().is_some());
+
+        let prompt = async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            2
+        };
+        assert_eq!(within_stall_timeout(limit, prompt).await.unwrap(), 2);
+        // Without a timeout a slow stream is just slow
+        let slow = async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            3
+        };
+        assert_eq!(within_stall_timeout(None, slow).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn signatureless_transactions_are_skipped_everywhere() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let wallet = Pubkey::new_unique();
+        state.wallet_monitor.add_wallet(wallet);
+        let raydium = program(KnownPrograms::RAYDIUM_V4);
+        // A swap touching a watched wallet, but with its signatures stripped
+        let mut unsigned = txn(&[wallet, raydium], &[(2, &[1], &[9])]);
+        unsigned.signatures.clear();
+
+        process_txns(&state, test_config(), vec![unsigned]).await;
+
+        assert_eq!(state.coverage.unsigned.load(std::sync::atomic::Ordering::Relaxed), 1);
+        assert_eq!(state.coverage.analyzed(), 0);
+        assert_eq!(state.slot_history.read().back().unwrap().dex_txn_count, 0);
+        assert!(state.program_stats.get_top_programs(10).is_empty());
+        assert!(state.wallet_monitor.transactions.read().is_empty());
+        assert!(state.txn_samples.read().is_empty());
+        assert!(state.competition_stats.duplicate_txns.read().is_empty());
+    }
+
+    #[test]
+    fn retry_budget_gives_up_after_max_failures_in_a_row() {
+        let mut budget = RetryBudget::new(Some(3));
+        assert!(!budget.record(false));
+        assert!(!budget.record(false));
+        // A successful connection starts the count over
+        assert!(!budget.record(true));
+        assert!(!budget.record(false));
+        assert!(!budget.record(false));
+        assert!(budget.record(false));
+        budget.reset();
+        assert!(!budget.record(false));
+
+        let mut unlimited = RetryBudget::new(None);
+        assert!((0..100).all(|_| !unlimited.record(false)));
+    }
+
+    #[tokio::test]
+    async fn client_waits_for_a_retry_request_after_giving_up() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:1".to_string()));
+        let config = ClientConfig { proxy_urls: vec!["http://127.0.0.1:1".to_string()], max_reconnects: Some(1), ..test_config() };
+        let mut client = ShredstreamClient::new(config, Arc::clone(&state));
+        let (tx, _rx) = mpsc::channel(16);
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { client.subscribe(tx, cancel).await }
+        });
+
+        let gave_up = tokio::time::timeout(Duration::from_secs(2), async {
+            while !matches!(*state.connection_state.read(), ConnectionState::Error(_)) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+        assert!(gave_up.await.is_ok(), "state: {:?}", state.connection_state.read());
+        assert!(state.request_reconnect());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(state.logs.read().iter().any(|log| log.message == "Retrying connection"));
+
+        cancel.cancel();
+        assert!(tokio::time::timeout(Duration::from_secs(1), task).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn coverage_counts_a_mixed_batch() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let raydium = program(KnownPrograms::RAYDIUM_V4);
+        let vote = txn(&[Pubkey::new_unique(), program(KnownPrograms::VOTE_PROGRAM)], &[(2, &[1, 0], &[12, 0, 0, 0])]);
+        let mut unsigned = txn(&[raydium], &[(1, &[0], &[9])]);
+        unsigned.signatures.clear();
+        let txns = vec![txn(&[raydium], &[(1, &[0], &[9])]), txn(&[], &[]), unsigned, vote];
+        let entry = Entry { num_hashes: 1, hash: solana_sdk::hash::Hash::new_unique(), transactions: txns };
+        let mut source = Batches(VecDeque::from([
+            RawEntries { slot: 100, entries: bincode::serialize(&vec![entry]).unwrap() },
+            RawEntries { slot: 101, entries: vec![0xff; 7] },
+        ]));
+        let (tx, _rx) = mpsc::channel(16);
+        ShredstreamClient::new(test_config(), Arc::clone(&state)).process(&mut source, &tx).await.unwrap();
+
+        let coverage = &state.coverage;
+        let load = |counter: &std::sync::atomic::AtomicU64| counter.load(std::sync::atomic::Ordering::Relaxed);
+        assert_eq!((load(&coverage.seen), load(&coverage.unsigned), load(&coverage.votes)), (4, 1, 1));
+        assert_eq!(coverage.analyzed(), 2);
+        // An empty sample buffer keeps everything offered
+        assert_eq!(load(&coverage.sampled), 2);
+        assert!((coverage.analyzed_pct().unwrap() - 200.0 / 3.0).abs() < 1e-9);
+        assert_eq!(load(&state.metrics.deserialize_failures), 1);
+    }
+
+    #[tokio::test]
+    async fn a_rejected_subscription_never_counts_as_connected() {
+        // A gRPC server with no services: the channel opens, but every RPC is rejected
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_routes(tonic::service::Routes::default())
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+
+        let state = Arc::new(AppState::new(url.clone()));
+        let config = ClientConfig { proxy_urls: vec![url], max_reconnects: Some(2), ..test_config() };
+        let mut client = ShredstreamClient::new(config, Arc::clone(&state));
+        let (tx, mut rx) = mpsc::channel(16);
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { client.subscribe(tx, cancel).await }
+        });
+
+        let gave_up = tokio::time::timeout(Duration::from_secs(5), async {
+            while !matches!(*state.connection_state.read(), ConnectionState::Error(_)) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+        assert!(gave_up.await.is_ok(), "state: {:?}", state.connection_state.read());
+        assert!(state.connected_at.read().is_none());
+        assert_eq!(state.reconnect_count.load(std::sync::atomic::Ordering::Relaxed), 0);
+        assert!(state.connection_events.read().iter().all(|(_, s)| *s != ConnectionState::Connected));
+        while let Ok(message) = rx.try_recv() {
+            assert!(!matches!(message, ClientMessage::ConnectionChanged(ConnectionState::Connected)));
+        }
+
+        cancel.cancel();
+        assert!(tokio::time::timeout(Duration::from_secs(1), task).await.is_ok());
+        server.abort();
+    }
+}
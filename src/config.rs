@@ -0,0 +1,279 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, FromArgMatches, ValueEnum};
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::client::EntryFormat;
+use crate::keymap::KeymapScheme;
+use crate::theme::ThemeName;
+use crate::Args;
+
+/// Settings read from `--config`, keyed by the long flag names
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct FileConfig {
+    proxy_url: Option<String>,
+    region: Option<String>,
+    tls_domain: Option<String>,
+    stall_timeout: Option<u64>,
+    heartbeat_interval: Option<u64>,
+    max_reconnects: Option<u32>,
+    entry_format: Option<String>,
+    tick_rate: Option<u64>,
+    metrics_window: Option<u64>,
+    ema_alpha: Option<f64>,
+    keymap: Option<String>,
+    keymap_file: Option<PathBuf>,
+    dedup_window_secs: Option<u64>,
+    estimate_turbine: Option<bool>,
+    detect_wsol: Option<bool>,
+    count_votes: Option<bool>,
+    programs_file: Option<PathBuf>,
+    program_cache: Option<PathBuf>,
+    validators_file: Option<PathBuf>,
+    theme: Option<String>,
+    programs_reload_secs: Option<u64>,
+    category_colors: Option<String>,
+    locale: Option<String>,
+    sla_latency_ms: Option<f64>,
+    sla_reset_with_window: Option<bool>,
+    alert_latency_ms: Option<f64>,
+    alert_skip_pct: Option<f64>,
+    metrics_port: Option<u16>,
+    record_csv: Option<PathBuf>,
+    log_file: Option<PathBuf>,
+    min_tip_sol: Option<f64>,
+    dex_only: Option<bool>,
+    flagged_sample_weight: Option<f64>,
+    record_raw: Option<PathBuf>,
+    replay: Option<PathBuf>,
+    wallet: Option<String>,
+    rpc_url: Option<String>,
+    confirm_quit: Option<bool>,
+    headless: Option<bool>,
+    duration: Option<u64>,
+}
+
+impl FileConfig {
+    /// Parse `path`, resolving relative paths in it against the file's directory
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        let mut config: Self = toml::from_str(&text).with_context(|| format!("Invalid config file {}", path.display()))?;
+        if let Some(dir) = path.parent() {
+            config.resolve_paths(dir);
+        }
+        Ok(config)
+    }
+
+    fn resolve_paths(&mut self, dir: &Path) {
+        let paths = [
+            &mut self.keymap_file,
+            &mut self.programs_file,
+            &mut self.program_cache,
+            &mut self.validators_file,
+            &mut self.record_csv,
+            &mut self.log_file,
+            &mut self.record_raw,
+            &mut self.replay,
+        ];
+        // Joining an absolute path replaces `dir`, so those are kept as written
+        for path in paths.into_iter().flatten() {
+            *path = dir.join(&*path);
+        }
+    }
+}
+
+/// True if the user set this argument on the command line or through its env var
+fn set_explicitly(matches: &ArgMatches, id: &str) -> bool {
+    matches!(
+        matches.value_source(id),
+        Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+    )
+}
+
+/// Build `Args` from the command line, filling anything left at its default from `--config`.
+///
+/// Precedence is command line, then environment variables, then the config file, then defaults.
+pub fn load_merged(matches: &ArgMatches) -> Result<Args> {
+    let mut args = Args::from_arg_matches(matches)?;
+    let Some(path) = args.config.clone() else {
+        return Ok(args);
+    };
+    let file = FileConfig::load(&path)?;
+
+    macro_rules! merge {
+        ($($field:ident),* $(,)?) => {$(
+            if let Some(value) = file.$field {
+                if !set_explicitly(matches, stringify!($field)) {
+                    args.$field = value.into();
+                }
+            }
+        )*};
+    }
+
+    merge!(
+        tls_domain,
+        stall_timeout,
+        heartbeat_interval,
+        max_reconnects,
+        tick_rate,
+        metrics_window,
+        ema_alpha,
+        keymap_file,
+        dedup_window_secs,
+        estimate_turbine,
+        detect_wsol,
+        count_votes,
+        programs_file,
+        program_cache,
+        validators_file,
+        programs_reload_secs,
+        locale,
+        sla_latency_ms,
+        sla_reset_with_window,
+        alert_latency_ms,
+        alert_skip_pct,
+        metrics_port,
+        record_csv,
+        log_file,
+        min_tip_sol,
+        dex_only,
+        flagged_sample_weight,
+        record_raw,
+        replay,
+        rpc_url,
+        confirm_quit,
+        headless,
+        duration,
+    );
+
+    // These are parsed the same way clap parses the flags
+    if let Some(urls) = file.proxy_url.filter(|_| !set_explicitly(matches, "proxy_url")) {
+        args.proxy_url = urls.split(',').map(|url| url.trim().to_string()).collect();
+    }
+    if let Some(regions) = file.region.filter(|_| !set_explicitly(matches, "region")) {
+        args.region = regions.split(',').map(|region| region.trim().to_string()).collect();
+    }
+    if let Some(wallets) = file.wallet.filter(|_| !set_explicitly(matches, "wallet")) {
+        args.wallet = wallets.split(',')
+            .map(|wallet| wallet.trim().parse::<Pubkey>().map_err(|e| anyhow!("{}: wallet: {}", path.display(), e)))
+            .collect::<Result<_>>()?;
+    }
+    if let Some(format) = file.entry_format.filter(|_| !set_explicitly(matches, "entry_format")) {
+        args.entry_format = EntryFormat::from_str(&format, true)
+            .map_err(|e| anyhow!("{}: entry-format: {}", path.display(), e))?;
+    }
+    if let Some(keymap) = file.keymap.filter(|_| !set_explicitly(matches, "keymap")) {
+        args.keymap = KeymapScheme::from_str(&keymap, true)
+            .map_err(|e| anyhow!("{}: keymap: {}", path.display(), e))?;
+    }
+    if let Some(theme) = file.theme.filter(|_| !set_explicitly(matches, "theme")) {
+        args.theme = ThemeName::from_str(&theme, true)
+            .map_err(|e| anyhow!("{}: theme: {}", path.display(), e))?;
+    }
+    if let Some(colors) = file.category_colors.filter(|_| !set_explicitly(matches, "category_colors")) {
+        args.category_colors = colors
+            .parse()
+            .map_err(|e| anyhow!("{}: category-colors: {}", path.display(), e))?;
+    }
+
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn write_config(contents: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    fn load(cli: &[&str]) -> Result<Args> {
+        let matches = Args::command().try_get_matches_from(std::iter::once("shredstream-tui").chain(cli.iter().copied()))?;
+        load_merged(&matches)
+    }
+
+    /// Set in the child process started by `rerun_with_env`
+    const CHILD_ENV: &str = "SHREDSTREAM_TUI_TEST_CHILD";
+
+    /// Run `test` again in a child test process whose `SHREDSTREAM_*` variables are exactly `vars`,
+    /// since clap reads them straight from the process environment. Returns false in the child,
+    /// which should go on with the test.
+    fn rerun_with_env(test: &str, vars: &[(&str, &str)]) -> bool {
+        if std::env::var_os(CHILD_ENV).is_some() {
+            return false;
+        }
+        let mut child = std::process::Command::new(std::env::current_exe().unwrap());
+        child.args([test, "--exact", "--test-threads=1"]);
+        for (key, _) in std::env::vars_os() {
+            if key.to_string_lossy().starts_with("SHREDSTREAM_") {
+                child.env_remove(key);
+            }
+        }
+        child.envs(vars.iter().copied()).env(CHILD_ENV, "1");
+        let output = child.output().unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{}", stdout);
+        assert!(stdout.contains("1 passed"), "the child ran no test: {}", stdout);
+        true
+    }
+
+    #[test]
+    fn command_line_beats_env_beats_file_beats_defaults() {
+        if rerun_with_env("config::tests::command_line_beats_env_beats_file_beats_defaults", &[("SHREDSTREAM_RPC_URL", "http://env-rpc")]) {
+            return;
+        }
+        let file = write_config(r#"
+            proxy-url = "http://file-a:50051, http://file-b:50051"
+            rpc-url = "http://file-rpc"
+            tick-rate = 250
+            stall-timeout = 5
+            theme = "light"
+        "#);
+        let args = load(&["--config", file.path().to_str().unwrap(), "--tick-rate", "50"]).unwrap();
+
+        assert_eq!(args.tick_rate, 50);
+        assert_eq!(args.rpc_url.as_deref(), Some("http://env-rpc"));
+        assert_eq!(args.stall_timeout, 5);
+        assert_eq!(args.proxy_url, vec!["http://file-a:50051", "http://file-b:50051"]);
+        assert_eq!(args.theme, ThemeName::Light);
+        assert_eq!(args.heartbeat_interval, 0);
+    }
+
+    #[test]
+    fn config_file_errors_name_the_file() {
+        let file = write_config("tick-rat = 250\n");
+        let err = load(&["--config", file.path().to_str().unwrap()]).unwrap_err();
+        assert!(format!("{:#}", err).contains("Invalid config file"), "{:#}", err);
+
+        let file = write_config("theme = \"neon\"\n");
+        let err = load(&["--config", file.path().to_str().unwrap()]).unwrap_err();
+        assert!(err.to_string().contains("theme"), "{}", err);
+    }
+
+    #[test]
+    fn relative_paths_in_the_file_are_relative_to_it() {
+        let absolute = std::env::temp_dir().join("elsewhere").join("shredstream.log");
+        let file = write_config(&format!(
+            "programs-file = \"programs.json\"\nrecord-csv = \"out/slots.csv\"\nlog-file = {:?}\n",
+            absolute
+        ));
+        let args = load(&["--config", file.path().to_str().unwrap(), "--validators-file", "validators.json"]).unwrap();
+        let dir = file.path().parent().unwrap();
+
+        assert_eq!(args.programs_file, Some(dir.join("programs.json")));
+        assert_eq!(args.record_csv, Some(dir.join("out/slots.csv")));
+        assert_eq!(args.log_file, Some(absolute));
+        // Paths on the command line stay relative to the working directory
+        assert_eq!(args.validators_file, Some(PathBuf::from("validators.json")));
+    }
+}
@@ -1,67 +1,135 @@
 use std::time::Duration;
 
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
+
+use crate::keymap::Keymap;
+use crate::ui;
 
 /// Input events for the application
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputEvent {
-    /// Quit the application
+    /// Quit the application, asking first with `--confirm-quit`
     Quit,
+    /// Quit without asking (Ctrl+C)
+    ForceQuit,
     /// Navigate to next tab
     NextTab,
     /// Navigate to previous tab
     PrevTab,
+    /// Jump to a tab by index
+    GotoTab(usize),
     /// Scroll up
     ScrollUp,
     /// Scroll down
     ScrollDown,
+    /// Move up by the visible list height
+    PageUp,
+    /// Move down by the visible list height
+    PageDown,
+    /// Jump to the first row
+    ScrollTop,
+    /// Jump to the last row
+    ScrollBottom,
     /// Reset metrics window
     ResetMetrics,
+    /// Clear all accumulated statistics
+    HardReset,
+    /// Toggle rates between per-second and per-slot
+    ToggleRateUnit,
+    /// Export a JSON snapshot of current metrics
+    ExportSnapshot,
+    /// Export every program seen to CSV
+    ExportPrograms,
+    /// Freeze or resume the scrolling lists
+    TogglePause,
+    /// Copy the selected row's full pubkey/signature to the clipboard
+    Copy,
     /// Toggle help display
     ToggleHelp,
+    /// Toggle the runtime configuration overlay
+    ToggleInfo,
+    /// Open the wallet input box
+    EditWallet,
+    /// Open the label box for the selected program
+    LabelProgram,
+    /// Open the signature search box for Recent Transactions
+    Search,
+    /// Cycle the sort order of the leader latency table
+    CycleSort,
+    /// Cycle the leader latency table between all regions and each region
+    CycleRegion,
+    /// Turn the Recent Bundles tip threshold on or off
+    ToggleTipFilter,
+    /// Show only slots and transactions with DEX or bundle activity
+    ToggleDexOnly,
+    /// Open the detail popup for the selected bundle
+    OpenDetail,
+    /// Switch the Overview counters between all time and the current connection
+    ToggleScope,
+    /// Retry the proxy after the client gave up under `--max-reconnects`
+    Reconnect,
+    /// Redraw more often
+    SpeedUp,
+    /// Redraw less often
+    SpeedDown,
+    /// A raw key press while a text input box is open
+    TextKey(KeyCode),
     /// Close help/overlay
     CloseOverlay,
     /// No input (tick)
     Tick,
 }
 
-/// Poll for input events with a timeout
-pub fn poll_event(timeout: Duration) -> Option<InputEvent> {
+/// Poll for input events with a timeout.
+///
+/// While `raw_keys` is set (a text box or prompt is open), key presses bypass the keymap and
+/// arrive as `TextKey`.
+pub fn poll_event(timeout: Duration, keymap: &Keymap, raw_keys: bool) -> Option<InputEvent> {
     if event::poll(timeout).ok()? {
-        if let Event::Key(key) = event::read().ok()? {
-            // Only handle key press events (not release)
-            if key.kind != KeyEventKind::Press {
-                return None;
+        match event::read().ok()? {
+            Event::Key(key) => return key_event(&key, keymap, raw_keys),
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                return ui::tab_at(mouse.column, mouse.row).map(InputEvent::GotoTab);
             }
+            _ => {}
+        }
+    }
+    
+    Some(InputEvent::Tick)
+}
+
+fn key_event(key: &KeyEvent, keymap: &Keymap, raw_keys: bool) -> Option<InputEvent> {
+    // Only handle key press events (not release)
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
 
-            return Some(match key.code {
-                // Quit
-                KeyCode::Char('q') => InputEvent::Quit,
-                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    InputEvent::Quit
-                }
-                KeyCode::Esc => InputEvent::CloseOverlay,
+    // Ctrl+C always quits, regardless of keymap or --confirm-quit
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return Some(InputEvent::ForceQuit);
+    }
 
-                // Tab navigation
-                KeyCode::Tab => InputEvent::NextTab,
-                KeyCode::BackTab => InputEvent::PrevTab,
-                KeyCode::Right | KeyCode::Char('l') => InputEvent::NextTab,
-                KeyCode::Left | KeyCode::Char('h') => InputEvent::PrevTab,
+    if raw_keys {
+        return Some(InputEvent::TextKey(key.code));
+    }
+    keymap.lookup(key)
+}
 
-                // Scrolling
-                KeyCode::Up | KeyCode::Char('k') => InputEvent::ScrollUp,
-                KeyCode::Down | KeyCode::Char('j') => InputEvent::ScrollDown,
-                KeyCode::PageUp => InputEvent::ScrollUp,
-                KeyCode::PageDown => InputEvent::ScrollDown,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keymap::KeymapScheme;
 
-                // Actions
-                KeyCode::Char('r') => InputEvent::ResetMetrics,
-                KeyCode::Char('?') => InputEvent::ToggleHelp,
+    #[test]
+    fn ctrl_c_quits_even_while_the_quit_prompt_is_open() {
+        let keymap = Keymap::load(KeymapScheme::Vim, None).unwrap();
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
 
-                _ => return None,
-            });
-        }
+        assert_eq!(key_event(&ctrl_c, &keymap, false), Some(InputEvent::ForceQuit));
+        assert_eq!(key_event(&ctrl_c, &keymap, true), Some(InputEvent::ForceQuit));
+        assert_eq!(key_event(&q, &keymap, false), Some(InputEvent::Quit));
+        // With the prompt open, other keys go to it as answers
+        assert_eq!(key_event(&q, &keymap, true), Some(InputEvent::TextKey(KeyCode::Char('q'))));
     }
-    
-    Some(InputEvent::Tick)
 }
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::ValueEnum;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::events::InputEvent;
+
+/// Built-in keybinding schemes selectable with `--keymap`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum KeymapScheme {
+    /// Arrows plus vim-style h/j/k/l navigation
+    Vim,
+    /// Arrow keys only; h/j/k/l are left unbound
+    ArrowsOnly,
+    /// Arrow keys plus overrides loaded from `--keymap-file`
+    Custom,
+}
+
+/// A single key press, normalized so that shifted characters match by character alone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode) -> Self {
+        Self { code, modifiers: KeyModifiers::NONE }
+    }
+
+    pub fn from_event(key: &KeyEvent) -> Self {
+        let mut modifiers = key.modifiers;
+        if matches!(key.code, KeyCode::Char(_)) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        Self { code: key.code, modifiers }
+    }
+}
+
+/// Lookup table from key presses to input events
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyBinding, InputEvent>,
+}
+
+impl Keymap {
+    pub fn load(scheme: KeymapScheme, file: Option<&Path>) -> Result<Self> {
+        let mut keymap = match scheme {
+            KeymapScheme::Vim => Self::vim(),
+            KeymapScheme::ArrowsOnly | KeymapScheme::Custom => Self::arrows_only(),
+        };
+
+        match (scheme, file) {
+            (KeymapScheme::Custom, Some(path)) => keymap.apply_file(path)?,
+            (KeymapScheme::Custom, None) => bail!("--keymap custom requires --keymap-file"),
+            _ => {}
+        }
+
+        Ok(keymap)
+    }
+
+    /// Arrow keys, Tab and the action keys
+    pub fn arrows_only() -> Self {
+        let mut keymap = Self { bindings: HashMap::new() };
+
+        // Quit
+        keymap.bind(KeyCode::Char('q'), InputEvent::Quit);
+        keymap.bind(KeyCode::Esc, InputEvent::CloseOverlay);
+
+        // Tab navigation
+        keymap.bind(KeyCode::Tab, InputEvent::NextTab);
+        keymap.bind(KeyCode::BackTab, InputEvent::PrevTab);
+        keymap.bind(KeyCode::Right, InputEvent::NextTab);
+        keymap.bind(KeyCode::Left, InputEvent::PrevTab);
+        for (index, key) in ('1'..='8').enumerate() {
+            keymap.bind(KeyCode::Char(key), InputEvent::GotoTab(index));
+        }
+
+        // Scrolling
+        keymap.bind(KeyCode::Up, InputEvent::ScrollUp);
+        keymap.bind(KeyCode::Down, InputEvent::ScrollDown);
+        keymap.bind(KeyCode::PageUp, InputEvent::PageUp);
+        keymap.bind(KeyCode::PageDown, InputEvent::PageDown);
+        keymap.bind(KeyCode::Home, InputEvent::ScrollTop);
+        keymap.bind(KeyCode::End, InputEvent::ScrollBottom);
+
+        // Actions
+        keymap.bind(KeyCode::Char('r'), InputEvent::ResetMetrics);
+        keymap.bind(KeyCode::Char('R'), InputEvent::HardReset);
+        keymap.bind(KeyCode::Char('s'), InputEvent::ExportSnapshot);
+        keymap.bind(KeyCode::Char('e'), InputEvent::ExportPrograms);
+        keymap.bind(KeyCode::Char('u'), InputEvent::ToggleRateUnit);
+        keymap.bind(KeyCode::Char(' '), InputEvent::TogglePause);
+        keymap.bind(KeyCode::Char('y'), InputEvent::Copy);
+        keymap.bind(KeyCode::Char('i'), InputEvent::ToggleInfo);
+        keymap.bind(KeyCode::Char('w'), InputEvent::EditWallet);
+        keymap.bind(KeyCode::Char('n'), InputEvent::LabelProgram);
+        keymap.bind(KeyCode::Char('/'), InputEvent::Search);
+        keymap.bind(KeyCode::Char('o'), InputEvent::CycleSort);
+        keymap.bind(KeyCode::Char('f'), InputEvent::CycleRegion);
+        keymap.bind(KeyCode::Char('t'), InputEvent::ToggleTipFilter);
+        keymap.bind(KeyCode::Char('d'), InputEvent::ToggleDexOnly);
+        keymap.bind(KeyCode::Enter, InputEvent::OpenDetail);
+        keymap.bind(KeyCode::Char('c'), InputEvent::ToggleScope);
+        keymap.bind(KeyCode::Char('p'), InputEvent::Reconnect);
+        keymap.bind(KeyCode::Char('+'), InputEvent::SpeedUp);
+        keymap.bind(KeyCode::Char('-'), InputEvent::SpeedDown);
+        keymap.bind(KeyCode::Char('?'), InputEvent::ToggleHelp);
+
+        keymap
+    }
+
+    /// The default scheme: arrows plus h/j/k/l
+    pub fn vim() -> Self {
+        let mut keymap = Self::arrows_only();
+        keymap.bind(KeyCode::Char('l'), InputEvent::NextTab);
+        keymap.bind(KeyCode::Char('h'), InputEvent::PrevTab);
+        keymap.bind(KeyCode::Char('k'), InputEvent::ScrollUp);
+        keymap.bind(KeyCode::Char('j'), InputEvent::ScrollDown);
+        keymap.bind(KeyCode::Char('g'), InputEvent::ScrollTop);
+        keymap.bind(KeyCode::Char('G'), InputEvent::ScrollBottom);
+        keymap
+    }
+
+    pub fn bind(&mut self, code: KeyCode, event: InputEvent) {
+        self.bindings.insert(KeyBinding::new(code), event);
+    }
+
+    pub fn lookup(&self, key: &KeyEvent) -> Option<InputEvent> {
+        self.bindings.get(&KeyBinding::from_event(key)).cloned()
+    }
+
+    /// Apply overrides from a file of `key = action` lines.
+    ///
+    /// Blank lines and lines starting with `#` are ignored. An action of
+    /// `none` unbinds the key.
+    fn apply_file(&mut self, path: &Path) -> Result<()> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read keymap file {}", path.display()))?;
+
+        for (lineno, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, action) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("{}:{}: expected `key = action`", path.display(), lineno + 1))?;
+            let binding = parse_key(key.trim())
+                .with_context(|| format!("{}:{}", path.display(), lineno + 1))?;
+
+            match action.trim() {
+                "none" => {
+                    self.bindings.remove(&binding);
+                }
+                action => {
+                    let event = parse_action(action)
+                        .with_context(|| format!("{}:{}", path.display(), lineno + 1))?;
+                    self.bindings.insert(binding, event);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_key(s: &str) -> Result<KeyBinding> {
+    let (modifiers, name) = match s.strip_prefix("ctrl+") {
+        Some(rest) => (KeyModifiers::CONTROL, rest),
+        None => (KeyModifiers::NONE, s),
+    };
+
+    let code = match name {
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "enter" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => bail!("unknown key `{}`", s),
+            }
+        }
+    };
+
+    Ok(KeyBinding { code, modifiers })
+}
+
+fn parse_action(s: &str) -> Result<InputEvent> {
+    Ok(match s {
+        "quit" => InputEvent::Quit,
+        "next_tab" => InputEvent::NextTab,
+        "prev_tab" => InputEvent::PrevTab,
+        "scroll_up" => InputEvent::ScrollUp,
+        "scroll_down" => InputEvent::ScrollDown,
+        "page_up" => InputEvent::PageUp,
+        "page_down" => InputEvent::PageDown,
+        "scroll_top" => InputEvent::ScrollTop,
+        "scroll_bottom" => InputEvent::ScrollBottom,
+        "reset_metrics" => InputEvent::ResetMetrics,
+        "reset_all" => InputEvent::HardReset,
+        "export_snapshot" => InputEvent::ExportSnapshot,
+        "export_programs" => InputEvent::ExportPrograms,
+        "toggle_rate_unit" => InputEvent::ToggleRateUnit,
+        "toggle_pause" => InputEvent::TogglePause,
+        "copy" => InputEvent::Copy,
+        "toggle_help" => InputEvent::ToggleHelp,
+        "toggle_info" => InputEvent::ToggleInfo,
+        "edit_wallet" => InputEvent::EditWallet,
+        "label_program" => InputEvent::LabelProgram,
+        "search" => InputEvent::Search,
+        "cycle_sort" => InputEvent::CycleSort,
+        "cycle_region" => InputEvent::CycleRegion,
+        "toggle_tip_filter" => InputEvent::ToggleTipFilter,
+        "toggle_dex_only" => InputEvent::ToggleDexOnly,
+        "open_detail" => InputEvent::OpenDetail,
+        "toggle_scope" => InputEvent::ToggleScope,
+        "reconnect" => InputEvent::Reconnect,
+        "speed_up" => InputEvent::SpeedUp,
+        "speed_down" => InputEvent::SpeedDown,
+        "close_overlay" => InputEvent::CloseOverlay,
+        _ => match s.strip_prefix("goto_tab_").and_then(|n| n.parse::<usize>().ok()) {
+            Some(n @ 1..=8) => InputEvent::GotoTab(n - 1),
+            _ => bail!("unknown action `{}`", s),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_parse_from_names_characters_and_ctrl() {
+        assert_eq!(parse_key("pagedown").unwrap(), KeyBinding::new(KeyCode::PageDown));
+        assert_eq!(parse_key("space").unwrap(), KeyBinding::new(KeyCode::Char(' ')));
+        assert_eq!(parse_key("x").unwrap(), KeyBinding::new(KeyCode::Char('x')));
+        assert_eq!(
+            parse_key("ctrl+d").unwrap(),
+            KeyBinding { code: KeyCode::Char('d'), modifiers: KeyModifiers::CONTROL }
+        );
+        assert!(parse_key("xy").is_err());
+        assert!(parse_key("").is_err());
+        assert!(parse_key("shift+x").is_err());
+    }
+
+    #[test]
+    fn actions_parse_including_tab_numbers() {
+        assert_eq!(parse_action("scroll_down").unwrap(), InputEvent::ScrollDown);
+        assert_eq!(parse_action("reset_all").unwrap(), InputEvent::HardReset);
+        assert_eq!(parse_action("goto_tab_1").unwrap(), InputEvent::GotoTab(0));
+        assert_eq!(parse_action("goto_tab_8").unwrap(), InputEvent::GotoTab(7));
+        assert!(parse_action("goto_tab_0").is_err());
+        assert!(parse_action("goto_tab_9").is_err());
+        assert!(parse_action("launch_rockets").is_err());
+    }
+
+    #[test]
+    fn keymap_file_overrides_and_unbinds_keys() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "# comment\n\nx = quit\nq = none\nctrl+n = next_tab\n").unwrap();
+        let keymap = Keymap::load(KeymapScheme::Custom, Some(file.path()));
+        std::fs::write(file.path(), "x = quit\ny = fly\n").unwrap();
+        let broken = Keymap::load(KeymapScheme::Custom, Some(file.path()));
+
+        let keymap = keymap.unwrap();
+        let press = |code, modifiers| keymap.lookup(&KeyEvent::new(code, modifiers));
+        assert_eq!(press(KeyCode::Char('x'), KeyModifiers::NONE), Some(InputEvent::Quit));
+        assert_eq!(press(KeyCode::Char('q'), KeyModifiers::NONE), None);
+        assert_eq!(press(KeyCode::Char('n'), KeyModifiers::CONTROL), Some(InputEvent::NextTab));
+        // Shifted characters match by character, so R is Shift+r
+        assert_eq!(press(KeyCode::Char('R'), KeyModifiers::SHIFT), Some(InputEvent::HardReset));
+        // Custom builds on the arrows-only scheme
+        assert_eq!(press(KeyCode::Char('l'), KeyModifiers::NONE), None);
+
+        let error = format!("{:#}", broken.unwrap_err());
+        assert!(error.contains(":2:") && error.contains("unknown action `fly`"), "{}", error);
+        assert!(Keymap::load(KeymapScheme::Custom, None).is_err());
+    }
+}
@@ -1,26 +1,76 @@
+mod alerts;
+mod analysis;
 mod client;
+mod config;
 mod events;
+mod keymap;
 mod programs;
+mod prometheus;
+mod recorder;
+mod replay;
+mod rpc;
+mod snapshot;
 mod state;
+mod theme;
 mod ui;
 
-use std::io;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{Context, Result};
+use arboard::Clipboard;
+use clap::{CommandFactory, Parser};
 use crossterm::{
+    cursor,
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use num_format::Locale;
 use ratatui::{backend::CrosstermBackend, Terminal};
+use solana_sdk::pubkey::Pubkey;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
 
-use crate::client::{start_client, ClientMessage};
+use crate::alerts::{check_alerts, AlertThresholds};
+use crate::client::{endpoint_regions, start_client, start_replay, ClientConfig, ClientMessage, EntryFormat};
 use crate::events::{poll_event, InputEvent};
-use crate::state::AppState;
+use crate::keymap::{Keymap, KeymapScheme};
+use crate::programs::{apply_program_cache, apply_programs_file, apply_validators_file, start_registry_reloader};
+use crate::prometheus::start_metrics_server;
+use crate::recorder::start_csv_recorder;
+use crate::replay::{start_raw_recorder, FileReplay};
+use crate::rpc::{start_rpc_poller, RpcClient};
+use crate::snapshot::{write_programs_csv, Snapshot};
+use crate::state::{clamp_tick_rate, AppState, CounterScope, DEFAULT_EMA_ALPHA, DEFAULT_FLAGGED_SAMPLE_WEIGHT, DEFAULT_METRICS_WINDOW_SECS};
+use crate::theme::{CategoryColors, Theme, ThemeName};
+
+/// Tabs with their own shortcuts: `c` switches counter scope on Overview; `o` sorts leaders and `f`
+/// picks a region on Latency; `n` labels, Enter opens and `e` exports programs and `/` searches
+/// transactions on Programs; `t` filters bundles and Enter opens one on Competition; `w` edits the
+/// wallet on Wallet
+const OVERVIEW_TAB: usize = 0;
+const LATENCY_TAB: usize = 1;
+const PROGRAMS_TAB: usize = 3;
+const COMPETITION_TAB: usize = 5;
+const WALLET_TAB: usize = 7;
+
+/// Default `--log-file` filter when RUST_LOG isn't set: this crate at debug, dependencies at warn
+const LOG_FILE_FILTER: &str = "shredstream_tui=debug,warn";
+
+/// How long to wait for background tasks to finish on quit
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Set while the terminal is in raw mode on the alternate screen
+static TERMINAL_ACTIVE: AtomicBool = AtomicBool::new(false);
 
 #[derive(Parser, Debug)]
 #[command(name = "shredstream-tui")]
@@ -28,48 +78,437 @@ use crate::state::AppState;
 #[command(version = "0.1.0")]
 #[command(about = "Terminal UI for monitoring Jito ShredStream proxy", long_about = None)]
 struct Args {
-    /// gRPC endpoint for the ShredStream proxy
-    /// Example: http://127.0.0.1:50051
-    #[arg(short, long, env = "SHREDSTREAM_PROXY_URL", default_value = "http://127.0.0.1:50051")]
-    proxy_url: String,
+    /// TOML file of default settings, keyed by long flag name; flags and env vars take precedence
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// gRPC endpoint(s) for the ShredStream proxy, tried in order on failure
+    /// Example: http://127.0.0.1:50051,http://10.0.0.2:50051
+    #[arg(short, long, env = "SHREDSTREAM_PROXY_URL", value_delimiter = ',', default_value = "http://127.0.0.1:50051")]
+    proxy_url: Vec<String>,
 
-    /// Tick rate in milliseconds for UI refresh
+    /// Region label for latency samples: one for all endpoints, or one per --proxy-url endpoint in order.
+    /// Derived from the endpoint host (e.g. ny, amsterdam) when omitted
+    #[arg(long, value_delimiter = ',')]
+    region: Vec<String>,
+
+    /// TLS server name to verify when it differs from the proxy URL host (https:// only)
+    #[arg(long)]
+    tls_domain: Option<String>,
+
+    /// Tick rate in milliseconds for UI refresh (16-2000; adjust at runtime with +/-)
     #[arg(short, long, default_value = "100")]
     tick_rate: u64,
 
+    /// Reconnect if a connected proxy sends no entries for this many seconds (0 disables)
+    #[arg(long, value_name = "SECS", default_value = "30")]
+    stall_timeout: u64,
+
+    /// Send a heartbeat RPC to the proxy this often while connected (0 disables).
+    /// Needs an endpoint serving the Shredstream heartbeat service, which a stock proxy doesn't.
+    #[arg(long, value_name = "SECS", default_value = "0")]
+    heartbeat_interval: u64,
+
+    /// Stop retrying after this many failed connection attempts in a row until `p` is pressed
+    /// (0 retries forever)
+    #[arg(long, value_name = "N", default_value = "0")]
+    max_reconnects: u32,
+
+    /// Entry payload layout to try first: legacy (fixed-width integers) or varint (bincode varint integers).
+    /// The other is tried if this one keeps failing
+    #[arg(long, value_enum, default_value = "legacy")]
+    entry_format: EntryFormat,
+
     /// Metrics window duration in seconds (how often to reset rate calculations)
-    #[arg(short, long, default_value = "10")]
+    #[arg(short, long, default_value_t = DEFAULT_METRICS_WINDOW_SECS)]
     metrics_window: u64,
+
+    /// Smoothing factor for the EMA txn rate on Overview, in (0, 1]; higher follows changes faster
+    #[arg(long, default_value_t = DEFAULT_EMA_ALPHA)]
+    ema_alpha: f64,
+
+    /// Keybinding scheme: vim (h/j/k/l navigation), arrows-only, or custom
+    #[arg(long, value_enum, default_value = "vim")]
+    keymap: KeymapScheme,
+
+    /// File of `key = action` overrides used with `--keymap custom`
+    #[arg(long)]
+    keymap_file: Option<PathBuf>,
+
+    /// Seconds a transaction signature is remembered for duplicate detection
+    #[arg(long, default_value = "5")]
+    dedup_window_secs: u64,
+
+    /// Estimate turbine tree position from entry arrival order (heuristic)
+    #[arg(long)]
+    estimate_turbine: bool,
+
+    /// Detect wrapped-SOL wrap/unwrap instructions (extra per-transaction parsing)
+    #[arg(long)]
+    detect_wsol: bool,
+
+    /// Include vote transactions in transaction totals and rates
+    #[arg(long)]
+    count_votes: bool,
+
+    /// JSON file of extra program definitions: {"<program id>": {"name": "...", "category": "dex"}}
+    #[arg(long, value_name = "PATH")]
+    programs_file: Option<PathBuf>,
+
+    /// JSON file of validator names: {"<identity pubkey>": "name"}, shown on the Leaders tab
+    #[arg(long, value_name = "PATH")]
+    validators_file: Option<PathBuf>,
+
+    /// JSON file of program labels; loaded at startup and updated when a program is labeled with `n`
+    #[arg(long, value_name = "PATH")]
+    program_cache: Option<PathBuf>,
+
+    /// Color theme; forced to mono when NO_COLOR is set
+    #[arg(long, value_enum, default_value = "dark")]
+    theme: ThemeName,
+
+    /// Also reload --programs-file and --validators-file every N seconds (they always reload on SIGHUP)
+    #[arg(long, value_name = "SECS")]
+    programs_reload_secs: Option<u64>,
+
+    /// Program category colors, e.g. "dex=green,lending=blue,mev=#ffaa00"
+    #[arg(long, default_value = "")]
+    category_colors: CategoryColors,
+
+    /// Locale for number grouping, e.g. "de" or "fr"; unknown names fall back to "en"
+    #[arg(long, default_value = "en")]
+    locale: String,
+
+    /// Slot arrival delay SLA target in milliseconds; tracks the share of slots arriving at or under it
+    #[arg(long)]
+    sla_latency_ms: Option<f64>,
+
+    /// Restart SLA tracking with each metrics window instead of per session
+    #[arg(long)]
+    sla_reset_with_window: bool,
+
+    /// Raise an alert when the average slot arrival delay exceeds this many milliseconds
+    #[arg(long, value_name = "MS")]
+    alert_latency_ms: Option<f64>,
+
+    /// Raise an alert when the leader skip rate exceeds this percentage (needs --rpc-url)
+    #[arg(long, value_name = "PCT")]
+    alert_skip_pct: Option<f64>,
+
+    /// Serve Prometheus metrics at http://0.0.0.0:<PORT>/metrics
+    #[arg(long, value_name = "PORT")]
+    metrics_port: Option<u16>,
+
+    /// Append per-slot and bundle CSV files (slots.csv, bundles.csv) to this directory
+    #[arg(long, value_name = "DIR")]
+    record_csv: Option<PathBuf>,
+
+    /// Append JSON debug logs to this file (RUST_LOG overrides the level)
+    #[arg(long, value_name = "PATH")]
+    log_file: Option<PathBuf>,
+
+    /// Only list bundles tipping at least this much in Recent Bundles (toggle with t)
+    #[arg(long, value_name = "SOL")]
+    min_tip_sol: Option<f64>,
+
+    /// Start with Recent Slots and Recent Transactions limited to DEX and bundle activity (toggle with d)
+    #[arg(long)]
+    dex_only: bool,
+
+    /// How much more likely DEX and bundle transactions are to be sampled into Recent Transactions
+    /// than other transactions (1 samples evenly)
+    #[arg(long, value_name = "WEIGHT", default_value_t = DEFAULT_FLAGGED_SAMPLE_WEIGHT)]
+    flagged_sample_weight: f64,
+
+    /// Append every received slot and its raw entries to this file, for --replay
+    #[arg(long, value_name = "PATH")]
+    record_raw: Option<PathBuf>,
+
+    /// Process a --record-raw file instead of connecting to the proxy
+    #[arg(long, value_name = "PATH")]
+    replay: Option<PathBuf>,
+
+    /// Wallet to monitor on the Wallet tab; repeat or comma-separate to watch several
+    #[arg(long, value_name = "PUBKEY", value_delimiter = ',')]
+    wallet: Vec<Pubkey>,
+
+    /// Solana JSON-RPC endpoint for optional stake and schedule data
+    #[arg(long, env = "SHREDSTREAM_RPC_URL")]
+    rpc_url: Option<String>,
+
+    /// Ask for confirmation before quitting on q (Ctrl+C still quits immediately)
+    #[arg(long)]
+    confirm_quit: bool,
+
+    /// Run without the TUI: collect for --duration seconds, print a JSON snapshot to stdout and exit
+    #[arg(long)]
+    headless: bool,
+
+    /// Seconds to collect before printing the snapshot in --headless mode
+    #[arg(long, value_name = "SECS", default_value = "10")]
+    duration: u64,
+}
+
+/// The selected theme, or mono when NO_COLOR is set to any non-empty value (https://no-color.org)
+fn effective_theme(args: &Args) -> ThemeName {
+    match std::env::var_os("NO_COLOR") {
+        Some(v) if !v.is_empty() => ThemeName::Mono,
+        _ => args.theme,
+    }
+}
+
+/// Startup settings shown in the info overlay
+fn runtime_config(args: &Args) -> Vec<(&'static str, String)> {
+    fn on_off(enabled: bool) -> String {
+        if enabled { "on".to_string() } else { "off".to_string() }
+    }
+    fn or_off<T: ToString>(value: Option<T>) -> String {
+        value.map_or_else(|| "off".to_string(), |v| v.to_string())
+    }
+
+    vec![
+        ("Version", env!("CARGO_PKG_VERSION").to_string()),
+        ("Config file", or_off(args.config.as_ref().map(|path| path.display()))),
+        ("Proxy URL", args.proxy_url.join(", ")),
+        ("Region", if args.region.is_empty() { "from host".to_string() } else { args.region.join(", ") }),
+        ("TLS domain", or_off(args.tls_domain.as_ref())),
+        ("Stall timeout", if args.stall_timeout > 0 { format!("{} s", args.stall_timeout) } else { "off".to_string() }),
+        ("Heartbeat interval", if args.heartbeat_interval > 0 { format!("{} s", args.heartbeat_interval) } else { "off".to_string() }),
+        ("Max reconnects", if args.max_reconnects > 0 { args.max_reconnects.to_string() } else { "unlimited".to_string() }),
+        ("Entry format", args.entry_format.to_string()),
+        ("Tick rate", format!("{} ms", args.tick_rate)),
+        ("Metrics window", format!("{} s", args.metrics_window)),
+        ("EMA alpha", args.ema_alpha.to_string()),
+        ("Keymap", format!("{:?}", args.keymap)),
+        ("Theme", format!("{:?}", effective_theme(args))),
+        ("Dedup window", format!("{} s", args.dedup_window_secs)),
+        ("Locale", args.locale.clone()),
+        ("Validators file", or_off(args.validators_file.as_ref().map(|path| path.display()))),
+        ("Program cache", or_off(args.program_cache.as_ref().map(|path| path.display()))),
+        ("Turbine estimate", on_off(args.estimate_turbine)),
+        ("wSOL detection", on_off(args.detect_wsol)),
+        ("Count votes", on_off(args.count_votes)),
+        ("Arrival delay SLA", or_off(args.sla_latency_ms.map(|ms| format!("{} ms", ms)))),
+        ("RPC", or_off(args.rpc_url.as_ref())),
+        ("Arrival delay alert", or_off(args.alert_latency_ms.map(|ms| format!("{} ms", ms)))),
+        ("Skip rate alert", or_off(args.alert_skip_pct.map(|pct| format!("{}%", pct)))),
+        ("Metrics port", or_off(args.metrics_port)),
+        ("CSV recording", or_off(args.record_csv.as_ref().map(|dir| dir.display()))),
+        ("Log file", or_off(args.log_file.as_ref().map(|path| path.display()))),
+        ("Min bundle tip", or_off(args.min_tip_sol.map(|sol| format!("{} SOL", sol)))),
+        ("DEX-only lists", on_off(args.dex_only)),
+        ("Flagged sample weight", args.flagged_sample_weight.to_string()),
+        ("Raw recording", or_off(args.record_raw.as_ref().map(|path| path.display()))),
+        ("Replay", or_off(args.replay.as_ref().map(|path| path.display()))),
+        ("Confirm quit", on_off(args.confirm_quit)),
+        ("Headless", on_off(args.headless)),
+    ]
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Parse CLI arguments
-    let args = Args::parse();
+    let args = config::load_merged(&Args::command().get_matches())?;
+    anyhow::ensure!(args.metrics_window > 0, "--metrics-window must be at least 1 second");
+    anyhow::ensure!(
+        args.ema_alpha > 0.0 && args.ema_alpha <= 1.0,
+        "--ema-alpha must be in (0, 1], got {}",
+        args.ema_alpha
+    );
+    anyhow::ensure!(
+        args.proxy_url.iter().all(|url| !url.trim().is_empty()),
+        "--proxy-url must not contain empty endpoints"
+    );
+    anyhow::ensure!(
+        args.region.len() <= 1 || args.region.len() == args.proxy_url.len(),
+        "--region takes one label or one per --proxy-url endpoint ({} given for {} endpoints)",
+        args.region.len(),
+        args.proxy_url.len()
+    );
+    anyhow::ensure!(
+        args.flagged_sample_weight > 0.0,
+        "--flagged-sample-weight must be positive, got {}",
+        args.flagged_sample_weight
+    );
+    anyhow::ensure!(
+        args.min_tip_sol.is_none_or(|sol| sol >= 0.0),
+        "--min-tip-sol must not be negative"
+    );
+    anyhow::ensure!(
+        args.record_raw.is_none() || args.replay.is_none(),
+        "--record-raw can't be combined with --replay"
+    );
+    let regions = endpoint_regions(&args.proxy_url, &args.region);
+    let keymap = Keymap::load(args.keymap, args.keymap_file.as_deref())?;
 
-    // Initialize tracing for debug logging
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::from_default_env()
-                .add_directive(tracing::Level::WARN.into()),
+    // Initialize tracing: warnings to stderr, and everything down to debug to --log-file as JSON.
+    // The guard flushes the file writer when main returns.
+    let (file_layer, _log_guard) = match &args.log_file {
+        Some(path) => {
+            let (layer, guard) = json_file_layer(path)?;
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .with_writer(io::stderr)
+                .with_filter(EnvFilter::from_default_env().add_directive(tracing::Level::WARN.into())),
         )
-        .with_target(false)
+        .with(file_layer)
         .init();
 
     // Create application state
-    let state = Arc::new(AppState::new(args.proxy_url.clone()));
+    let source = match &args.replay {
+        Some(path) => format!("replay {}", path.display()),
+        None => args.proxy_url[0].clone(),
+    };
+    let mut state = AppState::new(source);
+    *state.region.get_mut() = regions[0].clone();
+    let theme = effective_theme(&args);
+    state.theme = Theme::from_name(theme);
+    state.category_colors = match theme {
+        ThemeName::Mono => CategoryColors::mono(),
+        _ => args.category_colors,
+    };
+    state.runtime_config = runtime_config(&args);
+    state.ema_alpha = args.ema_alpha;
+    state.metrics_window = Duration::from_secs(args.metrics_window);
+    state.competition_stats.track_landing = args.rpc_url.is_some();
+    state.min_tip_lamports = args.min_tip_sol.map(|sol| (sol * 1e9).round() as u64);
+    *state.tip_filter_enabled.get_mut() = state.min_tip_lamports.is_some();
+    *state.dex_only.get_mut() = args.dex_only;
+    state.flagged_sample_weight = args.flagged_sample_weight;
+    for wallet in &args.wallet {
+        state.wallet_monitor.add_wallet(*wallet);
+    }
+    state.confirm_quit = args.confirm_quit;
+    *state.tick_rate_ms.get_mut() = clamp_tick_rate(args.tick_rate);
+    state.metrics.count_votes = args.count_votes;
+    state.latency_stats.sla_target_us = args.sla_latency_ms.map(|ms| (ms * 1000.0) as i64);
+    state.latency_stats.sla_resets_with_window = args.sla_reset_with_window;
+    state.alert_thresholds = AlertThresholds {
+        latency_ms: args.alert_latency_ms,
+        skip_pct: args.alert_skip_pct,
+    };
+    if let Some(path) = &args.programs_file {
+        apply_programs_file(path, &state)?;
+    }
+    if let Some(path) = &args.validators_file {
+        apply_validators_file(path, &state)?;
+    }
+    if let Some(path) = &args.program_cache {
+        apply_program_cache(path, &mut state)?;
+    }
+    let unknown_locale = match Locale::from_name(&args.locale) {
+        Ok(locale) => {
+            state.locale = locale;
+            false
+        }
+        Err(_) => true,
+    };
+    let state = Arc::new(state);
+    if unknown_locale {
+        state.log_warn(format!("Unknown locale '{}', using en", args.locale));
+    }
     state.log_info("ShredStream TUI starting...");
-    state.log_info(format!("Connecting to proxy at {}", args.proxy_url));
+    match &args.replay {
+        Some(path) => state.log_info(format!("Replaying {}", path.display())),
+        None => state.log_info(format!("Connecting to proxy at {}", args.proxy_url.join(", "))),
+    }
 
     // Create channel for client messages
     let (client_tx, mut client_rx) = mpsc::channel::<ClientMessage>(1000);
 
+    // Start the optional recorders
+    let mut recorder_handles = Vec::new();
+    let recorder = match &args.record_csv {
+        Some(dir) => {
+            let (recorder, handle) = start_csv_recorder(dir.clone()).await?;
+            state.log_info(format!("Recording CSV to {}", dir.display()));
+            recorder_handles.push(handle);
+            Some(recorder)
+        }
+        None => None,
+    };
+    let raw_recorder = match &args.record_raw {
+        Some(path) => {
+            let (recorder, handle) = start_raw_recorder(path.clone()).await?;
+            state.log_info(format!("Recording raw entries to {}", path.display()));
+            recorder_handles.push(handle);
+            Some(recorder)
+        }
+        None => None,
+    };
+
     // Start the gRPC client in background
     let client_state = Arc::clone(&state);
-    let _client_handle = start_client(args.proxy_url.clone(), client_state, client_tx);
+    let client_config = ClientConfig {
+        proxy_urls: args.proxy_url.clone(),
+        regions,
+        tls_domain: args.tls_domain.clone(),
+        dedup_window: Duration::from_secs(args.dedup_window_secs),
+        estimate_turbine: args.estimate_turbine,
+        detect_wsol: args.detect_wsol,
+        recorder,
+        raw_recorder,
+        stall_timeout: (args.stall_timeout > 0).then(|| Duration::from_secs(args.stall_timeout)),
+        entry_format: args.entry_format,
+        heartbeat_interval: (args.heartbeat_interval > 0).then(|| Duration::from_secs(args.heartbeat_interval)),
+        max_reconnects: (args.max_reconnects > 0).then_some(args.max_reconnects),
+    };
+    let cancel = CancellationToken::new();
+    let client_handle = match &args.replay {
+        Some(path) => {
+            let source = FileReplay::open(path).await?;
+            start_replay(source, client_config, client_state, client_tx, cancel.clone())
+        }
+        None => start_client(client_config, client_state, client_tx, cancel.clone()),
+    };
+
+    // Start the optional RPC poller
+    let _rpc_handle = match &args.rpc_url {
+        Some(url) => {
+            state.log_info(format!("Using RPC at {}", url));
+            Some(start_rpc_poller(RpcClient::new(url.clone())?, Arc::clone(&state)))
+        }
+        None => None,
+    };
+
+    // Reload the programs and validators files on SIGHUP or a timer
+    let _registry_handle = (args.programs_file.is_some() || args.validators_file.is_some()).then(|| {
+        let interval = args.programs_reload_secs.map(Duration::from_secs);
+        start_registry_reloader(
+            args.programs_file.clone(),
+            args.validators_file.clone(),
+            interval,
+            Arc::clone(&state),
+            cancel.clone(),
+        )
+    });
+
+    // Start the optional Prometheus endpoint
+    let _metrics_handle = match args.metrics_port {
+        Some(port) => {
+            let handle = start_metrics_server(port, Arc::clone(&state)).await?;
+            state.log_info(format!("Serving metrics on port {}", port));
+            Some(handle)
+        }
+        None => None,
+    };
+
+    if args.headless {
+        let result = run_headless(&state, &mut client_rx, Duration::from_secs(args.duration), &mut io::stdout()).await;
+        shutdown(cancel, client_handle, recorder_handles).await;
+        return result;
+    }
 
     // Set up terminal
+    install_panic_hook();
     enable_raw_mode()?;
+    TERMINAL_ACTIVE.store(true, Ordering::SeqCst);
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
@@ -77,16 +516,11 @@ async fn main() -> Result<()> {
     terminal.clear()?;
 
     // Run the main event loop
-    let result = run_app(&mut terminal, state, &mut client_rx, &args).await;
+    let result = run_app(&mut terminal, state, &mut client_rx, &keymap).await;
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    shutdown(cancel, client_handle, recorder_handles).await;
+
+    restore_terminal()?;
 
     if let Err(e) = result {
         eprintln!("Error: {}", e);
@@ -95,15 +529,111 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// A tracing layer appending JSON lines to `path`, with the guard that flushes it on drop
+fn json_file_layer<S>(path: &Path) -> Result<(impl Layer<S>, WorkerGuard)>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open log file {}", path.display()))?;
+    let (writer, guard) = tracing_appender::non_blocking(file);
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(LOG_FILE_FILTER));
+    Ok((tracing_subscriber::fmt::layer().json().with_writer(writer).with_filter(filter), guard))
+}
+
+/// Leave raw mode and the alternate screen. Only the first call after setup does anything,
+/// so the panic hook and the normal exit path can both call it.
+fn restore_terminal() -> io::Result<()> {
+    restore_once(&TERMINAL_ACTIVE, || {
+        disable_raw_mode()?;
+        execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, cursor::Show)
+    })
+}
+
+/// Run `restore` if `active` is still set, clearing it so later calls do nothing
+fn restore_once(active: &AtomicBool, restore: impl FnOnce() -> io::Result<()>) -> io::Result<()> {
+    if !active.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
+    restore()
+}
+
+/// Restore the terminal before the default hook prints a panic from the UI thread.
+///
+/// Panics in background tasks leave the TUI running, so the terminal is left alone for those.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().name() == Some("main") {
+            let _ = restore_terminal();
+        }
+        default_hook(info);
+    }));
+}
+
+/// Stop the client, which drops the recorders' senders so the writers flush and exit
+async fn shutdown(
+    cancel: CancellationToken,
+    client_handle: JoinHandle<()>,
+    recorder_handles: Vec<JoinHandle<()>>,
+) {
+    cancel.cancel();
+    if tokio::time::timeout(SHUTDOWN_TIMEOUT, client_handle).await.is_err() {
+        tracing::warn!("Client did not stop within {:?}", SHUTDOWN_TIMEOUT);
+    }
+    for handle in recorder_handles {
+        if tokio::time::timeout(SHUTDOWN_TIMEOUT, handle).await.is_err() {
+            tracing::warn!("Recorder did not flush within {:?}", SHUTDOWN_TIMEOUT);
+        }
+    }
+}
+
+/// Collect for `duration` without a terminal, then print a snapshot as JSON to `out`.
+///
+/// Fails if the proxy was never reached, so scripts can tell an empty snapshot from a quiet one.
+async fn run_headless(
+    state: &AppState,
+    client_rx: &mut mpsc::Receiver<ClientMessage>,
+    duration: Duration,
+    out: &mut impl Write,
+) -> Result<()> {
+    let deadline = tokio::time::sleep(duration);
+    tokio::pin!(deadline);
+
+    loop {
+        tokio::select! {
+            _ = &mut deadline => break,
+            msg = client_rx.recv() => match msg {
+                Some(ClientMessage::ConnectionChanged(conn_state)) => state.set_connection_state(conn_state),
+                Some(ClientMessage::Error(e)) => tracing::warn!("Client error: {}", e),
+                Some(ClientMessage::EntriesReceived { .. }) => {}
+                None => break,
+            },
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&Snapshot::from_state(state))?;
+    writeln!(out, "{}", json)?;
+
+    anyhow::ensure!(
+        state.connected_at.read().is_some(),
+        "Never connected to proxy at {}",
+        state.proxy_url.read()
+    );
+    Ok(())
+}
+
 async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     state: Arc<AppState>,
     client_rx: &mut mpsc::Receiver<ClientMessage>,
-    args: &Args,
+    keymap: &Keymap,
 ) -> Result<()> {
-    let tick_duration = Duration::from_millis(args.tick_rate);
-    let metrics_window_duration = Duration::from_secs(args.metrics_window);
-    let mut last_metrics_reset = std::time::Instant::now();
+    // Created on first copy and kept alive, since on X11 the owner must live to serve the text
+    let mut clipboard: Option<Clipboard> = None;
 
     loop {
         // Draw the UI
@@ -112,9 +642,10 @@ async fn run_app(
         // Process any pending client messages (non-blocking)
         while let Ok(msg) = client_rx.try_recv() {
             match msg {
-                ClientMessage::EntriesReceived { .. } => {
-                    // Entries are already processed in the client
-                    // We could add additional processing here if needed
+                ClientMessage::EntriesReceived { slot, sent_at, .. } => {
+                    // Entries are already processed in the client; only the queueing delay is recorded here
+                    state.latency_stats.budget.record_queue(sent_at.elapsed());
+                    state.last_rendered_slot.fetch_max(slot, std::sync::atomic::Ordering::Relaxed);
                 }
                 ClientMessage::ConnectionChanged(conn_state) => {
                     state.set_connection_state(conn_state);
@@ -126,48 +657,189 @@ async fn run_app(
         }
 
         // Handle input events
-        if let Some(event) = poll_event(tick_duration) {
+        let raw_keys = state.is_editing_text() || state.is_quit_pending();
+        if let Some(event) = poll_event(state.tick_rate(), keymap, raw_keys) {
             let show_help = *state.show_help.read();
+            let show_info = *state.show_info.read();
+            let overlay_open = show_help || show_info || state.is_editing_text()
+                || state.bundle_detail.read().is_some() || state.program_detail.read().is_some();
             
             match event {
-                InputEvent::Quit => {
+                InputEvent::ForceQuit => {
                     state.log_info("Shutting down...");
                     break;
                 }
-                InputEvent::CloseOverlay if show_help => {
-                    state.toggle_help();
+                InputEvent::Quit => {
+                    if state.request_quit() {
+                        state.log_info("Shutting down...");
+                        break;
+                    }
+                }
+                InputEvent::TextKey(code) if state.is_quit_pending() => {
+                    if state.answer_quit(code) {
+                        state.log_info("Shutting down...");
+                        break;
+                    }
+                }
+                InputEvent::TextKey(code) => {
+                    state.text_input_key(code);
+                }
+                InputEvent::CycleSort if !overlay_open && *state.selected_tab.read() == LATENCY_TAB => {
+                    state.cycle_leader_sort();
+                    *state.selected_row.write() = 0;
+                }
+                InputEvent::CycleRegion if !overlay_open && *state.selected_tab.read() == LATENCY_TAB => {
+                    state.cycle_latency_region();
+                    *state.selected_row.write() = 0;
+                }
+                InputEvent::LabelProgram if !overlay_open && *state.selected_tab.read() == PROGRAMS_TAB => {
+                    let rows = ui::selectable_rows(&state);
+                    match state.selected_row_in(rows.len()).and_then(|i| rows[i].parse().ok()) {
+                        Some(program) => state.open_label_input(program),
+                        None => state.log_warn("Select a program to label"),
+                    }
+                }
+                InputEvent::Search if !overlay_open && *state.selected_tab.read() == PROGRAMS_TAB => {
+                    state.open_signature_search();
+                }
+                InputEvent::ToggleTipFilter if !overlay_open && *state.selected_tab.read() == COMPETITION_TAB => {
+                    if state.min_tip_lamports.is_some() {
+                        let mut enabled = state.tip_filter_enabled.write();
+                        *enabled = !*enabled;
+                        *state.selected_row.write() = 0;
+                    } else {
+                        state.log_warn("Set --min-tip-sol to filter bundles by tip");
+                    }
+                }
+                InputEvent::ToggleDexOnly if !overlay_open => {
+                    let mut dex_only = state.dex_only.write();
+                    *dex_only = !*dex_only;
+                    state.set_notice(if *dex_only { "DEX-only lists on" } else { "DEX-only lists off" });
+                }
+                InputEvent::OpenDetail if !overlay_open && *state.selected_tab.read() == PROGRAMS_TAB => {
+                    let rows = ui::selectable_rows(&state);
+                    match state.selected_row_in(rows.len()).and_then(|i| rows[i].parse().ok()) {
+                        Some(program) => *state.program_detail.write() = Some(program),
+                        None => state.log_warn("Select a program to show its activity"),
+                    }
+                }
+                InputEvent::OpenDetail if !overlay_open && *state.selected_tab.read() == COMPETITION_TAB => {
+                    match ui::selected_bundle(&state) {
+                        Some(bundle) => *state.bundle_detail.write() = Some(bundle),
+                        None => state.log_warn("Select a bundle to show its details"),
+                    }
+                }
+                InputEvent::ToggleScope if !overlay_open && *state.selected_tab.read() == OVERVIEW_TAB => {
+                    state.set_notice(match state.toggle_counter_scope() {
+                        CounterScope::AllTime => "Counters: all time",
+                        CounterScope::Connection => "Counters: since connect",
+                    });
+                }
+                InputEvent::EditWallet if !overlay_open && *state.selected_tab.read() == WALLET_TAB => {
+                    state.open_wallet_input();
                 }
-                InputEvent::ToggleHelp => {
+                InputEvent::CloseOverlay if overlay_open => {
+                    state.close_overlays();
+                }
+                InputEvent::ToggleHelp if !show_info => {
                     state.toggle_help();
                 }
-                InputEvent::NextTab if !show_help => {
+                InputEvent::ToggleInfo if !show_help => {
+                    state.toggle_info();
+                }
+                InputEvent::NextTab if !overlay_open => {
                     state.next_tab();
                 }
-                InputEvent::PrevTab if !show_help => {
+                InputEvent::PrevTab if !overlay_open => {
                     state.prev_tab();
                 }
-                InputEvent::ScrollUp if !show_help => {
+                InputEvent::GotoTab(index) if !overlay_open => {
+                    state.set_tab(index);
+                }
+                InputEvent::ScrollUp if !overlay_open => {
                     state.scroll_up();
                 }
-                InputEvent::ScrollDown if !show_help => {
-                    state.scroll_down();
+                InputEvent::ScrollDown if !overlay_open => {
+                    state.scroll_down(ui::selectable_rows(&state).len());
+                }
+                InputEvent::PageUp if !overlay_open => {
+                    state.page_up();
+                }
+                InputEvent::PageDown if !overlay_open => {
+                    state.page_down(ui::selectable_rows(&state).len());
+                }
+                InputEvent::ScrollTop if !overlay_open => {
+                    state.scroll_top();
+                }
+                InputEvent::ScrollBottom if !overlay_open => {
+                    state.scroll_bottom(ui::selectable_rows(&state).len());
                 }
-                InputEvent::ResetMetrics if !show_help => {
+                InputEvent::ResetMetrics if !overlay_open => {
                     state.reset_metrics_window();
                     state.log_info("Metrics window reset");
                 }
+                InputEvent::Reconnect if !overlay_open => {
+                    if state.request_reconnect() {
+                        state.set_notice("Retrying connection");
+                    }
+                }
+                InputEvent::HardReset if !overlay_open => {
+                    state.reset_all();
+                    state.log_info("All statistics reset");
+                    state.set_notice("All statistics reset");
+                }
+                InputEvent::SpeedUp | InputEvent::SpeedDown if !overlay_open => {
+                    let ms = state.adjust_tick_rate(matches!(event, InputEvent::SpeedUp));
+                    state.set_notice(format!("Tick rate {} ms", ms));
+                }
+                InputEvent::ToggleRateUnit if !overlay_open => {
+                    state.toggle_rate_unit();
+                }
+                InputEvent::Copy if !overlay_open => {
+                    let rows = ui::selectable_rows(&state);
+                    match state.selected_row_in(rows.len()).map(|i| &rows[i]) {
+                        Some(text) => {
+                            let result = match clipboard.as_mut() {
+                                Some(clipboard) => clipboard.set_text(text.as_str()),
+                                None => Clipboard::new().and_then(|c| clipboard.insert(c).set_text(text.as_str())),
+                            };
+                            match result {
+                                Ok(()) => state.log_info(format!("Copied {}", text.replace('\n', " "))),
+                                Err(e) => state.log_error(format!("Clipboard copy failed: {}", e)),
+                            }
+                        }
+                        None => state.log_warn("Nothing selected to copy on this tab"),
+                    }
+                }
+                InputEvent::TogglePause if !overlay_open => {
+                    state.toggle_pause();
+                }
+                InputEvent::ExportPrograms if !overlay_open && *state.selected_tab.read() == PROGRAMS_TAB => {
+                    match write_programs_csv(&state.program_stats.get_top_programs(usize::MAX)) {
+                        Ok(path) => state.log_info(format!("Programs written to {}", path.display())),
+                        Err(e) => state.log_error(format!("Programs export failed: {:#}", e)),
+                    }
+                }
+                InputEvent::ExportSnapshot if !overlay_open => {
+                    match Snapshot::from_state(&state).write_to_file() {
+                        Ok(path) => state.log_info(format!("Snapshot written to {}", path.display())),
+                        Err(e) => state.log_error(format!("Snapshot export failed: {:#}", e)),
+                    }
+                }
                 InputEvent::Tick => {
-                    // Regular tick - check if we need to reset metrics window
-                    if last_metrics_reset.elapsed() >= metrics_window_duration {
-                        // Don't reset cumulative, just the window metrics for rate calc
-                        // The state already handles this internally
-                        last_metrics_reset = std::time::Instant::now();
+                    state.update_ema_txns_per_sec();
+                    state.update_mev_index();
+                    check_alerts(&state);
+
+                    // Roll the metrics window over; cumulative counters are kept
+                    if state.metrics_window_remaining().is_zero() {
+                        state.reset_metrics_window();
                     }
                 }
                 _ => {
-                    // Close help on any key if showing
-                    if show_help {
-                        state.toggle_help();
+                    // Close any overlay on other keys
+                    if overlay_open {
+                        state.close_overlays();
                     }
                 }
             }
@@ -176,3 +848,112 @@ async fn run_app(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_entry::entry::Entry;
+
+    fn replay_config() -> ClientConfig {
+        ClientConfig {
+            proxy_urls: vec!["http://127.0.0.1:50051".to_string()],
+            regions: vec![None],
+            tls_domain: None,
+            dedup_window: Duration::from_secs(60),
+            estimate_turbine: false,
+            detect_wsol: false,
+            recorder: None,
+            raw_recorder: None,
+            stall_timeout: None,
+            entry_format: EntryFormat::Legacy,
+            heartbeat_interval: None,
+            max_reconnects: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn headless_prints_a_json_snapshot_of_a_mock_stream() {
+        // Two slots of entries in the --record-raw layout, replayed in place of the proxy
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let mut raw = Vec::new();
+        for slot in [100u64, 101] {
+            let payload = bincode::serialize(&vec![Entry::default(), Entry::default(), Entry::default()]).unwrap();
+            raw.extend_from_slice(&slot.to_le_bytes());
+            raw.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+            raw.extend_from_slice(&payload);
+        }
+        std::fs::write(file.path(), raw).unwrap();
+
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        let (tx, mut rx) = mpsc::channel(1000);
+        let source = FileReplay::open(file.path()).await.unwrap();
+        let handle = start_replay(source, replay_config(), Arc::clone(&state), tx, CancellationToken::new());
+
+        let mut out = Vec::new();
+        let result = run_headless(&state, &mut rx, Duration::from_secs(5), &mut out).await;
+        handle.await.unwrap();
+        assert!(result.is_ok(), "{:?}", result);
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(!text.contains('\x1b'), "ANSI escape in {:?}", text);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["proxy_url"], "http://127.0.0.1:50051");
+        assert_eq!(json["current_slot"], 101);
+        assert_eq!(json["total_entries"], 6);
+        assert_eq!(json["total_txns"], 0);
+        assert!(json["top_programs"].is_array());
+    }
+
+    #[tokio::test]
+    async fn headless_fails_but_still_prints_when_never_connected() {
+        let state = AppState::new("http://127.0.0.1:1".to_string());
+        let (tx, mut rx) = mpsc::channel(1);
+        drop(tx);
+
+        let mut out = Vec::new();
+        let result = run_headless(&state, &mut rx, Duration::from_secs(5), &mut out).await;
+        assert!(result.unwrap_err().to_string().contains("Never connected"));
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json["total_entries"], 0);
+    }
+
+    #[test]
+    fn terminal_restore_runs_once_across_panic_and_normal_exit() {
+        let active = AtomicBool::new(true);
+        let restores = std::sync::atomic::AtomicUsize::new(0);
+        let restore = || {
+            restores.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        };
+
+        // The panic hook fires first, then the normal exit path runs as well
+        let panicked = std::panic::catch_unwind(|| {
+            restore_once(&active, restore).unwrap();
+            panic!("UI thread panic");
+        });
+        assert!(panicked.is_err());
+        restore_once(&active, restore).unwrap();
+        restore_once(&active, restore).unwrap();
+
+        assert_eq!(restores.load(Ordering::SeqCst), 1);
+        assert!(!active.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn log_file_gets_one_json_line_per_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.json");
+        let (layer, guard) = json_file_layer(&path).unwrap();
+        tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+            tracing::warn!(slot = 42, "Slot arrived late");
+        });
+        // Dropping the guard flushes the background writer
+        drop(guard);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let line: serde_json::Value = serde_json::from_str(contents.lines().last().unwrap()).unwrap();
+        assert_eq!(line["level"], "WARN");
+        assert_eq!(line["fields"]["message"], "Slot arrived late");
+        assert_eq!(line["fields"]["slot"], 42);
+    }
+}
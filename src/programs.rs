@@ -1,5 +1,15 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
 use solana_sdk::pubkey::Pubkey;
+use tokio_util::sync::CancellationToken;
+
+use crate::state::AppState;
 
 /// Well-known program IDs for MEV-relevant protocols
 pub struct KnownPrograms;
@@ -39,6 +49,14 @@ impl KnownPrograms {
     pub const TOKEN_2022: &'static str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
     pub const ASSOCIATED_TOKEN: &'static str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
     
+    // Native Programs
+    pub const SYSTEM_PROGRAM: &'static str = "11111111111111111111111111111111";
+    pub const COMPUTE_BUDGET: &'static str = "ComputeBudget111111111111111111111111111111";
+    pub const VOTE_PROGRAM: &'static str = "Vote111111111111111111111111111111111111111";
+    
+    // Mints
+    pub const NATIVE_MINT: &'static str = "So11111111111111111111111111111111111111112";
+    
     pub fn get_all() -> HashMap<Pubkey, ProgramInfo> {
         let mut map = HashMap::new();
         
@@ -102,7 +120,23 @@ impl std::fmt::Display for ProgramCategory {
     }
 }
 
-#[derive(Debug, Clone)]
+impl FromStr for ProgramCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "dex" => Ok(ProgramCategory::Dex),
+            "lending" => Ok(ProgramCategory::Lending),
+            "staking" => Ok(ProgramCategory::Staking),
+            "mev" => Ok(ProgramCategory::Mev),
+            "token" => Ok(ProgramCategory::Token),
+            "other" => Ok(ProgramCategory::Other),
+            other => Err(format!("unknown category `{}`", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProgramInfo {
     pub name: String,
     pub category: ProgramCategory,
@@ -117,6 +151,219 @@ impl ProgramInfo {
     }
 }
 
+/// A program definition from `--programs-file`
+#[derive(Debug, Deserialize)]
+struct CustomProgram {
+    name: String,
+    category: String,
+}
+
+/// Load user program definitions from a JSON object of
+/// `{ "<program id>": { "name": "...", "category": "dex" } }`.
+///
+/// Returns the valid entries along with a message for each skipped one.
+pub fn load_programs_file(path: &Path) -> Result<(HashMap<Pubkey, ProgramInfo>, Vec<String>)> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read programs file {}", path.display()))?;
+    let entries: HashMap<String, serde_json::Value> = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse programs file {}", path.display()))?;
+
+    let mut programs = HashMap::new();
+    let mut skipped = Vec::new();
+    for (id, value) in entries {
+        let parsed = id
+            .parse::<Pubkey>()
+            .map_err(|e| format!("invalid program id: {}", e))
+            .and_then(|program_id| {
+                let custom: CustomProgram = serde_json::from_value(value).map_err(|e| e.to_string())?;
+                let category = custom.category.parse::<ProgramCategory>()?;
+                Ok((program_id, ProgramInfo { name: custom.name, category }))
+            });
+        match parsed {
+            Ok((program_id, info)) => {
+                programs.insert(program_id, info);
+            }
+            Err(e) => skipped.push(format!("Skipping program `{}` in {}: {}", id, path.display(), e)),
+        }
+    }
+
+    Ok((programs, skipped))
+}
+
+/// Load the programs file into `state`, logging skipped entries and the number of changes
+pub fn apply_programs_file(path: &Path, state: &AppState) -> Result<()> {
+    let (programs, skipped) = load_programs_file(path)?;
+    for message in skipped {
+        state.log_warn(message);
+    }
+    let count = programs.len();
+    let changed = state.program_stats.set_custom_programs(programs);
+    state.log_info(format!(
+        "Loaded {} custom programs from {} ({} entries changed)",
+        count,
+        path.display(),
+        changed
+    ));
+    Ok(())
+}
+
+/// Load program labels saved by an earlier run, as a JSON object of `{ "<program id>": "label" }`.
+///
+/// A missing file is an empty cache. Returns the valid entries along with a message for each skipped one.
+pub fn load_program_cache(path: &Path) -> Result<(HashMap<Pubkey, String>, Vec<String>)> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((HashMap::new(), Vec::new())),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read program cache {}", path.display())),
+    };
+    let entries: HashMap<String, String> = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse program cache {}", path.display()))?;
+
+    let mut labels = HashMap::new();
+    let mut skipped = Vec::new();
+    for (id, label) in entries {
+        match id.parse::<Pubkey>() {
+            Ok(program_id) => {
+                labels.insert(program_id, label);
+            }
+            Err(e) => skipped.push(format!("Skipping program `{}` in {}: invalid program id: {}", id, path.display(), e)),
+        }
+    }
+
+    Ok((labels, skipped))
+}
+
+/// Write all program labels to the cache file, sorted by program ID so the file diffs cleanly
+pub fn save_program_cache(path: &Path, labels: &HashMap<Pubkey, String>) -> Result<()> {
+    let entries: std::collections::BTreeMap<String, &String> =
+        labels.iter().map(|(id, label)| (id.to_string(), label)).collect();
+    let json = serde_json::to_string_pretty(&entries).context("Failed to serialize program cache")?;
+    std::fs::write(path, json).with_context(|| format!("Failed to write program cache {}", path.display()))
+}
+
+/// Load the program cache into `state` and remember where to save new labels
+pub fn apply_program_cache(path: &Path, state: &mut AppState) -> Result<()> {
+    let (labels, skipped) = load_program_cache(path)?;
+    for message in skipped {
+        state.log_warn(message);
+    }
+    let count = labels.len();
+    for (program_id, label) in labels {
+        state.program_stats.label_program(program_id, label);
+    }
+    state.program_cache = Some(path.to_path_buf());
+    state.log_info(format!("Loaded {} program labels from {}", count, path.display()));
+    Ok(())
+}
+
+/// Load validator names from a JSON object of `{ "<identity pubkey>": "name" }`.
+///
+/// Returns the valid entries along with a message for each skipped one.
+pub fn load_validators_file(path: &Path) -> Result<(HashMap<Pubkey, String>, Vec<String>)> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read validators file {}", path.display()))?;
+    let entries: HashMap<String, String> = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse validators file {}", path.display()))?;
+
+    let mut names = HashMap::new();
+    let mut skipped = Vec::new();
+    for (id, name) in entries {
+        match id.parse::<Pubkey>() {
+            Ok(identity) => {
+                names.insert(identity, name);
+            }
+            Err(e) => skipped.push(format!("Skipping validator `{}` in {}: invalid pubkey: {}", id, path.display(), e)),
+        }
+    }
+
+    Ok((names, skipped))
+}
+
+/// Load `--validators-file` into `state`, replacing the names loaded before
+pub fn apply_validators_file(path: &Path, state: &AppState) -> Result<()> {
+    let (names, skipped) = load_validators_file(path)?;
+    for message in skipped {
+        state.log_warn(message);
+    }
+    let count = names.len();
+    let changed = state.leader_tracker.set_validator_names(names);
+    state.log_info(format!(
+        "Loaded {} validator names from {} ({} entries changed)",
+        count,
+        path.display(),
+        changed
+    ));
+    Ok(())
+}
+
+/// SIGHUP listener that never fires where signals aren't available
+struct Hangup(#[cfg(unix)] Option<tokio::signal::unix::Signal>);
+
+impl Hangup {
+    fn new() -> Self {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+            Self(signal(SignalKind::hangup()).ok())
+        }
+        #[cfg(not(unix))]
+        {
+            Self()
+        }
+    }
+
+    async fn recv(&mut self) {
+        #[cfg(unix)]
+        if let Some(signal) = &mut self.0 {
+            if signal.recv().await.is_some() {
+                return;
+            }
+            self.0 = None;
+        }
+        std::future::pending::<()>().await
+    }
+}
+
+/// Reload the programs and validators files on SIGHUP and, if set, every `interval`, until
+/// `cancel` fires
+pub fn start_registry_reloader(
+    programs_file: Option<PathBuf>,
+    validators_file: Option<PathBuf>,
+    interval: Option<Duration>,
+    state: Arc<AppState>,
+    cancel: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut hangup = Hangup::new();
+        let mut timer = interval.map(|period| tokio::time::interval_at(tokio::time::Instant::now() + period, period));
+
+        loop {
+            tokio::select! {
+                _ = hangup.recv() => {}
+                _ = async {
+                    match timer.as_mut() {
+                        Some(timer) => { timer.tick().await; }
+                        None => std::future::pending::<()>().await,
+                    }
+                } => {}
+                _ = cancel.cancelled() => return,
+            }
+
+            // Keep the current definitions if a file is unreadable mid-edit
+            if let Some(path) = &programs_file {
+                if let Err(e) = apply_programs_file(path, &state) {
+                    state.log_error(format!("Programs reload failed: {:#}", e));
+                }
+            }
+            if let Some(path) = &validators_file {
+                if let Err(e) = apply_validators_file(path, &state) {
+                    state.log_error(format!("Validators reload failed: {:#}", e));
+                }
+            }
+        }
+    })
+}
+
 /// Known MEV bot addresses (add more as discovered)
 pub struct KnownBots;
 
@@ -164,3 +411,93 @@ pub const JITO_TIP_ACCOUNTS: [&str; 8] = [
     "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
     "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    /// Write `contents` to a temporary file, removed when it's dropped
+    fn temp_file(contents: &str) -> NamedTempFile {
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), contents).unwrap();
+        file
+    }
+
+    #[test]
+    fn custom_programs_are_categorized_when_recorded() {
+        let custom = Pubkey::new_unique();
+        let file = temp_file(&format!(
+            r#"{{
+                "{}": {{ "name": "My AMM", "category": "DEX" }},
+                "not-a-pubkey": {{ "name": "Broken", "category": "dex" }},
+                "{}": {{ "name": "Odd", "category": "casino" }}
+            }}"#,
+            custom,
+            Pubkey::new_unique()
+        ));
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        apply_programs_file(file.path(), &state).unwrap();
+        let (_, skipped) = load_programs_file(file.path()).unwrap();
+        assert_eq!(skipped.len(), 2);
+
+        state.program_stats.record_program(custom, 100);
+        let activity = state.program_stats.activities.read()[&custom].clone();
+        assert_eq!((activity.name.as_str(), activity.category, activity.txn_count), ("My AMM", ProgramCategory::Dex, 1));
+        assert!(state.program_stats.known_program(&KnownPrograms::parse(KnownPrograms::RAYDIUM_V4)).is_some());
+    }
+
+    #[test]
+    fn program_cache_round_trips_through_runtime_labels() {
+        use crossterm::event::KeyCode;
+        let raydium = KnownPrograms::parse(KnownPrograms::RAYDIUM_V4);
+        let (cached, labeled) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("program-cache.json");
+        std::fs::write(&path, format!(r#"{{ "{}": "Ray", "{}": "Old label" }}"#, raydium, cached)).unwrap();
+
+        // Loading merges into the known programs, keeping the category of ones already known
+        let mut state = AppState::new("http://127.0.0.1:50051".to_string());
+        apply_program_cache(&path, &mut state).unwrap();
+        let known = state.program_stats.known_program(&raydium).unwrap();
+        assert_eq!((known.name.as_str(), known.category), ("Ray", ProgramCategory::Dex));
+        assert_eq!(state.program_stats.known_program(&cached).unwrap().name, "Old label");
+
+        // A label assigned at runtime is written back alongside the loaded ones
+        state.open_label_input(labeled);
+        for c in "Fresh".chars() {
+            state.text_input_key(KeyCode::Char(c));
+        }
+        state.text_input_key(KeyCode::Enter);
+        let (labels, skipped) = load_program_cache(&path).unwrap();
+        assert!(skipped.is_empty());
+        assert_eq!(labels.len(), 3);
+        assert_eq!(labels[&raydium], "Ray");
+        assert_eq!(labels[&cached], "Old label");
+        assert_eq!(labels[&labeled], "Fresh");
+
+        // A missing cache is just empty, so the first run can create it
+        assert!(load_program_cache(&dir.path().join("missing.json")).unwrap().0.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reloader_picks_up_validator_changes_until_cancelled() {
+        let (kept, renamed, added) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let file = temp_file(&format!(r#"{{ "{}": "Kept", "{}": "Old" }}"#, kept, renamed));
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        apply_validators_file(file.path(), &state).unwrap();
+        assert_eq!(state.leader_tracker.validator_names.read()[&renamed], "Old");
+
+        let cancel = CancellationToken::new();
+        let reloader = start_registry_reloader(None, Some(file.path().to_path_buf()), Some(Duration::from_millis(20)), Arc::clone(&state), cancel.clone());
+        std::fs::write(file.path(), format!(r#"{{ "{}": "Kept", "{}": "New", "{}": "Added" }}"#, kept, renamed, added)).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancel.cancel();
+        let stopped = tokio::time::timeout(Duration::from_secs(1), reloader).await;
+
+        assert!(stopped.is_ok(), "the reloader kept running after cancel");
+        let names = state.leader_tracker.validator_names.read();
+        assert_eq!((names[&kept].as_str(), names[&renamed].as_str(), names[&added].as_str()), ("Kept", "New", "Added"));
+        assert!(state.logs.read().iter().any(|log| log.message.ends_with("(2 entries changed)")));
+    }
+}
@@ -0,0 +1,180 @@
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use crate::state::AppState;
+
+const CONTENT_TYPE_TEXT: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+fn write_metric(out: &mut String, name: &str, kind: &str, help: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+/// Render the current state in the Prometheus text exposition format
+pub fn render(state: &AppState) -> String {
+    let mut out = String::new();
+
+    write_metric(
+        &mut out,
+        "shredstream_entries_total",
+        "counter",
+        "Entries received since startup",
+        state.metrics.total_entries.load(Ordering::Relaxed),
+    );
+    write_metric(
+        &mut out,
+        "shredstream_txns_total",
+        "counter",
+        "Transactions received since startup",
+        state.metrics.total_txns.load(Ordering::Relaxed),
+    );
+    write_metric(
+        &mut out,
+        "shredstream_bundles_total",
+        "counter",
+        "Jito bundles detected since startup",
+        state.competition_stats.bundle_count.load(Ordering::Relaxed),
+    );
+    write_metric(
+        &mut out,
+        "shredstream_tips_lamports_total",
+        "counter",
+        "Jito tips paid in detected bundles, in lamports",
+        state.competition_stats.total_tips_lamports.load(Ordering::Relaxed),
+    );
+    if let Some(index) = state.mev_index.current() {
+        write_metric(
+            &mut out,
+            "shredstream_mev_index",
+            "gauge",
+            "Composite MEV activity index, 0-100",
+            index,
+        );
+    }
+    write_metric(
+        &mut out,
+        "shredstream_avg_slot_arrival_delay_ms",
+        "gauge",
+        "Average slot arrival delay against the projected slot start, in milliseconds; negative when slots arrive early",
+        state.latency_stats.avg_latency_ms(),
+    );
+    write_metric(
+        &mut out,
+        "shredstream_reconnects_total",
+        "counter",
+        "Reconnects to the proxy since startup",
+        state.reconnect_count.load(Ordering::Relaxed),
+    );
+    write_metric(
+        &mut out,
+        "shredstream_payload_bytes_total",
+        "counter",
+        "Entry payload bytes received from the proxy, before decoding",
+        state.metrics.total_bytes.load(Ordering::Relaxed),
+    );
+    write_metric(
+        &mut out,
+        "shredstream_decode_errors_total",
+        "counter",
+        "Entry payloads dropped because they could not be decoded",
+        state.metrics.deserialize_failures.load(Ordering::Relaxed),
+    );
+
+    out
+}
+
+async fn handle(state: Arc<AppState>, req: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    let response = match (req.method(), req.uri().path()) {
+        (&Method::GET, "/metrics") => Response::builder()
+            .header(CONTENT_TYPE, CONTENT_TYPE_TEXT)
+            .body(Full::new(Bytes::from(render(&state)))),
+        _ => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Full::new(Bytes::from_static(b"Not Found\n"))),
+    };
+    Ok(response.expect("static response parts are valid"))
+}
+
+/// Bind `/metrics` on the given port and serve it in a background task
+pub async fn start_metrics_server(port: u16, state: Arc<AppState>) -> Result<tokio::task::JoinHandle<()>> {
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics endpoint on {}", addr))?;
+
+    Ok(tokio::spawn(async move {
+        loop {
+            let (stream, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    tracing::warn!("Metrics endpoint accept failed: {}", e);
+                    continue;
+                }
+            };
+
+            let state = Arc::clone(&state);
+            tokio::spawn(async move {
+                let service = service_fn(move |req| handle(Arc::clone(&state), req));
+                if let Err(e) = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await {
+                    tracing::debug!("Metrics connection error: {}", e);
+                }
+            });
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    async fn get(port: u16, path: &str) -> String {
+        let mut stream = tokio::net::TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", path);
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn metrics_endpoint_serves_parseable_exposition() {
+        let state = Arc::new(AppState::new("http://127.0.0.1:50051".to_string()));
+        state.metrics.add_entry(12, 40);
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let server = start_metrics_server(port, Arc::clone(&state)).await.unwrap();
+
+        let response = get(port, "/metrics").await;
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200"), "{}", head);
+        assert!(head.contains(CONTENT_TYPE_TEXT), "{}", head);
+
+        let mut samples = std::collections::HashMap::new();
+        for line in body.lines().filter(|line| !line.starts_with('#')) {
+            let (name, value) = line.split_once(' ').unwrap();
+            samples.insert(name, value.parse::<f64>().unwrap());
+        }
+        for name in ["shredstream_entries_total", "shredstream_txns_total", "shredstream_decode_errors_total"] {
+            assert!(body.contains(&format!("# TYPE {} counter", name)), "{}", body);
+        }
+        assert_eq!(samples["shredstream_entries_total"], 12.0);
+        assert_eq!(samples["shredstream_txns_total"], 40.0);
+
+        assert!(get(port, "/other").await.starts_with("HTTP/1.1 404"));
+        server.abort();
+    }
+}
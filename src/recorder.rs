@@ -0,0 +1,176 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use tokio::fs::OpenOptions;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc;
+
+/// Rows buffered between the client and the writer task before new rows are dropped
+const RECORD_CHANNEL_CAPACITY: usize = 10_000;
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+const SLOTS_FILE: &str = "slots.csv";
+const SLOTS_HEADER: &[&str] = &["slot", "entry_count", "txn_count", "dex_count", "timestamp"];
+const BUNDLES_FILE: &str = "bundles.csv";
+const BUNDLES_HEADER: &[&str] = &["slot", "txn_count", "tip_lamports", "tip_account", "timestamp"];
+
+/// A row destined for one of the CSV files
+#[derive(Debug, Clone)]
+pub enum RecordRow {
+    Slot {
+        slot: u64,
+        entry_count: u64,
+        txn_count: u64,
+        dex_count: u64,
+        timestamp: DateTime<Local>,
+    },
+    Bundle {
+        slot: u64,
+        txn_count: u32,
+        tip_lamports: u64,
+        tip_account: String,
+        timestamp: DateTime<Local>,
+    },
+}
+
+/// Handle used by the client to queue rows without blocking on disk I/O
+#[derive(Debug, Clone)]
+pub struct CsvRecorder {
+    tx: mpsc::Sender<RecordRow>,
+}
+
+impl CsvRecorder {
+    /// Queue a row; returns false if the writer is behind and the row was dropped
+    pub fn record(&self, row: RecordRow) -> bool {
+        self.tx.try_send(row).is_ok()
+    }
+}
+
+/// Format one CSV line, quoting fields that contain separators or quotes
+pub fn csv_line<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|f| {
+            let f = f.as_ref();
+            if f.contains([',', '"', '\n']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+async fn open_csv(path: &Path, header: &[&str]) -> Result<BufWriter<tokio::fs::File>> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+
+    if file.metadata().await?.len() == 0 {
+        file.write_all(csv_line(header).as_bytes()).await?;
+    }
+    Ok(BufWriter::new(file))
+}
+
+/// Open the CSV files in `dir` and start the writer task
+pub async fn start_csv_recorder(dir: PathBuf) -> Result<(CsvRecorder, tokio::task::JoinHandle<()>)> {
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let mut slots = open_csv(&dir.join(SLOTS_FILE), SLOTS_HEADER).await?;
+    let mut bundles = open_csv(&dir.join(BUNDLES_FILE), BUNDLES_HEADER).await?;
+
+    let (tx, mut rx) = mpsc::channel::<RecordRow>(RECORD_CHANNEL_CAPACITY);
+
+    let handle = tokio::spawn(async move {
+        let mut flush = tokio::time::interval(FLUSH_INTERVAL);
+
+        loop {
+            tokio::select! {
+                row = rx.recv() => {
+                    let Some(row) = row else { break };
+                    let result = match row {
+                        RecordRow::Slot { slot, entry_count, txn_count, dex_count, timestamp } => {
+                            let line = csv_line(&[
+                                slot.to_string(),
+                                entry_count.to_string(),
+                                txn_count.to_string(),
+                                dex_count.to_string(),
+                                timestamp.to_rfc3339(),
+                            ]);
+                            slots.write_all(line.as_bytes()).await
+                        }
+                        RecordRow::Bundle { slot, txn_count, tip_lamports, tip_account, timestamp } => {
+                            let line = csv_line(&[
+                                slot.to_string(),
+                                txn_count.to_string(),
+                                tip_lamports.to_string(),
+                                tip_account,
+                                timestamp.to_rfc3339(),
+                            ]);
+                            bundles.write_all(line.as_bytes()).await
+                        }
+                    };
+                    if let Err(e) = result {
+                        tracing::error!("CSV write failed: {}", e);
+                    }
+                }
+                _ = flush.tick() => {
+                    let _ = slots.flush().await;
+                    let _ = bundles.flush().await;
+                }
+            }
+        }
+
+        let _ = slots.flush().await;
+        let _ = bundles.flush().await;
+    });
+
+    Ok((CsvRecorder { tx }, handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn recorded_rows_follow_their_file_headers() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("csv");
+        let (recorder, handle) = start_csv_recorder(dir.clone()).await.unwrap();
+        let timestamp = Local::now();
+        assert!(recorder.record(RecordRow::Slot { slot: 100, entry_count: 4, txn_count: 30, dex_count: 2, timestamp }));
+        assert!(recorder.record(RecordRow::Slot { slot: 101, entry_count: 1, txn_count: 5, dex_count: 0, timestamp }));
+        assert!(recorder.record(RecordRow::Bundle {
+            slot: 101,
+            txn_count: 3,
+            tip_lamports: 10_000,
+            tip_account: "tip,account".to_string(),
+            timestamp,
+        }));
+        drop(recorder);
+        handle.await.unwrap();
+
+        let slots = std::fs::read_to_string(dir.join(SLOTS_FILE)).unwrap();
+        let bundles = std::fs::read_to_string(dir.join(BUNDLES_FILE)).unwrap();
+
+        let stamp = timestamp.to_rfc3339();
+        assert_eq!(slots, format!(
+            "slot,entry_count,txn_count,dex_count,timestamp\n100,4,30,2,{0}\n101,1,5,0,{0}\n",
+            stamp
+        ));
+        assert_eq!(bundles, format!(
+            "slot,txn_count,tip_lamports,tip_account,timestamp\n101,3,10000,\"tip,account\",{}\n",
+            stamp
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::sync::mpsc;
+
+use crate::client::{EntrySource, RawEntries};
+
+/// Batches buffered between the client and the writer task before new ones are dropped
+const RECORD_CHANNEL_CAPACITY: usize = 10_000;
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Larger records are treated as corruption rather than allocated
+const MAX_RECORD_BYTES: u32 = 64 * 1024 * 1024;
+
+// File format: a sequence of records, each a little-endian u64 slot, a little-endian u32
+// payload length, then the payload exactly as the proxy sent it (bincode `Vec<Entry>`).
+
+/// Handle used by the client to queue raw batches without blocking on disk I/O
+#[derive(Debug, Clone)]
+pub struct RawRecorder {
+    tx: mpsc::Sender<RawEntries>,
+}
+
+impl RawRecorder {
+    /// Queue a batch; returns false if the writer is behind and the batch was dropped
+    pub fn record(&self, batch: RawEntries) -> bool {
+        self.tx.try_send(batch).is_ok()
+    }
+}
+
+async fn write_record(file: &mut BufWriter<File>, batch: &RawEntries) -> std::io::Result<()> {
+    file.write_all(&batch.slot.to_le_bytes()).await?;
+    file.write_all(&(batch.entries.len() as u32).to_le_bytes()).await?;
+    file.write_all(&batch.entries).await
+}
+
+/// Open `path` for appending and start the writer task
+pub async fn start_raw_recorder(path: PathBuf) -> Result<(RawRecorder, tokio::task::JoinHandle<()>)> {
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .await
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut file = BufWriter::new(file);
+
+    let (tx, mut rx) = mpsc::channel::<RawEntries>(RECORD_CHANNEL_CAPACITY);
+
+    let handle = tokio::spawn(async move {
+        let mut flush = tokio::time::interval(FLUSH_INTERVAL);
+
+        loop {
+            tokio::select! {
+                batch = rx.recv() => {
+                    let Some(batch) = batch else { break };
+                    if let Err(e) = write_record(&mut file, &batch).await {
+                        tracing::error!("Raw entry write failed: {}", e);
+                    }
+                }
+                _ = flush.tick() => {
+                    let _ = file.flush().await;
+                }
+            }
+        }
+
+        let _ = file.flush().await;
+    });
+
+    Ok((RawRecorder { tx }, handle))
+}
+
+/// Reads back a file written by `--record-raw`
+pub struct FileReplay {
+    path: PathBuf,
+    reader: BufReader<File>,
+}
+
+impl FileReplay {
+    pub async fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .await
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        Ok(Self { path: path.to_path_buf(), reader: BufReader::new(file) })
+    }
+
+    async fn read_record(&mut self) -> Result<Option<RawEntries>> {
+        let slot = match self.reader.read_u64_le().await {
+            Ok(slot) => slot,
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", self.path.display())),
+        };
+
+        let truncated = || format!("Truncated record for slot {} in {}", slot, self.path.display());
+        let len = self.reader.read_u32_le().await.with_context(truncated)?;
+        if len > MAX_RECORD_BYTES {
+            bail!("Record for slot {} in {} claims {} bytes; not a --record-raw file?", slot, self.path.display(), len);
+        }
+        let mut entries = vec![0; len as usize];
+        self.reader.read_exact(&mut entries).await.with_context(truncated)?;
+
+        Ok(Some(RawEntries { slot, entries }))
+    }
+}
+
+impl EntrySource for FileReplay {
+    async fn next_batch(&mut self) -> Result<Option<RawEntries>> {
+        self.read_record().await
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_sdk::{clock::Slot, pubkey::Pubkey};
+
+use crate::state::{AppState, RpcHealth, TxnStatus};
+
+/// How often the poller checks for due work
+const RPC_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How often validator stakes are refreshed
+const STAKE_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+/// Wallet transactions older than this are no longer looked up, and stay unknown
+const WALLET_STATUS_MAX_AGE: chrono::Duration = chrono::Duration::minutes(2);
+/// Bundles not fully confirmed within this long are counted as dropped
+const BUNDLE_STATUS_MAX_AGE: chrono::Duration = chrono::Duration::minutes(2);
+/// Most signatures `getSignatureStatuses` accepts per request
+const MAX_SIGNATURES_PER_REQUEST: usize = 256;
+/// Bundle signatures looked up per poll, to keep the request rate bounded under heavy bundle flow
+const MAX_BUNDLE_SIGNATURES_PER_POLL: usize = 4 * MAX_SIGNATURES_PER_REQUEST;
+/// Retries per request before it counts as a failure
+const RPC_MAX_RETRIES: u32 = 2;
+const RPC_RETRY_DELAY: Duration = Duration::from_secs(1);
+/// Consecutive failed requests that open the circuit breaker
+const BREAKER_FAILURE_THRESHOLD: u32 = 3;
+/// How long polling stays paused once the breaker opens
+const BREAKER_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Minimal Solana JSON-RPC client for the optional RPC-backed features
+pub struct RpcClient {
+    url: String,
+    http: reqwest::Client,
+    next_id: AtomicU64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VoteAccount {
+    node_pubkey: String,
+    activated_stake: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EpochInfo {
+    epoch: u64,
+    absolute_slot: u64,
+    slot_index: u64,
+    slots_in_epoch: u64,
+}
+
+/// Leader schedule for one epoch, as absolute slots
+#[derive(Debug, Clone)]
+pub struct LeaderSchedule {
+    pub epoch: u64,
+    /// First slot of the next epoch
+    pub end_slot: Slot,
+    /// (slot, leader) pairs sorted by slot
+    pub slots: Vec<(Slot, Pubkey)>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcContextValue<T> {
+    value: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignatureStatus {
+    err: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VoteAccounts {
+    current: Vec<VoteAccount>,
+    delinquent: Vec<VoteAccount>,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(15))
+            .build()
+            .context("Failed to build RPC HTTP client")?;
+        Ok(Self { url, http, next_id: AtomicU64::new(1) })
+    }
+
+    async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let body = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+
+        let response: RpcResponse<T> = self
+            .http
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .await
+            .with_context(|| format!("{} request failed", method))?
+            .error_for_status()
+            .with_context(|| format!("{} returned an HTTP error", method))?
+            .json()
+            .await
+            .with_context(|| format!("{} returned an invalid response", method))?;
+
+        if let Some(error) = response.error {
+            return Err(anyhow!("{} error: {}", method, error));
+        }
+        response.result.ok_or_else(|| anyhow!("{} returned no result", method))
+    }
+
+    /// Activated stake in lamports per validator identity
+    pub async fn get_vote_account_stakes(&self) -> Result<HashMap<Pubkey, u64>> {
+        let accounts: VoteAccounts = self.call("getVoteAccounts", json!([])).await?;
+
+        let mut stakes = HashMap::new();
+        for account in accounts.current.iter().chain(accounts.delinquent.iter()) {
+            if let Ok(node) = account.node_pubkey.parse::<Pubkey>() {
+                *stakes.entry(node).or_insert(0) += account.activated_stake;
+            }
+        }
+        Ok(stakes)
+    }
+
+    /// Execution result per signature, or `None` for signatures the node doesn't know yet
+    pub async fn get_signature_statuses(&self, signatures: &[String]) -> Result<Vec<Option<TxnStatus>>> {
+        let statuses: RpcContextValue<Vec<Option<SignatureStatus>>> = self
+            .call("getSignatureStatuses", json!([signatures]))
+            .await?;
+
+        Ok(statuses.value.into_iter()
+            .map(|status| status.map(|s| if s.err.is_none() { TxnStatus::Success } else { TxnStatus::Failed }))
+            .collect())
+    }
+
+    /// Leader schedule for the current epoch
+    pub async fn get_leader_schedule(&self) -> Result<LeaderSchedule> {
+        let info: EpochInfo = self.call("getEpochInfo", json!([])).await?;
+        let epoch_start = info.absolute_slot - info.slot_index;
+
+        let schedule: HashMap<String, Vec<u64>> = self
+            .call("getLeaderSchedule", json!([info.absolute_slot]))
+            .await?;
+
+        let mut slots = Vec::new();
+        for (identity, indexes) in schedule {
+            if let Ok(leader) = identity.parse::<Pubkey>() {
+                slots.extend(indexes.into_iter().map(|i| (epoch_start + i, leader)));
+            }
+        }
+        slots.sort_unstable_by_key(|(slot, _)| *slot);
+
+        Ok(LeaderSchedule {
+            epoch: info.epoch,
+            end_slot: epoch_start + info.slots_in_epoch,
+            slots,
+        })
+    }
+}
+
+// ============================================================================
+// Poller
+// ============================================================================
+
+/// Opens after repeated failures and pauses polling for a cooldown period
+#[derive(Debug, Default)]
+struct CircuitBreaker {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Whether a request may be made now; a breaker past its cooldown allows a trial request
+    fn allow(&mut self) -> bool {
+        match self.open_until {
+            Some(until) if Instant::now() < until => false,
+            Some(_) => {
+                self.open_until = None;
+                true
+            }
+            None => true,
+        }
+    }
+
+    fn record_success(&mut self, state: &AppState) {
+        if self.failures >= BREAKER_FAILURE_THRESHOLD {
+            state.log_info("RPC recovered, resuming polling");
+        }
+        self.failures = 0;
+        *state.rpc_health.write() = RpcHealth::Healthy;
+    }
+
+    fn record_failure(&mut self, state: &AppState, error: &anyhow::Error) {
+        self.failures += 1;
+        if self.failures >= BREAKER_FAILURE_THRESHOLD {
+            // Only log when the breaker first opens, not on every failed trial
+            if self.failures == BREAKER_FAILURE_THRESHOLD {
+                state.log_warn(format!(
+                    "RPC failed {} times, pausing for {}s: {:#}",
+                    self.failures,
+                    BREAKER_COOLDOWN.as_secs(),
+                    error
+                ));
+            }
+            self.open_until = Some(Instant::now() + BREAKER_COOLDOWN);
+            *state.rpc_health.write() = RpcHealth::CircuitOpen;
+        } else {
+            *state.rpc_health.write() = RpcHealth::Degraded(self.failures);
+        }
+    }
+}
+
+/// Run an RPC call, retrying a bounded number of times with a short linear delay
+async fn with_retries<T, F, Fut>(mut call: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match call().await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= RPC_MAX_RETRIES => return Err(e),
+            Err(_) => {
+                attempt += 1;
+                tokio::time::sleep(RPC_RETRY_DELAY * attempt).await;
+            }
+        }
+    }
+}
+
+/// Start the RPC poller in a background task
+pub fn start_rpc_poller(client: RpcClient, state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
+    *state.rpc_health.write() = RpcHealth::Healthy;
+
+    tokio::spawn(async move {
+        let mut breaker = CircuitBreaker::default();
+        let mut next_stake_refresh = Instant::now();
+        let mut schedule_end: Option<Slot> = None;
+        // Confirmed bundle signatures, kept until their bundle settles so they aren't looked up again
+        let mut bundle_statuses: HashMap<String, TxnStatus> = HashMap::new();
+        let mut interval = tokio::time::interval(RPC_POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            // Refresh the leader schedule on startup and at each epoch boundary
+            let current_slot = state.current_slot.load(Ordering::Relaxed);
+            let schedule_due = match schedule_end {
+                Some(end) => current_slot >= end,
+                None => true,
+            };
+            if schedule_due && breaker.allow() {
+                match with_retries(|| client.get_leader_schedule()).await {
+                    // A node still in the previous epoch returns the schedule already loaded; keep
+                    // it and ask again next poll
+                    Ok(schedule) if schedule_end.is_some_and(|end| schedule.end_slot <= end) => {
+                        breaker.record_success(&state);
+                    }
+                    Ok(schedule) => {
+                        breaker.record_success(&state);
+                        state.log_info(format!(
+                            "Loaded leader schedule for epoch {} ({} slots)",
+                            schedule.epoch,
+                            schedule.slots.len()
+                        ));
+                        schedule_end = Some(schedule.end_slot);
+                        state.leader_tracker.set_schedule(schedule.slots);
+                    }
+                    Err(e) => breaker.record_failure(&state, &e),
+                }
+            }
+
+            if Instant::now() >= next_stake_refresh && breaker.allow() {
+                match with_retries(|| client.get_vote_account_stakes()).await {
+                    Ok(stakes) => {
+                        breaker.record_success(&state);
+                        state.log_info(format!("Loaded stakes for {} validators", stakes.len()));
+                        state.leader_tracker.set_stakes(stakes);
+                        next_stake_refresh = Instant::now() + STAKE_REFRESH_INTERVAL;
+                    }
+                    Err(e) => breaker.record_failure(&state, &e),
+                }
+            }
+
+            // Confirm recent wallet transactions, which shreds can't tell apart from failures
+            let pending = state.wallet_monitor.unknown_signatures(chrono::Local::now() - WALLET_STATUS_MAX_AGE);
+            for batch in pending.chunks(MAX_SIGNATURES_PER_REQUEST) {
+                if !breaker.allow() {
+                    break;
+                }
+                match with_retries(|| client.get_signature_statuses(batch)).await {
+                    Ok(statuses) => {
+                        breaker.record_success(&state);
+                        for (signature, status) in batch.iter().zip(statuses) {
+                            if let Some(status) = status {
+                                state.wallet_monitor.set_status(signature, status);
+                            }
+                        }
+                    }
+                    Err(e) => breaker.record_failure(&state, &e),
+                }
+            }
+
+            // Confirm whether recent bundles landed
+            let competition = &state.competition_stats;
+            if !competition.track_landing {
+                continue;
+            }
+            let unconfirmed: Vec<String> = competition.pending_bundle_signatures().into_iter()
+                .filter(|sig| !bundle_statuses.contains_key(sig))
+                .take(MAX_BUNDLE_SIGNATURES_PER_POLL)
+                .collect();
+            // Signatures the node was asked about this poll; a bundle is only dropped once all of
+            // its unresolved ones were, since the cap or a failed request can leave some unchecked
+            let mut looked_up = HashSet::new();
+            for batch in unconfirmed.chunks(MAX_SIGNATURES_PER_REQUEST) {
+                if !breaker.allow() {
+                    break;
+                }
+                match with_retries(|| client.get_signature_statuses(batch)).await {
+                    Ok(statuses) => {
+                        breaker.record_success(&state);
+                        for (signature, status) in batch.iter().zip(statuses) {
+                            if let Some(status) = status {
+                                bundle_statuses.insert(signature.clone(), status);
+                            }
+                        }
+                        looked_up.extend(batch.iter().cloned());
+                    }
+                    Err(e) => breaker.record_failure(&state, &e),
+                }
+            }
+            competition.settle_bundles(&bundle_statuses, &looked_up, chrono::Local::now() - BUNDLE_STATUS_MAX_AGE);
+            let pending: HashSet<String> = competition.pending_bundle_signatures().into_iter().collect();
+            bundle_statuses.retain(|sig, _| pending.contains(sig));
+        }
+    })
+}
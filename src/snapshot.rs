@@ -0,0 +1,190 @@
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+
+use crate::recorder::csv_line;
+use crate::state::{AppState, ProgramActivity};
+
+/// Number of programs included in a snapshot
+const SNAPSHOT_TOP_PROGRAMS: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgramSnapshot {
+    pub program_id: String,
+    pub name: String,
+    pub category: String,
+    pub txn_count: u64,
+}
+
+/// Point-in-time summary of `AppState` for offline analysis
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Snapshot {
+    pub timestamp: String,
+    pub proxy_url: String,
+    pub connection_state: String,
+    pub uptime_secs: u64,
+    pub current_slot: u64,
+    pub total_entries: u64,
+    pub total_txns: u64,
+    pub avg_arrival_delay_ms: f64,
+    pub min_arrival_delay_ms: f64,
+    pub max_arrival_delay_ms: f64,
+    pub top_programs: Vec<ProgramSnapshot>,
+    pub bundle_count: u64,
+    pub total_tips_lamports: u64,
+    pub total_tips_sol: f64,
+}
+
+impl Snapshot {
+    pub fn from_state(state: &AppState) -> Self {
+        let top_programs = state
+            .program_stats
+            .get_top_programs(SNAPSHOT_TOP_PROGRAMS)
+            .into_iter()
+            .map(|p| ProgramSnapshot {
+                program_id: p.program_id.to_string(),
+                name: p.name,
+                category: p.category.to_string(),
+                txn_count: p.txn_count,
+            })
+            .collect();
+
+        Self {
+            timestamp: Local::now().to_rfc3339(),
+            proxy_url: state.proxy_url.read().clone(),
+            connection_state: state.connection_state.read().to_string(),
+            uptime_secs: state.uptime().as_secs(),
+            current_slot: state.current_slot.load(Ordering::Relaxed),
+            total_entries: state.metrics.total_entries.load(Ordering::Relaxed),
+            total_txns: state.metrics.total_txns.load(Ordering::Relaxed),
+            avg_arrival_delay_ms: state.latency_stats.avg_latency_ms(),
+            min_arrival_delay_ms: state.latency_stats.min_latency_ms(),
+            max_arrival_delay_ms: state.latency_stats.max_latency_ms(),
+            top_programs,
+            bundle_count: state.competition_stats.bundle_count.load(Ordering::Relaxed),
+            total_tips_lamports: state.competition_stats.total_tips_lamports.load(Ordering::Relaxed),
+            total_tips_sol: state.competition_stats.total_tips_sol(),
+        }
+    }
+
+    /// Write the snapshot as pretty JSON to a timestamped file in the current directory
+    pub fn write_to_file(&self) -> Result<PathBuf> {
+        let path = PathBuf::from(format!(
+            "shredstream-snapshot-{}.json",
+            Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize snapshot")?;
+        std::fs::write(&path, json)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+const PROGRAMS_CSV_HEADER: &[&str] = &["program_id", "name", "category", "txn_count", "last_seen"];
+
+/// Write `programs` as CSV to a timestamped file in the current directory
+pub fn write_programs_csv(programs: &[ProgramActivity]) -> Result<PathBuf> {
+    let path = PathBuf::from(format!(
+        "shredstream-programs-{}.csv",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::write(&path, programs_csv(programs))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+fn programs_csv(programs: &[ProgramActivity]) -> String {
+    let mut csv = csv_line(PROGRAMS_CSV_HEADER);
+    for program in programs {
+        csv.push_str(&csv_line(&[
+            program.program_id.to_string(),
+            program.name.clone(),
+            program.category.to_string(),
+            program.txn_count.to_string(),
+            program.last_seen.to_rfc3339(),
+        ]));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::pubkey::Pubkey;
+    use crate::programs::{KnownPrograms, ProgramCategory};
+    use crate::state::{BundleInfo, BundleStatus, LatencySample};
+
+    #[test]
+    fn snapshot_of_a_populated_state_round_trips_through_json() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.current_slot.store(250, Ordering::Relaxed);
+        state.metrics.add_entry(12, 40);
+        let raydium = KnownPrograms::RAYDIUM_V4.parse().unwrap();
+        for slot in 248..=250 {
+            state.program_stats.record_program(raydium, slot);
+        }
+        state.competition_stats.add_bundle(BundleInfo {
+            slot: 250,
+            txn_count: 3,
+            tip_amount: 1_500_000,
+            tip_account: "tip".to_string(),
+            signatures: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            timestamp: Local::now(),
+            status: BundleStatus::Pending,
+        });
+        state.latency_stats.add_sample(LatencySample {
+            slot: 250,
+            timestamp: Local::now(),
+            arrival_delay_us: 4_000,
+            leader: None,
+            region: None,
+            turbine_index: None,
+        });
+
+        let snapshot = Snapshot::from_state(&state);
+        assert_eq!((snapshot.current_slot, snapshot.total_entries, snapshot.total_txns), (250, 12, 40));
+        assert_eq!((snapshot.bundle_count, snapshot.total_tips_lamports), (1, 1_500_000));
+        assert_eq!(snapshot.avg_arrival_delay_ms, 4.0);
+        assert_eq!(snapshot.top_programs, vec![ProgramSnapshot {
+            program_id: KnownPrograms::RAYDIUM_V4.to_string(),
+            name: "Raydium V4".to_string(),
+            category: "DEX".to_string(),
+            txn_count: 3,
+        }]);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(serde_json::from_str::<Snapshot>(&json).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn programs_csv_round_trips_rows_and_categories() {
+        let stats = crate::state::ProgramStats::new();
+        let raydium: Pubkey = KnownPrograms::RAYDIUM_V4.parse().unwrap();
+        let kamino: Pubkey = KnownPrograms::KAMINO_LENDING.parse().unwrap();
+        let unknown = Pubkey::new_unique();
+        for (program, count) in [(raydium, 3), (kamino, 2), (unknown, 1)] {
+            for slot in 0..count {
+                stats.record_program(program, slot);
+            }
+        }
+        let programs = stats.get_top_programs(usize::MAX);
+
+        let csv = programs_csv(&programs);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("program_id,name,category,txn_count,last_seen"));
+        let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+        assert_eq!(rows.len(), programs.len());
+        for (row, program) in rows.iter().zip(&programs) {
+            assert_eq!(row[0].parse::<Pubkey>().unwrap(), program.program_id);
+            assert_eq!(row[1], program.name);
+            assert_eq!(row[2].parse::<ProgramCategory>().unwrap(), program.category);
+            assert_eq!(row[3].parse::<u64>().unwrap(), program.txn_count);
+            assert_eq!(chrono::DateTime::parse_from_rfc3339(row[4]).unwrap(), program.last_seen);
+        }
+        let categories: Vec<&str> = rows.iter().map(|row| row[2]).collect();
+        assert_eq!(categories, vec!["DEX", "Lending", "Other"]);
+    }
+}
@@ -1,22 +1,128 @@
 use std::{
-    collections::{HashMap, VecDeque},
-    sync::atomic::{AtomicU64, Ordering},
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque},
+    path::PathBuf,
+    sync::atomic::{AtomicI64, AtomicU64, AtomicUsize, Ordering},
     time::{Duration, Instant},
 };
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, Timelike};
+use crossterm::event::KeyCode;
+use num_format::Locale;
 use parking_lot::RwLock;
+use rand::Rng;
 use solana_sdk::{clock::Slot, pubkey::Pubkey};
 
-use crate::programs::{KnownPrograms, ProgramCategory, ProgramInfo};
+use crate::alerts::{Alert, AlertThresholds};
+use crate::programs::{save_program_cache, KnownPrograms, ProgramCategory, ProgramInfo};
+use crate::theme::{CategoryColors, Theme};
+
+/// Nominal slot time, used until enough slots have been observed
+const NOMINAL_SLOTS_PER_SEC: f64 = 2.5;
+
+/// Ideal slot time the drift indicator compares against
+const NOMINAL_SLOT_MS: f64 = 1000.0 / NOMINAL_SLOTS_PER_SEC;
+/// Slots past the anchor before drift is reported, so early jitter doesn't dominate
+const MIN_DRIFT_SLOTS: u64 = 10;
+/// Drift beyond this means the clock or the stream jumped (suspend, long outage); re-anchor instead
+const MAX_DRIFT_MS_PER_SLOT: f64 = NOMINAL_SLOT_MS;
+
+/// Number of tabs in the UI
+const TAB_COUNT: usize = 8;
+
+/// How long a footer notice stays up
+const NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// Bounds for the UI tick rate, which `+`/`-` halve and double at runtime
+const MIN_TICK_RATE_MS: u64 = 16;
+const MAX_TICK_RATE_MS: u64 = 2000;
+
+/// Default smoothing factor for the smoothed txn rate
+pub const DEFAULT_EMA_ALPHA: f64 = 0.2;
+/// Default length of the metrics window in seconds
+pub const DEFAULT_METRICS_WINDOW_SECS: u64 = 10;
 
 /// Maximum history sizes
 const MAX_LOG_ENTRIES: usize = 200;
+const MAX_CONNECTION_EVENTS: usize = 100;
 const MAX_SLOT_HISTORY: usize = 100;
+const MAX_TXN_RATE_HISTORY: usize = 240;
 const MAX_TXN_SAMPLES: usize = 50;
+/// Weight the transaction sampler remembers; older transactions stop counting against new ones,
+/// so Recent Transactions keeps turning over on a long run
+const SAMPLE_HORIZON_WEIGHT: f64 = 20_000.0;
+/// Default sampling weight of DEX and bundle transactions relative to others
+pub const DEFAULT_FLAGGED_SAMPLE_WEIGHT: f64 = 4.0;
 const MAX_LATENCY_SAMPLES: usize = 100;
+const MAX_SLOT_LATENCY_HISTORY: usize = 240;
+/// Slots of per-program activity kept for the program detail sparkline
+pub const PROGRAM_SLOT_HISTORY: usize = 60;
+/// Programs with per-slot activity tracked; the one idle longest makes room for a new one
+const MAX_PROGRAM_SLOT_RINGS: usize = 100;
+/// Leaders tracked per region; the one with the fewest samples makes room for a new one
+const MAX_LEADERS_PER_REGION: usize = 200;
+
+/// Upper edges (ms) of the arrival delay histogram buckets above zero. Early arrivals get a bucket
+/// of their own below them, and a final bucket holds everything above the last edge
+const LATENCY_HISTOGRAM_EDGES_MS: [f64; 5] = [1.0, 2.0, 5.0, 10.0, 20.0];
 const MAX_LEADER_HISTORY: usize = 50;
 const MAX_BUNDLE_SAMPLES: usize = 50;
+/// Bundles awaiting RPC confirmation; the oldest are forgotten uncounted beyond this
+const MAX_PENDING_BUNDLES: usize = 2000;
+const MAX_MEV_INDEX_HISTORY: usize = 120;
+const MAX_MISSED_SLOTS: usize = 100;
+const MAX_PRIORITY_FEE_SLOTS: usize = 100;
+/// Writable accounts counted before the least-written half is dropped
+const MAX_TRACKED_ACCOUNTS: usize = 20_000;
+/// Per-minute volume buckets kept for the Overview chart
+const MAX_MINUTE_BUCKETS: usize = 60;
+/// Seconds of per-second buckets kept by a `RateTracker`
+const RATE_TRACKER_SECS: u64 = 60;
+
+/// How far behind the newest observed slot a scheduled slot must be before
+/// it's declared skipped, to tolerate out-of-order delivery
+const SKIP_DETECTION_LAG: u64 = 4;
+/// Larger jumps are treated as an outage rather than a run of skipped slots
+const SKIP_DETECTION_MAX_GAP: u64 = 64;
+
+pub fn clamp_tick_rate(ms: u64) -> u64 {
+    ms.clamp(MIN_TICK_RATE_MS, MAX_TICK_RATE_MS)
+}
+
+/// Average ms per slot by which `slot` arriving at `at` lags (positive) or leads the nominal
+/// cadence since `anchor`. Counting by slot number keeps missed slots from skewing it.
+/// `None` until the slot is far enough past the anchor to be meaningful.
+fn slot_drift_ms(anchor: (Slot, Instant), slot: Slot, at: Instant) -> Option<f64> {
+    let (anchor_slot, anchor_at) = anchor;
+    let slots = slot.checked_sub(anchor_slot).filter(|&n| n >= MIN_DRIFT_SLOTS)?;
+    let elapsed_ms = at.checked_duration_since(anchor_at)?.as_secs_f64() * 1000.0;
+    Some(elapsed_ms / slots as f64 - NOMINAL_SLOT_MS)
+}
+
+/// How far behind (positive) or ahead (negative) of schedule `slot` first arrived at `at`.
+///
+/// The schedule is projected from the drift anchor at `NOMINAL_SLOT_MS + drift_ms` per slot, so
+/// the delay is relative to the anchor slot's own arrival: it shows which slots land late compared
+/// with the stream's recent pace, not the absolute time since the leader started the slot.
+fn slot_arrival_delay_ms(anchor: (Slot, Instant), drift_ms: f64, slot: Slot, at: Instant) -> Option<f64> {
+    let (anchor_slot, anchor_at) = anchor;
+    let slots = slot.checked_sub(anchor_slot)?;
+    let elapsed_ms = at.checked_duration_since(anchor_at)?.as_secs_f64() * 1000.0;
+    Some(elapsed_ms - slots as f64 * (NOMINAL_SLOT_MS + drift_ms))
+}
+
+/// Time left at `now` in a window of length `window` that started at `start`, clamped to zero
+fn window_remaining(start: Instant, window: Duration, now: Instant) -> Duration {
+    window.saturating_sub(now.saturating_duration_since(start))
+}
+
+/// `sample` folded into the moving average `prev` with weight `alpha`; the first sample seeds it
+fn ema_update(prev: Option<f64>, sample: f64, alpha: f64) -> f64 {
+    match prev {
+        Some(prev) => alpha * sample + (1.0 - alpha) * prev,
+        None => sample,
+    }
+}
 
 // ============================================================================
 // Connection State
@@ -25,9 +131,13 @@ const MAX_BUNDLE_SAMPLES: usize = 50;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConnectionState {
     Disconnected,
+    /// Not yet connected since startup
     Connecting,
     Connected,
+    /// Retrying after a previously established connection dropped
     Reconnecting,
+    /// The stream stayed open but sent nothing for `--stall-timeout`; held until the next connection
+    Stalled,
     Error(String),
 }
 
@@ -38,11 +148,35 @@ impl std::fmt::Display for ConnectionState {
             ConnectionState::Connecting => write!(f, "Connecting..."),
             ConnectionState::Connected => write!(f, "Connected"),
             ConnectionState::Reconnecting => write!(f, "Reconnecting..."),
+            ConnectionState::Stalled => write!(f, "STALLED"),
             ConnectionState::Error(e) => write!(f, "Error: {}", e),
         }
     }
 }
 
+/// Health of the optional RPC poller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcHealth {
+    /// No `--rpc-url` configured
+    Disabled,
+    Healthy,
+    /// Recent consecutive failures, still polling
+    Degraded(u32),
+    /// Too many failures; polling paused until the cooldown ends
+    CircuitOpen,
+}
+
+impl std::fmt::Display for RpcHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpcHealth::Disabled => write!(f, "Disabled"),
+            RpcHealth::Healthy => write!(f, "Healthy"),
+            RpcHealth::Degraded(failures) => write!(f, "Degraded ({} failures)", failures),
+            RpcHealth::CircuitOpen => write!(f, "Paused (circuit open)"),
+        }
+    }
+}
+
 // ============================================================================
 // Logging
 // ============================================================================
@@ -89,6 +223,16 @@ pub struct SlotInfo {
     pub dex_txn_count: u64,
     pub jito_bundle_count: u64,
     pub turbine_index: Option<u32>,
+    /// False once entries for the slot arrived after a later slot had started, so its counts
+    /// may be missing some of them
+    pub complete: bool,
+}
+
+impl SlotInfo {
+    /// Whether the slot had any DEX or bundle activity, for DEX-only mode
+    pub fn has_dex_activity(&self) -> bool {
+        self.dex_txn_count > 0 || self.jito_bundle_count > 0
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -97,10 +241,51 @@ pub struct TxnSample {
     pub signature: String,
     pub received_at: DateTime<Local>,
     pub programs: Vec<String>,
+    pub is_dex: bool,
     pub is_bundle: bool,
     pub tip_amount: Option<u64>,
 }
 
+/// Weighted reservoir sampling (Chao's method) over the most recent `horizon` of weight.
+///
+/// Each offered item is kept with probability `capacity * weight / total_weight`, evicting a
+/// random kept item, so the buffer is a weighted sample of the stream rather than its first items.
+#[derive(Debug, Clone)]
+pub struct ReservoirSampler {
+    capacity: usize,
+    horizon: f64,
+    total_weight: f64,
+}
+
+impl ReservoirSampler {
+    pub fn new(capacity: usize, horizon: f64) -> Self {
+        Self { capacity, horizon, total_weight: 0.0 }
+    }
+
+    /// Offer `item` with a relative `weight`, returning whether it was kept. Kept items are
+    /// appended, so `buffer` stays in arrival order and never grows past the capacity.
+    pub fn offer<T>(&mut self, buffer: &mut VecDeque<T>, item: T, weight: f64) -> bool {
+        self.total_weight = (self.total_weight + weight).min(self.horizon);
+        if buffer.len() < self.capacity {
+            buffer.push_back(item);
+            return true;
+        }
+
+        let mut rng = rand::thread_rng();
+        let keep_chance = self.capacity as f64 * weight / self.total_weight;
+        if !rng.gen_bool(keep_chance.clamp(0.0, 1.0)) {
+            return false;
+        }
+        buffer.remove(rng.gen_range(0..buffer.len()));
+        buffer.push_back(item);
+        true
+    }
+
+    pub fn reset(&mut self) {
+        self.total_weight = 0.0;
+    }
+}
+
 // ============================================================================
 // Latency Tracking
 // ============================================================================
@@ -109,33 +294,126 @@ pub struct TxnSample {
 pub struct LatencySample {
     pub slot: Slot,
     pub timestamp: DateTime<Local>,
-    pub shred_latency_us: u64,
+    /// Slot arrival delay: how long after its projected start the slot's first entries arrived,
+    /// negative when they came early. A relative figure, not network propagation latency
+    pub arrival_delay_us: i64,
     pub leader: Option<Pubkey>,
     pub region: Option<String>,
     pub turbine_index: Option<u32>,
 }
 
+/// Latency samples accumulated for one slot
+#[derive(Debug, Clone)]
+pub struct SlotLatency {
+    pub slot: Slot,
+    pub total_us: i64,
+    pub count: u64,
+}
+
+impl SlotLatency {
+    pub fn avg_us(&self) -> i64 {
+        self.total_us / self.count.max(1) as i64
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct LatencyStats {
     pub samples: RwLock<VecDeque<LatencySample>>,
-    pub min_latency_us: AtomicU64,
-    pub max_latency_us: AtomicU64,
-    pub total_latency_us: AtomicU64,
+    /// Per-slot latency, oldest first, for the Overview sparkline
+    pub slot_history: RwLock<VecDeque<SlotLatency>>,
+    pub min_latency_us: AtomicI64,
+    pub max_latency_us: AtomicI64,
+    pub total_latency_us: AtomicI64,
     pub sample_count: AtomicU64,
     pub leader_latencies: RwLock<HashMap<Pubkey, LeaderLatencyStats>>,
     pub region_latencies: RwLock<HashMap<String, RegionLatencyStats>>,
+    /// Per-leader stats within each region, for the Latency tab's region drill-down
+    pub region_leader_latencies: RwLock<HashMap<String, HashMap<Pubkey, LeaderLatencyStats>>>,
+    /// Arrival delay SLA target, if configured
+    pub sla_target_us: Option<i64>,
+    /// Whether SLA counts restart with each metrics window
+    pub sla_resets_with_window: bool,
+    pub sla_met_count: AtomicU64,
+    pub sla_sample_count: AtomicU64,
+    pub budget: LatencyBudget,
+}
+
+/// Cumulative time spent in each tool-side stage of handling a message.
+///
+/// Network time isn't included: the proxy API has no way to measure it.
+#[derive(Debug, Default)]
+pub struct LatencyBudget {
+    pub decode_us: AtomicU64,
+    pub process_us: AtomicU64,
+    pub processed_count: AtomicU64,
+    pub queue_us: AtomicU64,
+    pub queued_count: AtomicU64,
+}
+
+impl LatencyBudget {
+    /// Record bincode decode time and the analysis time that followed it
+    pub fn record_processing(&self, decode: Duration, process: Duration) {
+        self.decode_us.fetch_add(decode.as_micros() as u64, Ordering::Relaxed);
+        self.process_us.fetch_add(process.as_micros() as u64, Ordering::Relaxed);
+        self.processed_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record how long a message waited in the client-to-UI channel
+    pub fn record_queue(&self, wait: Duration) {
+        self.queue_us.fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+        self.queued_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn reset(&self) {
+        self.decode_us.store(0, Ordering::Relaxed);
+        self.process_us.store(0, Ordering::Relaxed);
+        self.processed_count.store(0, Ordering::Relaxed);
+        self.queue_us.store(0, Ordering::Relaxed);
+        self.queued_count.store(0, Ordering::Relaxed);
+    }
+
+    fn avg_ms(total_us: &AtomicU64, count: &AtomicU64) -> f64 {
+        let count = count.load(Ordering::Relaxed);
+        if count == 0 {
+            return 0.0;
+        }
+        (total_us.load(Ordering::Relaxed) as f64 / count as f64) / 1000.0
+    }
+
+    pub fn avg_decode_ms(&self) -> f64 {
+        Self::avg_ms(&self.decode_us, &self.processed_count)
+    }
+
+    pub fn avg_process_ms(&self) -> f64 {
+        Self::avg_ms(&self.process_us, &self.processed_count)
+    }
+
+    pub fn avg_queue_ms(&self) -> f64 {
+        Self::avg_ms(&self.queue_us, &self.queued_count)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct LeaderLatencyStats {
     pub leader: Pubkey,
-    pub total_latency_us: u64,
+    pub total_latency_us: i64,
     pub sample_count: u64,
-    pub min_latency_us: u64,
-    pub max_latency_us: u64,
+    pub min_latency_us: i64,
+    pub max_latency_us: i64,
 }
 
 impl LeaderLatencyStats {
+    fn record(&mut self, latency_us: i64) {
+        self.total_latency_us += latency_us;
+        self.sample_count += 1;
+        if latency_us < self.min_latency_us || self.sample_count == 1 {
+            self.min_latency_us = latency_us;
+        }
+        if latency_us > self.max_latency_us || self.sample_count == 1 {
+            self.max_latency_us = latency_us;
+        }
+    }
+
     pub fn avg_latency_ms(&self) -> f64 {
         if self.sample_count == 0 {
             0.0
@@ -148,13 +426,24 @@ impl LeaderLatencyStats {
 #[derive(Debug, Clone, Default)]
 pub struct RegionLatencyStats {
     pub region: String,
-    pub total_latency_us: u64,
+    pub total_latency_us: i64,
     pub sample_count: u64,
-    pub min_latency_us: u64,
-    pub max_latency_us: u64,
+    pub min_latency_us: i64,
+    pub max_latency_us: i64,
 }
 
 impl RegionLatencyStats {
+    fn record(&mut self, latency_us: i64) {
+        self.total_latency_us += latency_us;
+        self.sample_count += 1;
+        if latency_us < self.min_latency_us || self.sample_count == 1 {
+            self.min_latency_us = latency_us;
+        }
+        if latency_us > self.max_latency_us || self.sample_count == 1 {
+            self.max_latency_us = latency_us;
+        }
+    }
+
     pub fn avg_latency_ms(&self) -> f64 {
         if self.sample_count == 0 {
             0.0
@@ -168,20 +457,49 @@ impl LatencyStats {
     pub fn new() -> Self {
         Self {
             samples: RwLock::new(VecDeque::with_capacity(MAX_LATENCY_SAMPLES)),
-            min_latency_us: AtomicU64::new(u64::MAX),
-            max_latency_us: AtomicU64::new(0),
-            total_latency_us: AtomicU64::new(0),
+            slot_history: RwLock::new(VecDeque::with_capacity(MAX_SLOT_LATENCY_HISTORY)),
+            min_latency_us: AtomicI64::new(i64::MAX),
+            max_latency_us: AtomicI64::new(i64::MIN),
+            total_latency_us: AtomicI64::new(0),
             sample_count: AtomicU64::new(0),
             leader_latencies: RwLock::new(HashMap::new()),
             region_latencies: RwLock::new(HashMap::new()),
+            region_leader_latencies: RwLock::new(HashMap::new()),
+            sla_target_us: None,
+            sla_resets_with_window: false,
+            sla_met_count: AtomicU64::new(0),
+            sla_sample_count: AtomicU64::new(0),
+            budget: LatencyBudget::default(),
         }
     }
 
+    /// Drop all samples and per-leader/region stats; the SLA target and budget counters start over too
+    pub fn reset(&self) {
+        self.samples.write().clear();
+        self.slot_history.write().clear();
+        self.min_latency_us.store(i64::MAX, Ordering::Relaxed);
+        self.max_latency_us.store(i64::MIN, Ordering::Relaxed);
+        self.total_latency_us.store(0, Ordering::Relaxed);
+        self.sample_count.store(0, Ordering::Relaxed);
+        self.leader_latencies.write().clear();
+        self.region_latencies.write().clear();
+        self.region_leader_latencies.write().clear();
+        self.reset_sla();
+        self.budget.reset();
+    }
+
     pub fn add_sample(&self, sample: LatencySample) {
-        let latency = sample.shred_latency_us;
+        let latency = sample.arrival_delay_us;
         
         self.total_latency_us.fetch_add(latency, Ordering::Relaxed);
         self.sample_count.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(target) = self.sla_target_us {
+            self.sla_sample_count.fetch_add(1, Ordering::Relaxed);
+            if latency <= target {
+                self.sla_met_count.fetch_add(1, Ordering::Relaxed);
+            }
+        }
         
         // Update min
         let mut current_min = self.min_latency_us.load(Ordering::Relaxed);
@@ -208,37 +526,25 @@ impl LatencyStats {
         // Update leader stats
         if let Some(leader) = sample.leader {
             let mut leader_stats = self.leader_latencies.write();
-            let stats = leader_stats.entry(leader).or_insert_with(|| LeaderLatencyStats {
-                leader,
-                ..Default::default()
-            });
-            stats.total_latency_us += latency;
-            stats.sample_count += 1;
-            if latency < stats.min_latency_us || stats.min_latency_us == 0 {
-                stats.min_latency_us = latency;
-            }
-            if latency > stats.max_latency_us {
-                stats.max_latency_us = latency;
-            }
+            leader_stats.entry(leader)
+                .or_insert_with(|| LeaderLatencyStats { leader, ..Default::default() })
+                .record(latency);
         }
         
         // Update region stats
         if let Some(ref region) = sample.region {
             let mut region_stats = self.region_latencies.write();
-            let stats = region_stats.entry(region.clone()).or_insert_with(|| RegionLatencyStats {
-                region: region.clone(),
-                ..Default::default()
-            });
-            stats.total_latency_us += latency;
-            stats.sample_count += 1;
-            if latency < stats.min_latency_us || stats.min_latency_us == 0 {
-                stats.min_latency_us = latency;
-            }
-            if latency > stats.max_latency_us {
-                stats.max_latency_us = latency;
-            }
+            region_stats.entry(region.clone())
+                .or_insert_with(|| RegionLatencyStats { region: region.clone(), ..Default::default() })
+                .record(latency);
+        }
+
+        if let (Some(region), Some(leader)) = (&sample.region, sample.leader) {
+            self.record_region_leader(region, leader, latency);
         }
         
+        self.record_slot_latency(sample.slot, latency);
+
         let mut samples = self.samples.write();
         if samples.len() >= MAX_LATENCY_SAMPLES {
             samples.pop_front();
@@ -246,6 +552,79 @@ impl LatencyStats {
         samples.push_back(sample);
     }
 
+    fn record_region_leader(&self, region: &str, leader: Pubkey, latency_us: i64) {
+        let mut regions = self.region_leader_latencies.write();
+        let leaders = regions.entry(region.to_string()).or_default();
+        if !leaders.contains_key(&leader) && leaders.len() >= MAX_LEADERS_PER_REGION {
+            let quietest = leaders.values().min_by_key(|s| s.sample_count).map(|s| s.leader);
+            if let Some(quietest) = quietest {
+                leaders.remove(&quietest);
+            }
+        }
+        leaders.entry(leader)
+            .or_insert_with(|| LeaderLatencyStats { leader, ..Default::default() })
+            .record(latency_us);
+    }
+
+    /// Per-region stats, lowest average latency first
+    pub fn regions_by_latency(&self) -> Vec<RegionLatencyStats> {
+        let mut regions: Vec<RegionLatencyStats> = self.region_latencies.read().values().cloned().collect();
+        regions.sort_by(|a, b| a.avg_latency_ms().total_cmp(&b.avg_latency_ms()).then_with(|| a.region.cmp(&b.region)));
+        regions
+    }
+
+    /// Regions with per-leader stats, in name order
+    pub fn leader_regions(&self) -> Vec<String> {
+        let mut regions: Vec<String> = self.region_leader_latencies.read().keys().cloned().collect();
+        regions.sort();
+        regions
+    }
+
+    /// Fold a sample into its slot's average; samples for an older slot than the newest are dropped
+    fn record_slot_latency(&self, slot: Slot, latency_us: i64) {
+        let mut history = self.slot_history.write();
+        match history.back_mut() {
+            Some(last) if last.slot == slot => {
+                last.total_us += latency_us;
+                last.count += 1;
+            }
+            Some(last) if last.slot > slot => {}
+            _ => {
+                if history.len() >= MAX_SLOT_LATENCY_HISTORY {
+                    history.pop_front();
+                }
+                history.push_back(SlotLatency { slot, total_us: latency_us, count: 1 });
+            }
+        }
+    }
+
+    /// Recent samples counted into an early bucket and the `LATENCY_HISTOGRAM_EDGES_MS` buckets,
+    /// as (label, count)
+    pub fn histogram(&self) -> Vec<(String, u64)> {
+        let mut counts = [0u64; LATENCY_HISTOGRAM_EDGES_MS.len() + 2];
+        for sample in self.samples.read().iter() {
+            let ms = sample.arrival_delay_us as f64 / 1000.0;
+            let bucket = if ms < 0.0 {
+                0
+            } else {
+                1 + LATENCY_HISTOGRAM_EDGES_MS.iter().position(|&edge| ms < edge)
+                    .unwrap_or(LATENCY_HISTOGRAM_EDGES_MS.len())
+            };
+            counts[bucket] += 1;
+        }
+
+        let mut lower = 0.0;
+        let mut labels = vec!["<0".to_string()];
+        labels.extend(LATENCY_HISTOGRAM_EDGES_MS.iter().map(|&edge| {
+            let label = format!("{}–{}", lower, edge);
+            lower = edge;
+            label
+        }));
+        labels.push(format!("{}+", lower));
+
+        labels.into_iter().zip(counts).collect()
+    }
+
     pub fn avg_latency_ms(&self) -> f64 {
         let count = self.sample_count.load(Ordering::Relaxed);
         if count == 0 {
@@ -257,11 +636,42 @@ impl LatencyStats {
 
     pub fn min_latency_ms(&self) -> f64 {
         let min = self.min_latency_us.load(Ordering::Relaxed);
-        if min == u64::MAX { 0.0 } else { min as f64 / 1000.0 }
+        if min == i64::MAX { 0.0 } else { min as f64 / 1000.0 }
     }
 
     pub fn max_latency_ms(&self) -> f64 {
-        self.max_latency_us.load(Ordering::Relaxed) as f64 / 1000.0
+        let max = self.max_latency_us.load(Ordering::Relaxed);
+        if max == i64::MIN { 0.0 } else { max as f64 / 1000.0 }
+    }
+
+    /// Population standard deviation of the recent samples in ms, or 0 with fewer than two.
+    ///
+    /// Takes the mean first and then sums squared deviations, which stays accurate where a single
+    /// sum-of-squares pass would lose precision.
+    pub fn stddev_ms(&self) -> f64 {
+        let latencies: Vec<f64> = self.samples.read().iter().map(|s| s.arrival_delay_us as f64).collect();
+        if latencies.len() < 2 {
+            return 0.0;
+        }
+        let count = latencies.len() as f64;
+        let mean = latencies.iter().sum::<f64>() / count;
+        let variance = latencies.iter().map(|l| (l - mean).powi(2)).sum::<f64>() / count;
+        variance.sqrt() / 1000.0
+    }
+
+    /// Percentage of samples at or under the SLA target, if a target is set and samples exist
+    pub fn sla_met_pct(&self) -> Option<f64> {
+        self.sla_target_us?;
+        let total = self.sla_sample_count.load(Ordering::Relaxed);
+        if total == 0 {
+            return None;
+        }
+        Some(self.sla_met_count.load(Ordering::Relaxed) as f64 / total as f64 * 100.0)
+    }
+
+    pub fn reset_sla(&self) {
+        self.sla_met_count.store(0, Ordering::Relaxed);
+        self.sla_sample_count.store(0, Ordering::Relaxed);
     }
 }
 
@@ -281,11 +691,42 @@ pub struct ProgramActivity {
 #[derive(Debug)]
 pub struct ProgramStats {
     pub activities: RwLock<HashMap<Pubkey, ProgramActivity>>,
-    pub known_programs: HashMap<Pubkey, ProgramInfo>,
+    /// Built-in programs merged with any `--programs-file` definitions
+    pub known_programs: RwLock<HashMap<Pubkey, ProgramInfo>>,
     pub dex_txn_count: AtomicU64,
     pub lending_txn_count: AtomicU64,
     pub mev_txn_count: AtomicU64,
     pub staking_txn_count: AtomicU64,
+    /// Per-category transactions per second over trailing windows
+    pub dex_rate: RateTracker,
+    pub lending_rate: RateTracker,
+    pub mev_rate: RateTracker,
+    pub staking_rate: RateTracker,
+    /// DEX transactions that go through the Jupiter aggregator, and those that hit pools directly
+    pub routed_swap_count: AtomicU64,
+    pub direct_swap_count: AtomicU64,
+    pub wsol_wrap_count: AtomicU64,
+    pub wsol_unwrap_count: AtomicU64,
+    /// Likely probe transactions, excluded from the per-program and category counts
+    pub probe_txn_count: AtomicU64,
+    /// Transactions that load accounts through address lookup tables
+    pub lut_txn_count: AtomicU64,
+    /// Lookup table accounts that couldn't be resolved without on-chain data
+    pub lut_unresolved_accounts: AtomicU64,
+    /// DEX transactions per token mint they reference
+    pub mint_activity: RwLock<HashMap<Pubkey, u64>>,
+    /// Names given to programs at runtime or loaded from `--program-cache`; these win over other definitions
+    pub labels: RwLock<HashMap<Pubkey, String>>,
+    /// (slot, transactions) for each program's most recent active slots, oldest first
+    pub slot_activity: RwLock<HashMap<Pubkey, VecDeque<(Slot, u64)>>>,
+}
+
+/// Rename `program_id` in `programs`, adding it as `Other` if it isn't there
+fn apply_label(programs: &mut HashMap<Pubkey, ProgramInfo>, program_id: Pubkey, label: &str) {
+    programs
+        .entry(program_id)
+        .and_modify(|info| info.name = label.to_string())
+        .or_insert_with(|| ProgramInfo::new(label, ProgramCategory::Other));
 }
 
 impl Default for ProgramStats {
@@ -298,28 +739,145 @@ impl ProgramStats {
     pub fn new() -> Self {
         Self {
             activities: RwLock::new(HashMap::new()),
-            known_programs: KnownPrograms::get_all(),
+            known_programs: RwLock::new(KnownPrograms::get_all()),
             dex_txn_count: AtomicU64::new(0),
             lending_txn_count: AtomicU64::new(0),
             mev_txn_count: AtomicU64::new(0),
             staking_txn_count: AtomicU64::new(0),
+            dex_rate: RateTracker::new(),
+            lending_rate: RateTracker::new(),
+            mev_rate: RateTracker::new(),
+            staking_rate: RateTracker::new(),
+            routed_swap_count: AtomicU64::new(0),
+            direct_swap_count: AtomicU64::new(0),
+            wsol_wrap_count: AtomicU64::new(0),
+            wsol_unwrap_count: AtomicU64::new(0),
+            probe_txn_count: AtomicU64::new(0),
+            lut_txn_count: AtomicU64::new(0),
+            lut_unresolved_accounts: AtomicU64::new(0),
+            mint_activity: RwLock::new(HashMap::new()),
+            labels: RwLock::new(HashMap::new()),
+            slot_activity: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Clear activity counts, keeping the known program definitions
+    pub fn reset(&self) {
+        self.activities.write().clear();
+        self.mint_activity.write().clear();
+        self.slot_activity.write().clear();
+        for counter in [
+            &self.dex_txn_count,
+            &self.lending_txn_count,
+            &self.mev_txn_count,
+            &self.staking_txn_count,
+            &self.routed_swap_count,
+            &self.direct_swap_count,
+            &self.wsol_wrap_count,
+            &self.wsol_unwrap_count,
+            &self.probe_txn_count,
+            &self.lut_txn_count,
+            &self.lut_unresolved_accounts,
+        ] {
+            counter.store(0, Ordering::Relaxed);
         }
+        for rate in [&self.dex_rate, &self.lending_rate, &self.mev_rate, &self.staking_rate] {
+            rate.reset();
+        }
+    }
+
+    pub fn known_program(&self, program_id: &Pubkey) -> Option<ProgramInfo> {
+        self.known_programs.read().get(program_id).cloned()
     }
 
-    pub fn record_program(&self, program_id: Pubkey) {
+    /// Rebuild the known programs from the built-ins plus `custom`, which win on conflict.
+    ///
+    /// Returns how many entries were added, removed or changed.
+    pub fn set_custom_programs(&self, custom: HashMap<Pubkey, ProgramInfo>) -> usize {
+        let mut merged = KnownPrograms::get_all();
+        merged.extend(custom);
+        for (program_id, label) in self.labels.read().iter() {
+            apply_label(&mut merged, *program_id, label);
+        }
+
+        let mut known = self.known_programs.write();
+        let changed = merged.iter().filter(|(id, info)| known.get(id) != Some(info)).count()
+            + known.keys().filter(|id| !merged.contains_key(id)).count();
+        *known = merged;
+
+        // Relabel programs already in the table
         let mut activities = self.activities.write();
-        
-        let (name, category) = if let Some(info) = self.known_programs.get(&program_id) {
-            (info.name.clone(), info.category)
-        } else {
-            (program_id.to_string()[..8].to_string(), ProgramCategory::Other)
+        for activity in activities.values_mut() {
+            if let Some(info) = known.get(&activity.program_id) {
+                activity.name = info.name.clone();
+                activity.category = info.category;
+            }
+        }
+
+        changed
+    }
+
+    /// Name `program_id`, keeping its category if it's already known
+    pub fn label_program(&self, program_id: Pubkey, name: String) {
+        let mut known = self.known_programs.write();
+        apply_label(&mut known, program_id, &name);
+        if let Some(activity) = self.activities.write().get_mut(&program_id) {
+            activity.name = name.clone();
+        }
+        drop(known);
+        self.labels.write().insert(program_id, name);
+    }
+
+    /// Count a DEX transaction as routed through Jupiter or as a direct pool swap
+    pub fn record_swap(&self, routed: bool) {
+        let counter = if routed { &self.routed_swap_count } else { &self.direct_swap_count };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count one DEX transaction against each mint it references
+    pub fn record_mints(&self, mints: &[Pubkey]) {
+        let mut activity = self.mint_activity.write();
+        for mint in mints {
+            *activity.entry(*mint).or_default() += 1;
+        }
+    }
+
+    /// Mints by DEX transaction count, most active first
+    pub fn get_top_mints(&self, limit: usize) -> Vec<(Pubkey, u64)> {
+        let activity = self.mint_activity.read();
+        let mut mints: Vec<_> = activity.iter().map(|(mint, count)| (*mint, *count)).collect();
+        mints.sort_by(|a, b| b.1.cmp(&a.1));
+        mints.truncate(limit);
+        mints
+    }
+
+    pub fn record_program(&self, program_id: Pubkey, slot: Slot) {
+        self.record_program_slot(program_id, slot);
+
+        let (name, category) = match self.known_program(&program_id) {
+            Some(info) => (info.name, info.category),
+            None => (program_id.to_string()[..8].to_string(), ProgramCategory::Other),
         };
+
+        let mut activities = self.activities.write();
         
         match category {
-            ProgramCategory::Dex => { self.dex_txn_count.fetch_add(1, Ordering::Relaxed); }
-            ProgramCategory::Lending => { self.lending_txn_count.fetch_add(1, Ordering::Relaxed); }
-            ProgramCategory::Mev => { self.mev_txn_count.fetch_add(1, Ordering::Relaxed); }
-            ProgramCategory::Staking => { self.staking_txn_count.fetch_add(1, Ordering::Relaxed); }
+            ProgramCategory::Dex => {
+                self.dex_txn_count.fetch_add(1, Ordering::Relaxed);
+                self.dex_rate.record(1);
+            }
+            ProgramCategory::Lending => {
+                self.lending_txn_count.fetch_add(1, Ordering::Relaxed);
+                self.lending_rate.record(1);
+            }
+            ProgramCategory::Mev => {
+                self.mev_txn_count.fetch_add(1, Ordering::Relaxed);
+                self.mev_rate.record(1);
+            }
+            ProgramCategory::Staking => {
+                self.staking_txn_count.fetch_add(1, Ordering::Relaxed);
+                self.staking_rate.record(1);
+            }
             _ => {}
         }
         
@@ -337,6 +895,46 @@ impl ProgramStats {
             });
     }
 
+    /// Count one transaction for `program_id` in `slot`, keeping the last `PROGRAM_SLOT_HISTORY`
+    /// active slots for at most `MAX_PROGRAM_SLOT_RINGS` programs
+    fn record_program_slot(&self, program_id: Pubkey, slot: Slot) {
+        let mut rings = self.slot_activity.write();
+        if !rings.contains_key(&program_id) && rings.len() >= MAX_PROGRAM_SLOT_RINGS {
+            let idle = rings.iter()
+                .min_by_key(|(_, ring)| ring.back().map_or(0, |(slot, _)| *slot))
+                .map(|(program, _)| *program);
+            if let Some(idle) = idle {
+                rings.remove(&idle);
+            }
+        }
+
+        let ring = rings.entry(program_id).or_default();
+        // Batches for a slot can arrive after later slots, so look past the newest entry
+        match ring.iter_mut().rev().find(|(s, _)| *s == slot) {
+            Some((_, count)) => *count += 1,
+            None => {
+                ring.push_back((slot, 1));
+                if ring.len() > PROGRAM_SLOT_HISTORY {
+                    ring.pop_front();
+                }
+            }
+        }
+    }
+
+    /// Transactions per slot for `program_id` over the `PROGRAM_SLOT_HISTORY` slots ending at
+    /// `last_slot`, oldest first, with zero for slots it wasn't seen in
+    pub fn program_slot_counts(&self, program_id: &Pubkey, last_slot: Slot) -> Vec<u64> {
+        let rings = self.slot_activity.read();
+        let ring = rings.get(program_id);
+        let first_slot = last_slot.saturating_sub(PROGRAM_SLOT_HISTORY as u64 - 1);
+        (first_slot..=last_slot)
+            .map(|slot| {
+                ring.and_then(|ring| ring.iter().find(|(s, _)| *s == slot))
+                    .map_or(0, |(_, count)| *count)
+            })
+            .collect()
+    }
+
     pub fn get_top_programs(&self, limit: usize) -> Vec<ProgramActivity> {
         let activities = self.activities.read();
         let mut programs: Vec<_> = activities.values().cloned().collect();
@@ -346,6 +944,55 @@ impl ProgramStats {
     }
 }
 
+/// Transactions writing to each account, for the Hot Accounts panel.
+///
+/// Memory is bounded: once `MAX_TRACKED_ACCOUNTS` accounts are tracked, the least-written half
+/// is forgotten, so a rarely written account that returns later starts counting from zero.
+#[derive(Debug, Default)]
+pub struct AccountStats {
+    writes: RwLock<HashMap<Pubkey, u64>>,
+}
+
+impl AccountStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&self) {
+        self.writes.write().clear();
+    }
+
+    /// Count one transaction against each of its writable accounts
+    pub fn record_writes(&self, accounts: &[Pubkey]) {
+        let mut writes = self.writes.write();
+        for account in accounts {
+            *writes.entry(*account).or_default() += 1;
+        }
+
+        if writes.len() > MAX_TRACKED_ACCOUNTS {
+            // Ties at the cutoff are broken by key, so exactly half is kept even when most
+            // accounts have the same count
+            let mut entries: Vec<(u64, Pubkey)> = writes.drain().map(|(account, count)| (count, account)).collect();
+            entries.select_nth_unstable_by(MAX_TRACKED_ACCOUNTS / 2, |a, b| b.cmp(a));
+            entries.truncate(MAX_TRACKED_ACCOUNTS / 2);
+            writes.extend(entries.into_iter().map(|(count, account)| (account, count)));
+        }
+    }
+
+    /// The `limit` most-written accounts, busiest first
+    pub fn top_accounts(&self, limit: usize) -> Vec<(Pubkey, u64)> {
+        // Min-heap of the busiest accounts seen so far; the quietest is evicted past `limit`
+        let mut heap = BinaryHeap::with_capacity(limit + 1);
+        for (account, &count) in self.writes.read().iter() {
+            heap.push(Reverse((count, *account)));
+            if heap.len() > limit {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec().into_iter().map(|Reverse((count, account))| (account, count)).collect()
+    }
+}
+
 // ============================================================================
 // Leader Tracking
 // ============================================================================
@@ -353,7 +1000,8 @@ impl ProgramStats {
 #[derive(Debug, Clone)]
 pub struct LeaderSlotInfo {
     pub slot: Slot,
-    pub leader: Pubkey,
+    /// Scheduled leader, once the leader schedule covers the slot
+    pub leader: Option<Pubkey>,
     pub entry_count: u64,
     pub txn_count: u64,
     pub skip: bool,
@@ -368,9 +1016,19 @@ pub struct LeaderStats {
     pub slots_skipped: u64,
     pub total_txns: u64,
     pub avg_latency_ms: f64,
+    /// Newest slot this leader produced, if any
+    pub last_slot: Option<Slot>,
+    pub total_first_shred_delay_ms: f64,
+    /// Produced slots with a first-shred delay
+    pub first_shred_delay_count: u64,
 }
 
 impl LeaderStats {
+    pub fn avg_first_shred_delay_ms(&self) -> Option<f64> {
+        (self.first_shred_delay_count > 0)
+            .then(|| self.total_first_shred_delay_ms / self.first_shred_delay_count as f64)
+    }
+
     pub fn skip_rate(&self) -> f64 {
         if self.slots_seen == 0 {
             0.0
@@ -386,6 +1044,16 @@ pub struct LeaderTracker {
     pub leader_stats: RwLock<HashMap<Pubkey, LeaderStats>>,
     pub current_leader: RwLock<Option<Pubkey>>,
     pub upcoming_leaders: RwLock<Vec<(Slot, Pubkey)>>,
+    /// Activated stake (lamports) per validator identity, from RPC
+    pub stakes: RwLock<HashMap<Pubkey, u64>>,
+    /// Validator names by identity, from `--validators-file`
+    pub validator_names: RwLock<HashMap<Pubkey, String>>,
+    /// All stakes sorted ascending, for percentile lookups
+    sorted_stakes: RwLock<Vec<u64>>,
+    /// Recently observed slots not yet checked for skips
+    observed_slots: RwLock<BTreeSet<Slot>>,
+    /// Scheduled slots up to here have been checked for skips
+    skip_checked_through: AtomicU64,
 }
 
 impl LeaderTracker {
@@ -395,25 +1063,155 @@ impl LeaderTracker {
             leader_stats: RwLock::new(HashMap::new()),
             current_leader: RwLock::new(None),
             upcoming_leaders: RwLock::new(Vec::new()),
+            stakes: RwLock::new(HashMap::new()),
+            validator_names: RwLock::new(HashMap::new()),
+            sorted_stakes: RwLock::new(Vec::new()),
+            observed_slots: RwLock::new(BTreeSet::new()),
+            skip_checked_through: AtomicU64::new(0),
         }
     }
 
-    pub fn record_slot(&self, info: LeaderSlotInfo) {
-        *self.current_leader.write() = Some(info.leader);
-        
-        {
-            let mut stats = self.leader_stats.write();
-            let leader_stat = stats.entry(info.leader).or_insert_with(|| LeaderStats {
-                leader: info.leader,
-                ..Default::default()
+    /// Clear per-leader history and stats. The schedule, stakes and skip-check progress are kept,
+    /// so slots already checked aren't counted as skipped again.
+    pub fn reset(&self) {
+        self.slot_history.write().clear();
+        self.leader_stats.write().clear();
+    }
+
+    /// Replace the validator names, returning how many entries were added, removed or renamed
+    pub fn set_validator_names(&self, names: HashMap<Pubkey, String>) -> usize {
+        let mut current = self.validator_names.write();
+        let changed = names.iter().filter(|(id, name)| current.get(id) != Some(name)).count()
+            + current.keys().filter(|id| !names.contains_key(id)).count();
+        *current = names;
+        changed
+    }
+
+    /// Replace the leader schedule with (slot, leader) pairs sorted by slot. Recent slots recorded
+    /// before the schedule covered them are credited to their leader now.
+    pub fn set_schedule(&self, schedule: Vec<(Slot, Pubkey)>) {
+        *self.upcoming_leaders.write() = schedule;
+        let mut history = self.slot_history.write();
+        for info in history.iter_mut().filter(|info| info.leader.is_none()) {
+            if let Some(leader) = self.leader_for_slot(info.slot) {
+                info.leader = Some(leader);
+                self.count_slot(leader, info);
+            }
+        }
+    }
+
+    /// Note that entries arrived for `slot` and record any scheduled slots that
+    /// were passed without producing entries as skipped. Returns the skipped slots.
+    pub fn observe_slot(&self, slot: Slot) -> Vec<Slot> {
+        let check_until = slot.saturating_sub(SKIP_DETECTION_LAG);
+        let checked_through = self.skip_checked_through.load(Ordering::Relaxed);
+
+        let mut observed = self.observed_slots.write();
+        observed.insert(slot);
+
+        if check_until <= checked_through {
+            return Vec::new();
+        }
+        self.skip_checked_through.store(check_until, Ordering::Relaxed);
+
+        // First slot seen, or a jump after an outage: start checking from here
+        if checked_through == 0 || check_until - checked_through > SKIP_DETECTION_MAX_GAP {
+            *observed = observed.split_off(&(check_until + 1));
+            return Vec::new();
+        }
+
+        let skipped: Vec<(Slot, Pubkey)> = self.upcoming_leaders.read()
+            .iter()
+            .filter(|(s, _)| *s > checked_through && *s <= check_until && !observed.contains(s))
+            .copied()
+            .collect();
+        *observed = observed.split_off(&(check_until + 1));
+        drop(observed);
+
+        for &(skipped_slot, leader) in &skipped {
+            self.record_slot(LeaderSlotInfo {
+                slot: skipped_slot,
+                leader: Some(leader),
+                entry_count: 0,
+                txn_count: 0,
+                skip: true,
+                first_shred_delay_ms: None,
+                timestamp: Local::now(),
             });
-            leader_stat.slots_seen += 1;
-            if info.skip {
-                leader_stat.slots_skipped += 1;
+        }
+
+        skipped.into_iter().map(|(s, _)| s).collect()
+    }
+
+    pub fn set_stakes(&self, stakes: HashMap<Pubkey, u64>) {
+        let mut sorted: Vec<u64> = stakes.values().copied().collect();
+        sorted.sort_unstable();
+        *self.sorted_stakes.write() = sorted;
+        *self.stakes.write() = stakes;
+    }
+
+    pub fn stake_of(&self, leader: &Pubkey) -> Option<u64> {
+        self.stakes.read().get(leader).copied()
+    }
+
+    /// Percentage of validators with stake at or below this leader's
+    pub fn stake_percentile(&self, leader: &Pubkey) -> Option<f64> {
+        let stake = self.stake_of(leader)?;
+        let sorted = self.sorted_stakes.read();
+        if sorted.is_empty() {
+            return None;
+        }
+        let at_or_below = sorted.partition_point(|&s| s <= stake);
+        Some(at_or_below as f64 / sorted.len() as f64 * 100.0)
+    }
+
+    /// Scheduled leader for a slot, if the schedule covers it
+    pub fn leader_for_slot(&self, slot: Slot) -> Option<Pubkey> {
+        let schedule = self.upcoming_leaders.read();
+        schedule
+            .binary_search_by_key(&slot, |(s, _)| *s)
+            .ok()
+            .map(|i| schedule[i].1)
+    }
+
+    /// Record data for an observed slot, counting it for its leader when that's known. Further
+    /// batches for an already recorded slot are merged into it so `slots_seen` counts each slot once,
+    /// and a slot recorded as skipped is counted as produced instead if its entries arrive late.
+    pub fn record_slot(&self, info: LeaderSlotInfo) {
+        if info.leader.is_some() {
+            *self.current_leader.write() = info.leader;
+        }
+
+        let merged = {
+            let mut history = self.slot_history.write();
+            history.iter_mut().rev().find(|s| s.slot == info.slot).map(|existing| {
+                // Entries for a slot already recorded as skipped arrived late after all
+                let revived = existing.skip && !info.skip;
+                existing.skip &= info.skip;
+                existing.entry_count += info.entry_count;
+                existing.txn_count += info.txn_count;
+                (existing.leader, revived)
+            })
+        };
+        match merged {
+            Some((Some(leader), revived)) => {
+                if let Some(stats) = self.leader_stats.write().get_mut(&leader) {
+                    stats.total_txns += info.txn_count;
+                    if revived {
+                        stats.slots_skipped = stats.slots_skipped.saturating_sub(1);
+                        stats.last_slot = stats.last_slot.max(Some(info.slot));
+                    }
+                }
+                return;
             }
-            leader_stat.total_txns += info.txn_count;
+            Some((None, _)) => return,
+            None => {}
         }
-        
+
+        if let Some(leader) = info.leader {
+            self.count_slot(leader, &info);
+        }
+
         let mut history = self.slot_history.write();
         if history.len() >= MAX_LEADER_HISTORY {
             history.pop_front();
@@ -421,6 +1219,39 @@ impl LeaderTracker {
         history.push_back(info);
     }
 
+    /// Fold a newly recorded slot into `leader`'s stats
+    fn count_slot(&self, leader: Pubkey, info: &LeaderSlotInfo) {
+        let mut stats = self.leader_stats.write();
+        let leader_stat = stats.entry(leader).or_insert_with(|| LeaderStats {
+            leader,
+            ..Default::default()
+        });
+        leader_stat.slots_seen += 1;
+        if info.skip {
+            leader_stat.slots_skipped += 1;
+        } else {
+            leader_stat.last_slot = leader_stat.last_slot.max(Some(info.slot));
+        }
+        if let Some(delay) = info.first_shred_delay_ms {
+            leader_stat.total_first_shred_delay_ms += delay;
+            leader_stat.first_shred_delay_count += 1;
+        }
+        leader_stat.total_txns += info.txn_count;
+    }
+
+    /// Skip rate across all leaders seen, as a percentage
+    pub fn overall_skip_rate(&self) -> f64 {
+        let stats = self.leader_stats.read();
+        let (seen, skipped) = stats
+            .values()
+            .fold((0, 0), |(seen, skipped), s| (seen + s.slots_seen, skipped + s.slots_skipped));
+        if seen == 0 {
+            0.0
+        } else {
+            (skipped as f64 / seen as f64) * 100.0
+        }
+    }
+
     pub fn get_top_leaders(&self, limit: usize) -> Vec<LeaderStats> {
         let stats = self.leader_stats.read();
         let mut leaders: Vec<_> = stats.values().cloned().collect();
@@ -472,6 +1303,18 @@ impl TurbineStats {
         }
     }
 
+    pub fn reset(&self) {
+        self.samples.write().clear();
+        self.total_samples.store(0, Ordering::Relaxed);
+        self.sum_index.store(0, Ordering::Relaxed);
+        self.min_index.store(u64::MAX, Ordering::Relaxed);
+        self.max_index.store(0, Ordering::Relaxed);
+        self.layer_0_count.store(0, Ordering::Relaxed);
+        self.layer_1_count.store(0, Ordering::Relaxed);
+        self.layer_2_count.store(0, Ordering::Relaxed);
+        self.layer_3_plus_count.store(0, Ordering::Relaxed);
+    }
+
     pub fn add_sample(&self, info: TurbineInfo) {
         let index = info.turbine_index as u64;
         
@@ -542,26 +1385,97 @@ pub struct BundleInfo {
     pub tip_account: String,
     pub signatures: Vec<String>,
     pub timestamp: DateTime<Local>,
+    pub status: BundleStatus,
 }
 
-#[derive(Debug, Clone)]
-pub struct SandwichPattern {
-    pub slot: Slot,
-    pub victim_sig: String,
-    pub frontrun_sig: String,
-    pub backrun_sig: String,
-    pub timestamp: DateTime<Local>,
+/// Whether a bundle made it on chain, as confirmed over `--rpc-url`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BundleStatus {
+    /// Not confirmed yet, or never checked without `--rpc-url`
+    #[default]
+    Pending,
+    /// Every transaction confirmed successfully
+    Landed,
+    /// At least one transaction confirmed with an error
+    Failed,
+    /// Not every transaction was found before the bundle stopped being checked
+    Dropped,
 }
 
-#[derive(Debug, Default)]
-pub struct CompetitionStats {
-    pub bundles: RwLock<VecDeque<BundleInfo>>,
+impl std::fmt::Display for BundleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BundleStatus::Pending => write!(f, "pending"),
+            BundleStatus::Landed => write!(f, "landed"),
+            BundleStatus::Failed => write!(f, "failed"),
+            BundleStatus::Dropped => write!(f, "dropped"),
+        }
+    }
+}
+
+/// A bundle waiting for its signatures to be confirmed
+#[derive(Debug, Clone)]
+struct PendingBundle {
+    signatures: Vec<String>,
+    seen_at: DateTime<Local>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SandwichPattern {
+    pub slot: Slot,
+    pub victim_sig: String,
+    pub frontrun_sig: String,
+    pub backrun_sig: String,
+    pub timestamp: DateTime<Local>,
+}
+
+/// A transaction seen again within the dedup window; differing slots point at cross-region re-broadcasts
+#[derive(Debug, Clone)]
+pub struct DuplicateInfo {
+    pub signature: String,
+    pub first_slot: Slot,
+    pub dup_slot: Slot,
+    pub timestamp: DateTime<Local>,
+}
+
+/// Priority fees paid in one slot, over transactions that set a compute unit price
+#[derive(Debug, Clone, Default)]
+pub struct SlotPriorityFees {
+    pub slot: Slot,
+    pub txn_count: u64,
+    pub total_lamports: u64,
+    pub max_lamports: u64,
+}
+
+/// Tips aggregated per Jito tip account
+#[derive(Debug, Clone, Default)]
+pub struct TipAccountStat {
+    pub total_lamports: u64,
+    pub bundle_count: u64,
+    pub max_tip_lamports: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct CompetitionStats {
+    pub bundles: RwLock<VecDeque<BundleInfo>>,
     pub sandwiches: RwLock<VecDeque<SandwichPattern>>,
-    pub duplicate_txns: RwLock<VecDeque<String>>,
+    pub duplicate_txns: RwLock<VecDeque<DuplicateInfo>>,
+    pub tip_account_stats: RwLock<HashMap<String, TipAccountStat>>,
+    /// Priority fees per recent slot, oldest first
+    pub priority_fees: RwLock<VecDeque<SlotPriorityFees>>,
+    /// Signatures of recent transactions that invoked two or more distinct DEX programs
+    pub arbitrage_txns: RwLock<VecDeque<String>>,
     pub bundle_count: AtomicU64,
     pub total_tips_lamports: AtomicU64,
     pub sandwich_count: AtomicU64,
     pub duplicate_count: AtomicU64,
+    pub arbitrage_count: AtomicU64,
+    /// Queue bundles for landing confirmation; set when `--rpc-url` is given
+    pub track_landing: bool,
+    pending_bundles: RwLock<VecDeque<PendingBundle>>,
+    pub bundles_landed: AtomicU64,
+    pub bundles_failed: AtomicU64,
+    pub bundles_dropped: AtomicU64,
 }
 
 impl CompetitionStats {
@@ -570,17 +1484,108 @@ impl CompetitionStats {
             bundles: RwLock::new(VecDeque::with_capacity(MAX_BUNDLE_SAMPLES)),
             sandwiches: RwLock::new(VecDeque::with_capacity(MAX_BUNDLE_SAMPLES)),
             duplicate_txns: RwLock::new(VecDeque::with_capacity(MAX_TXN_SAMPLES)),
+            tip_account_stats: RwLock::new(HashMap::new()),
+            priority_fees: RwLock::new(VecDeque::with_capacity(MAX_PRIORITY_FEE_SLOTS)),
+            arbitrage_txns: RwLock::new(VecDeque::with_capacity(MAX_TXN_SAMPLES)),
             bundle_count: AtomicU64::new(0),
             total_tips_lamports: AtomicU64::new(0),
             sandwich_count: AtomicU64::new(0),
             duplicate_count: AtomicU64::new(0),
+            arbitrage_count: AtomicU64::new(0),
+            track_landing: false,
+            pending_bundles: RwLock::new(VecDeque::new()),
+            bundles_landed: AtomicU64::new(0),
+            bundles_failed: AtomicU64::new(0),
+            bundles_dropped: AtomicU64::new(0),
+        }
+    }
+
+    pub fn reset(&self) {
+        self.bundles.write().clear();
+        self.sandwiches.write().clear();
+        self.duplicate_txns.write().clear();
+        self.tip_account_stats.write().clear();
+        self.priority_fees.write().clear();
+        self.arbitrage_txns.write().clear();
+        self.bundle_count.store(0, Ordering::Relaxed);
+        self.total_tips_lamports.store(0, Ordering::Relaxed);
+        self.sandwich_count.store(0, Ordering::Relaxed);
+        self.duplicate_count.store(0, Ordering::Relaxed);
+        self.arbitrage_count.store(0, Ordering::Relaxed);
+        self.pending_bundles.write().clear();
+        self.bundles_landed.store(0, Ordering::Relaxed);
+        self.bundles_failed.store(0, Ordering::Relaxed);
+        self.bundles_dropped.store(0, Ordering::Relaxed);
+    }
+
+    pub fn add_duplicate(&self, duplicate: DuplicateInfo) {
+        self.duplicate_count.fetch_add(1, Ordering::Relaxed);
+
+        let mut dups = self.duplicate_txns.write();
+        if dups.len() >= MAX_TXN_SAMPLES {
+            dups.pop_front();
+        }
+        dups.push_back(duplicate);
+    }
+
+    /// Add a transaction's priority fee to its slot; slots arrive in several batches, so the
+    /// newest slot is updated in place
+    pub fn record_priority_fee(&self, slot: Slot, lamports: u64) {
+        let mut fees = self.priority_fees.write();
+        if fees.back().is_none_or(|f| f.slot != slot) {
+            if fees.len() >= MAX_PRIORITY_FEE_SLOTS {
+                fees.pop_front();
+            }
+            fees.push_back(SlotPriorityFees { slot, ..Default::default() });
+        }
+        let entry = fees.back_mut().expect("just pushed");
+        entry.txn_count += 1;
+        entry.total_lamports += lamports;
+        entry.max_lamports = entry.max_lamports.max(lamports);
+    }
+
+    /// Average and max priority fee per paying transaction over the recent slots, with the slot count
+    pub fn priority_fee_summary(&self) -> Option<(f64, u64, usize)> {
+        let fees = self.priority_fees.read();
+        let txns: u64 = fees.iter().map(|f| f.txn_count).sum();
+        if txns == 0 {
+            return None;
+        }
+        let total: u64 = fees.iter().map(|f| f.total_lamports).sum();
+        let max = fees.iter().map(|f| f.max_lamports).max().unwrap_or(0);
+        Some((total as f64 / txns as f64, max, fees.len()))
+    }
+
+    pub fn add_arbitrage(&self, signature: String) {
+        self.arbitrage_count.fetch_add(1, Ordering::Relaxed);
+
+        let mut arbs = self.arbitrage_txns.write();
+        if arbs.len() >= MAX_TXN_SAMPLES {
+            arbs.pop_front();
         }
+        arbs.push_back(signature);
     }
 
     pub fn add_bundle(&self, bundle: BundleInfo) {
         self.bundle_count.fetch_add(1, Ordering::Relaxed);
         self.total_tips_lamports.fetch_add(bundle.tip_amount, Ordering::Relaxed);
+
+        {
+            let mut tip_stats = self.tip_account_stats.write();
+            let stat = tip_stats.entry(bundle.tip_account.clone()).or_default();
+            stat.total_lamports += bundle.tip_amount;
+            stat.bundle_count += 1;
+            stat.max_tip_lamports = stat.max_tip_lamports.max(bundle.tip_amount);
+        }
         
+        if self.track_landing {
+            let mut pending = self.pending_bundles.write();
+            if pending.len() >= MAX_PENDING_BUNDLES {
+                pending.pop_front();
+            }
+            pending.push_back(PendingBundle { signatures: bundle.signatures.clone(), seen_at: bundle.timestamp });
+        }
+
         let mut bundles = self.bundles.write();
         if bundles.len() >= MAX_BUNDLE_SAMPLES {
             bundles.pop_front();
@@ -588,6 +1593,67 @@ impl CompetitionStats {
         bundles.push_back(bundle);
     }
 
+    /// Signatures of the bundles still waiting for confirmation, oldest first
+    pub fn pending_bundle_signatures(&self) -> Vec<String> {
+        self.pending_bundles.read().iter()
+            .flat_map(|bundle| bundle.signatures.iter().cloned())
+            .collect()
+    }
+
+    /// Settle pending bundles from the signature statuses known so far: landed once every
+    /// transaction succeeded, failed once any failed, and dropped if still unresolved from before
+    /// `expire_before` although every unresolved signature is in `looked_up`. Bundles still in
+    /// Recent Bundles are updated to match.
+    pub fn settle_bundles(&self, statuses: &HashMap<String, TxnStatus>, looked_up: &HashSet<String>, expire_before: DateTime<Local>) {
+        let mut settled = Vec::new();
+        self.pending_bundles.write().retain(|bundle| {
+            let known: Vec<_> = bundle.signatures.iter().filter_map(|sig| statuses.get(sig)).collect();
+            let all_looked_up = || bundle.signatures.iter().all(|sig| statuses.contains_key(sig) || looked_up.contains(sig));
+            let status = if known.contains(&&TxnStatus::Failed) {
+                BundleStatus::Failed
+            } else if known.len() == bundle.signatures.len() {
+                BundleStatus::Landed
+            } else if bundle.seen_at < expire_before && all_looked_up() {
+                BundleStatus::Dropped
+            } else {
+                return true;
+            };
+            settled.push((bundle.signatures[0].clone(), status));
+            false
+        });
+
+        let mut bundles = self.bundles.write();
+        for (first_signature, status) in settled {
+            let counter = match status {
+                BundleStatus::Landed => &self.bundles_landed,
+                BundleStatus::Failed => &self.bundles_failed,
+                _ => &self.bundles_dropped,
+            };
+            counter.fetch_add(1, Ordering::Relaxed);
+            if let Some(bundle) = bundles.iter_mut().find(|b| b.signatures.first() == Some(&first_signature)) {
+                bundle.status = status;
+            }
+        }
+    }
+
+    /// Share of settled bundles that landed, once any have settled
+    pub fn land_rate_pct(&self) -> Option<f64> {
+        let landed = self.bundles_landed.load(Ordering::Relaxed);
+        let settled = landed
+            + self.bundles_failed.load(Ordering::Relaxed)
+            + self.bundles_dropped.load(Ordering::Relaxed);
+        (settled > 0).then(|| landed as f64 / settled as f64 * 100.0)
+    }
+
+    /// Tip accounts by total tips received, largest first
+    pub fn get_top_tip_accounts(&self, limit: usize) -> Vec<(String, TipAccountStat)> {
+        let tip_stats = self.tip_account_stats.read();
+        let mut accounts: Vec<_> = tip_stats.iter().map(|(a, s)| (a.clone(), s.clone())).collect();
+        accounts.sort_by(|a, b| b.1.total_lamports.cmp(&a.1.total_lamports));
+        accounts.truncate(limit);
+        accounts
+    }
+
     pub fn total_tips_sol(&self) -> f64 {
         self.total_tips_lamports.load(Ordering::Relaxed) as f64 / 1_000_000_000.0
     }
@@ -597,22 +1663,58 @@ impl CompetitionStats {
 // Wallet Monitoring
 // ============================================================================
 
+/// Execution result of a transaction. Shreds don't carry it, so it stays `Unknown`
+/// unless RPC confirms the signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnStatus {
+    Unknown,
+    Success,
+    Failed,
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletTxn {
+    /// The watched wallet among the transaction's account keys
+    pub wallet: Pubkey,
     pub slot: Slot,
     pub signature: String,
     pub timestamp: DateTime<Local>,
-    pub success: bool,
+    pub status: TxnStatus,
     pub programs: Vec<String>,
 }
 
+/// Transaction counts for one watched wallet
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WalletStats {
+    pub txn_count: u64,
+    pub success_count: u64,
+    pub fail_count: u64,
+    pub unknown_count: u64,
+}
+
+impl WalletStats {
+    fn status_count(&mut self, status: TxnStatus) -> &mut u64 {
+        match status {
+            TxnStatus::Unknown => &mut self.unknown_count,
+            TxnStatus::Success => &mut self.success_count,
+            TxnStatus::Failed => &mut self.fail_count,
+        }
+    }
+
+    fn add(&mut self, other: &WalletStats) {
+        self.txn_count += other.txn_count;
+        self.success_count += other.success_count;
+        self.fail_count += other.fail_count;
+        self.unknown_count += other.unknown_count;
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct WalletMonitor {
-    pub wallet: RwLock<Option<Pubkey>>,
+    pub wallets: RwLock<HashSet<Pubkey>>,
+    /// Counts per watched wallet; a transaction touching several of them counts for each
+    pub stats: RwLock<HashMap<Pubkey, WalletStats>>,
     pub transactions: RwLock<VecDeque<WalletTxn>>,
-    pub txn_count: AtomicU64,
-    pub success_count: AtomicU64,
-    pub fail_count: AtomicU64,
 }
 
 impl WalletMonitor {
@@ -620,24 +1722,87 @@ impl WalletMonitor {
         Self::default()
     }
 
-    pub fn set_wallet(&self, wallet: Pubkey) {
-        *self.wallet.write() = Some(wallet);
+    /// Start monitoring `wallet`; returns false if it already was
+    pub fn add_wallet(&self, wallet: Pubkey) -> bool {
+        self.wallets.write().insert(wallet)
+    }
+
+    /// Stop monitoring `wallet`, discarding what was collected for it
+    pub fn remove_wallet(&self, wallet: &Pubkey) -> bool {
+        if !self.wallets.write().remove(wallet) {
+            return false;
+        }
+        self.stats.write().remove(wallet);
+        self.transactions.write().retain(|t| &t.wallet != wallet);
+        true
+    }
+
+    /// Discard collected transactions, keeping the monitored wallets
+    pub fn reset(&self) {
+        self.transactions.write().clear();
+        self.stats.write().clear();
+    }
+
+    /// Counts summed over every watched wallet
+    pub fn totals(&self) -> WalletStats {
+        let mut totals = WalletStats::default();
+        for stats in self.stats.read().values() {
+            totals.add(stats);
+        }
+        totals
+    }
+
+    /// Watched wallets with their counts, busiest first
+    pub fn breakdown(&self) -> Vec<(Pubkey, WalletStats)> {
+        let stats = self.stats.read();
+        let mut rows: Vec<(Pubkey, WalletStats)> = self.wallets.read().iter()
+            .map(|wallet| (*wallet, stats.get(wallet).copied().unwrap_or_default()))
+            .collect();
+        rows.sort_by(|a, b| b.1.txn_count.cmp(&a.1.txn_count).then_with(|| a.0.cmp(&b.0)));
+        rows
     }
 
     pub fn add_txn(&self, txn: WalletTxn) {
-        self.txn_count.fetch_add(1, Ordering::Relaxed);
-        if txn.success {
-            self.success_count.fetch_add(1, Ordering::Relaxed);
-        } else {
-            self.fail_count.fetch_add(1, Ordering::Relaxed);
+        {
+            let mut stats = self.stats.write();
+            let wallet_stats = stats.entry(txn.wallet).or_default();
+            wallet_stats.txn_count += 1;
+            *wallet_stats.status_count(txn.status) += 1;
         }
-        
+
         let mut txns = self.transactions.write();
         if txns.len() >= MAX_TXN_SAMPLES {
             txns.pop_front();
         }
         txns.push_back(txn);
     }
+
+    /// Signatures still `Unknown` that were seen at or after `since`, oldest first
+    pub fn unknown_signatures(&self, since: DateTime<Local>) -> Vec<String> {
+        let txns = self.transactions.read();
+        // A transaction is listed once per watched wallet it touches
+        let mut seen = HashSet::new();
+        txns.iter()
+            .filter(|t| t.status == TxnStatus::Unknown && t.timestamp >= since && seen.insert(t.signature.as_str()))
+            .map(|t| t.signature.clone())
+            .collect()
+    }
+
+    /// Record a confirmed status for `Unknown` transactions with `signature`; returns false if there were none to update
+    pub fn set_status(&self, signature: &str, status: TxnStatus) -> bool {
+        let mut txns = self.transactions.write();
+        let mut stats = self.stats.write();
+        let mut updated = false;
+        for txn in txns.iter_mut().filter(|t| t.signature == signature && t.status == TxnStatus::Unknown) {
+            txn.status = status;
+            if let Some(wallet_stats) = stats.get_mut(&txn.wallet) {
+                wallet_stats.unknown_count = wallet_stats.unknown_count.saturating_sub(1);
+                *wallet_stats.status_count(status) += 1;
+            }
+            updated = true;
+        }
+        updated
+    }
 }
 
 // ============================================================================
@@ -648,9 +1813,19 @@ impl WalletMonitor {
 pub struct NetworkHealth {
     pub fec_recovery_count: AtomicU64,
     pub direct_receive_count: AtomicU64,
+    /// Most recent slots skipped over by the stream, oldest first
     pub missed_slots: RwLock<VecDeque<Slot>>,
+    pub missed_slot_count: AtomicU64,
+    /// Slots the stream has advanced through, received or missed
+    pub slot_span: AtomicU64,
+    /// Newest slot seen before the stream dropped, until the first slot after reconnecting
+    pub last_slot_before_disconnect: RwLock<Option<Slot>>,
+    /// Slots missed while disconnected; also included in `missed_slot_count`
+    pub outage_missed_slots: AtomicU64,
     pub heartbeat_success: AtomicU64,
     pub heartbeat_fail: AtomicU64,
+    /// Messages for a slot that arrived after a higher slot had already been seen
+    pub out_of_order_count: AtomicU64,
 }
 
 impl NetworkHealth {
@@ -658,6 +1833,19 @@ impl NetworkHealth {
         Self::default()
     }
 
+    /// Zero the counters; an outage in progress is still measured when the stream returns
+    pub fn reset(&self) {
+        self.fec_recovery_count.store(0, Ordering::Relaxed);
+        self.direct_receive_count.store(0, Ordering::Relaxed);
+        self.missed_slots.write().clear();
+        self.missed_slot_count.store(0, Ordering::Relaxed);
+        self.slot_span.store(0, Ordering::Relaxed);
+        self.outage_missed_slots.store(0, Ordering::Relaxed);
+        self.heartbeat_success.store(0, Ordering::Relaxed);
+        self.heartbeat_fail.store(0, Ordering::Relaxed);
+        self.out_of_order_count.store(0, Ordering::Relaxed);
+    }
+
     pub fn fec_recovery_rate(&self) -> f64 {
         let recovered = self.fec_recovery_count.load(Ordering::Relaxed);
         let direct = self.direct_receive_count.load(Ordering::Relaxed);
@@ -665,12 +1853,101 @@ impl NetworkHealth {
         if total == 0 { 0.0 } else { (recovered as f64 / total as f64) * 100.0 }
     }
 
+    /// Record the slots strictly between `last` and `next` as missed
+    pub fn record_gap(&self, last: Slot, next: Slot) {
+        self.slot_span.fetch_add(next.saturating_sub(last), Ordering::Relaxed);
+        if next <= last + 1 {
+            return;
+        }
+        self.missed_slot_count.fetch_add(next - last - 1, Ordering::Relaxed);
+
+        // Only the newest slots of a long gap are worth keeping
+        let first_kept = (last + 1).max(next.saturating_sub(MAX_MISSED_SLOTS as u64));
+        let mut missed = self.missed_slots.write();
+        for slot in first_kept..next {
+            if missed.len() >= MAX_MISSED_SLOTS {
+                missed.pop_front();
+            }
+            missed.push_back(slot);
+        }
+    }
+
+    /// Remember where the stream stopped so the gap can be measured on reconnect
+    pub fn mark_disconnected(&self, last_slot: Slot) {
+        if last_slot > 0 {
+            *self.last_slot_before_disconnect.write() = Some(last_slot);
+        }
+    }
+
+    /// Slots skipped between the disconnect and `first_slot`, the first slot after reconnecting.
+    ///
+    /// Returns `None` if no disconnect is pending. A proxy that restarts at a lower slot yields 0.
+    pub fn take_outage_gap(&self, first_slot: Slot) -> Option<u64> {
+        let last = self.last_slot_before_disconnect.write().take()?;
+        let gap = first_slot.saturating_sub(last + 1);
+        self.outage_missed_slots.fetch_add(gap, Ordering::Relaxed);
+        Some(gap)
+    }
+
     pub fn heartbeat_success_rate(&self) -> f64 {
         let success = self.heartbeat_success.load(Ordering::Relaxed);
         let fail = self.heartbeat_fail.load(Ordering::Relaxed);
         let total = success + fail;
         if total == 0 { 100.0 } else { (success as f64 / total as f64) * 100.0 }
     }
+
+    /// Connection quality from 0 (bad) to 100 (good), given the reconnects over `uptime`.
+    ///
+    /// Each factor scores 1.0 when perfect, falling linearly to 0.0 at its "bad" level, and the
+    /// score is their weighted average. Factors without data yet are left out and the remaining
+    /// weights rescaled; with no data at all the score is a neutral 50.
+    pub fn score(&self, reconnects: u64, uptime: Duration) -> f64 {
+        const HEARTBEAT_WEIGHT: f64 = 0.25;
+        const FEC_WEIGHT: f64 = 0.20;
+        const RECONNECT_WEIGHT: f64 = 0.25;
+        const MISSED_SLOT_WEIGHT: f64 = 0.30;
+        /// FEC-recovered share of shreds (%) that means heavy packet loss
+        const FEC_BAD_PCT: f64 = 20.0;
+        const RECONNECTS_BAD_PER_HOUR: f64 = 6.0;
+        /// Share of slots missed that counts as a broken stream
+        const MISSED_SLOTS_BAD_RATIO: f64 = 0.05;
+        /// Data needed before a factor counts
+        const MIN_UPTIME: Duration = Duration::from_secs(60);
+        const MIN_SLOT_SPAN: u64 = 25;
+        const NEUTRAL_SCORE: f64 = 50.0;
+
+        let falling = |value: f64, bad: f64| 1.0 - (value / bad).clamp(0.0, 1.0);
+
+        let heartbeats = self.heartbeat_success.load(Ordering::Relaxed) + self.heartbeat_fail.load(Ordering::Relaxed);
+        let shreds = self.fec_recovery_count.load(Ordering::Relaxed) + self.direct_receive_count.load(Ordering::Relaxed);
+        let span = self.slot_span.load(Ordering::Relaxed);
+
+        let factors = [
+            (HEARTBEAT_WEIGHT, (heartbeats > 0).then(|| self.heartbeat_success_rate() / 100.0)),
+            (FEC_WEIGHT, (shreds > 0).then(|| falling(self.fec_recovery_rate(), FEC_BAD_PCT))),
+            (
+                RECONNECT_WEIGHT,
+                (uptime >= MIN_UPTIME).then(|| {
+                    falling(reconnects as f64 * 3600.0 / uptime.as_secs_f64(), RECONNECTS_BAD_PER_HOUR)
+                }),
+            ),
+            (
+                MISSED_SLOT_WEIGHT,
+                (span >= MIN_SLOT_SPAN).then(|| {
+                    falling(self.missed_slot_count.load(Ordering::Relaxed) as f64 / span as f64, MISSED_SLOTS_BAD_RATIO)
+                }),
+            ),
+        ];
+
+        let (weighted, total_weight) = factors
+            .iter()
+            .filter_map(|(weight, value)| value.map(|v| (weight * v, *weight)))
+            .fold((0.0, 0.0), |(sum, weights), (v, w)| (sum + v, weights + w));
+        if total_weight == 0.0 {
+            return NEUTRAL_SCORE;
+        }
+        weighted / total_weight * 100.0
+    }
 }
 
 // ============================================================================
@@ -692,6 +1969,39 @@ pub struct ShredMetrics {
     pub total_duplicate: AtomicU64,
     pub total_entries: AtomicU64,
     pub total_txns: AtomicU64,
+    /// Vote transactions in the current window, tracked whether or not they're in `txn_count`
+    pub vote_txn_count: AtomicU64,
+    pub total_vote_txns: AtomicU64,
+    /// Whether vote transactions are included in `txn_count` and `total_txns` (`--count-votes`)
+    pub count_votes: bool,
+    /// Entry payloads that could not be decoded and were dropped
+    pub deserialize_failures: AtomicU64,
+    /// Entry payload bytes received, before decoding
+    pub bytes: AtomicU64,
+    pub total_bytes: AtomicU64,
+}
+
+/// Snapshot of the cumulative counters shown on Overview
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CounterTotals {
+    pub entries: u64,
+    pub txns: u64,
+    pub vote_txns: u64,
+    pub bytes: u64,
+    pub decode_errors: u64,
+}
+
+impl CounterTotals {
+    /// Growth since `baseline`
+    pub fn since(&self, baseline: &CounterTotals) -> CounterTotals {
+        CounterTotals {
+            entries: self.entries.saturating_sub(baseline.entries),
+            txns: self.txns.saturating_sub(baseline.txns),
+            vote_txns: self.vote_txns.saturating_sub(baseline.vote_txns),
+            bytes: self.bytes.saturating_sub(baseline.bytes),
+            decode_errors: self.decode_errors.saturating_sub(baseline.decode_errors),
+        }
+    }
 }
 
 impl ShredMetrics {
@@ -699,6 +2009,30 @@ impl ShredMetrics {
         Self::default()
     }
 
+    pub fn totals(&self) -> CounterTotals {
+        CounterTotals {
+            entries: self.total_entries.load(Ordering::Relaxed),
+            txns: self.total_txns.load(Ordering::Relaxed),
+            vote_txns: self.total_vote_txns.load(Ordering::Relaxed),
+            bytes: self.total_bytes.load(Ordering::Relaxed),
+            decode_errors: self.deserialize_failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Zero the window and cumulative counters
+    pub fn reset(&self) {
+        self.reset_window();
+        self.total_received.store(0, Ordering::Relaxed);
+        self.total_success_forward.store(0, Ordering::Relaxed);
+        self.total_fail_forward.store(0, Ordering::Relaxed);
+        self.total_duplicate.store(0, Ordering::Relaxed);
+        self.total_entries.store(0, Ordering::Relaxed);
+        self.total_txns.store(0, Ordering::Relaxed);
+        self.total_vote_txns.store(0, Ordering::Relaxed);
+        self.deserialize_failures.store(0, Ordering::Relaxed);
+        self.total_bytes.store(0, Ordering::Relaxed);
+    }
+
     pub fn add_entry(&self, entry_count: u64, txn_count: u64) {
         self.entry_count.fetch_add(entry_count, Ordering::Relaxed);
         self.txn_count.fetch_add(txn_count, Ordering::Relaxed);
@@ -706,6 +2040,11 @@ impl ShredMetrics {
         self.total_txns.fetch_add(txn_count, Ordering::Relaxed);
     }
 
+    pub fn add_bytes(&self, bytes: u64) {
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+        self.total_bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
     pub fn get_entries_per_sec(&self, duration_secs: f64) -> f64 {
         if duration_secs <= 0.0 { return 0.0; }
         self.entry_count.load(Ordering::Relaxed) as f64 / duration_secs
@@ -716,6 +2055,22 @@ impl ShredMetrics {
         self.txn_count.load(Ordering::Relaxed) as f64 / duration_secs
     }
 
+    pub fn add_votes(&self, vote_count: u64) {
+        self.vote_txn_count.fetch_add(vote_count, Ordering::Relaxed);
+        self.total_vote_txns.fetch_add(vote_count, Ordering::Relaxed);
+    }
+
+    pub fn get_non_vote_txns_per_sec(&self, duration_secs: f64) -> f64 {
+        if duration_secs <= 0.0 { return 0.0; }
+        let txns = self.txn_count.load(Ordering::Relaxed);
+        let non_vote = if self.count_votes {
+            txns.saturating_sub(self.vote_txn_count.load(Ordering::Relaxed))
+        } else {
+            txns
+        };
+        non_vote as f64 / duration_secs
+    }
+
     pub fn reset_window(&self) {
         self.received.store(0, Ordering::Relaxed);
         self.success_forward.store(0, Ordering::Relaxed);
@@ -723,113 +2078,635 @@ impl ShredMetrics {
         self.duplicate.store(0, Ordering::Relaxed);
         self.entry_count.store(0, Ordering::Relaxed);
         self.txn_count.store(0, Ordering::Relaxed);
+        self.vote_txn_count.store(0, Ordering::Relaxed);
+        self.bytes.store(0, Ordering::Relaxed);
         self.recovered_count.store(0, Ordering::Relaxed);
     }
 }
 
-// ============================================================================
-// Main Application State
-// ============================================================================
+/// Where the transactions in decoded entries ended up, to judge how representative the stats are
+#[derive(Debug, Default)]
+pub struct TxnCoverage {
+    /// Transactions in entries that decoded
+    pub seen: AtomicU64,
+    /// Skipped because they carried no signature
+    pub unsigned: AtomicU64,
+    /// Vote transactions, which are counted but not analyzed
+    pub votes: AtomicU64,
+    /// Added to the Recent Transactions samples
+    pub sampled: AtomicU64,
+}
 
-pub struct AppState {
-    pub proxy_url: String,
-    pub connection_state: RwLock<ConnectionState>,
-    pub connected_at: RwLock<Option<Instant>>,
-    pub reconnect_count: AtomicU64,
+impl TxnCoverage {
+    /// Count one decoded batch
+    pub fn record_batch(&self, seen: u64, unsigned: u64, votes: u64, sampled: u64) {
+        self.seen.fetch_add(seen, Ordering::Relaxed);
+        self.unsigned.fetch_add(unsigned, Ordering::Relaxed);
+        self.votes.fetch_add(votes, Ordering::Relaxed);
+        self.sampled.fetch_add(sampled, Ordering::Relaxed);
+    }
 
-    pub metrics: ShredMetrics,
-    pub metrics_window_start: RwLock<Instant>,
+    /// Transactions that went through the full analysis
+    pub fn analyzed(&self) -> u64 {
+        self.seen.load(Ordering::Relaxed)
+            .saturating_sub(self.unsigned.load(Ordering::Relaxed))
+            .saturating_sub(self.votes.load(Ordering::Relaxed))
+    }
 
-    pub current_slot: AtomicU64,
-    pub slot_history: RwLock<VecDeque<SlotInfo>>,
-    pub txn_samples: RwLock<VecDeque<TxnSample>>,
+    /// Share of non-vote transactions that were fully analyzed, once any have been seen
+    pub fn analyzed_pct(&self) -> Option<f64> {
+        let non_vote = self.seen.load(Ordering::Relaxed).saturating_sub(self.votes.load(Ordering::Relaxed));
+        (non_vote > 0).then(|| self.analyzed() as f64 / non_vote as f64 * 100.0)
+    }
 
-    pub latency_stats: LatencyStats,
-    pub program_stats: ProgramStats,
-    pub leader_tracker: LeaderTracker,
-    pub turbine_stats: TurbineStats,
-    pub competition_stats: CompetitionStats,
-    pub wallet_monitor: WalletMonitor,
-    pub network_health: NetworkHealth,
+    pub fn reset(&self) {
+        self.seen.store(0, Ordering::Relaxed);
+        self.unsigned.store(0, Ordering::Relaxed);
+        self.votes.store(0, Ordering::Relaxed);
+        self.sampled.store(0, Ordering::Relaxed);
+    }
+}
 
-    pub logs: RwLock<VecDeque<LogEntry>>,
+// ============================================================================
+// MEV Activity Index
+// ============================================================================
 
-    pub selected_tab: RwLock<usize>,
-    pub scroll_offset: RwLock<usize>,
-    pub show_help: RwLock<bool>,
+/// Component levels at which each part of the MEV index saturates
+const MEV_FULL_BUNDLES_PER_SEC: f64 = 20.0;
+const MEV_FULL_DEX_SHARE: f64 = 0.5;
+const MEV_FULL_ARB_CANDIDATES_PER_SEC: f64 = 50.0;
+const MEV_FULL_TIP_SOL_PER_SEC: f64 = 0.5;
 
-    pub start_time: Instant,
+/// Component weights, summing to 1.0
+const MEV_WEIGHT_BUNDLES: f64 = 0.30;
+const MEV_WEIGHT_DEX_SHARE: f64 = 0.20;
+const MEV_WEIGHT_ARB_CANDIDATES: f64 = 0.20;
+const MEV_WEIGHT_TIPS: f64 = 0.30;
+
+/// Cumulative counters the index is computed from
+#[derive(Debug, Clone, Copy)]
+struct MevTotals {
+    at: Instant,
+    bundles: u64,
+    tips_lamports: u64,
+    txns: u64,
+    dex_txns: u64,
+    arb_candidates: u64,
 }
 
-impl AppState {
-    pub fn new(proxy_url: String) -> Self {
+impl MevTotals {
+    fn capture(state: &AppState) -> Self {
         Self {
-            proxy_url,
-            connection_state: RwLock::new(ConnectionState::Disconnected),
-            connected_at: RwLock::new(None),
-            reconnect_count: AtomicU64::new(0),
-            metrics: ShredMetrics::new(),
-            metrics_window_start: RwLock::new(Instant::now()),
-            current_slot: AtomicU64::new(0),
-            slot_history: RwLock::new(VecDeque::with_capacity(MAX_SLOT_HISTORY)),
-            txn_samples: RwLock::new(VecDeque::with_capacity(MAX_TXN_SAMPLES)),
-            latency_stats: LatencyStats::new(),
-            program_stats: ProgramStats::new(),
-            leader_tracker: LeaderTracker::new(),
-            turbine_stats: TurbineStats::new(),
-            competition_stats: CompetitionStats::new(),
-            wallet_monitor: WalletMonitor::new(),
-            network_health: NetworkHealth::new(),
-            logs: RwLock::new(VecDeque::with_capacity(MAX_LOG_ENTRIES)),
-            selected_tab: RwLock::new(0),
-            scroll_offset: RwLock::new(0),
-            show_help: RwLock::new(false),
-            start_time: Instant::now(),
+            at: Instant::now(),
+            bundles: state.competition_stats.bundle_count.load(Ordering::Relaxed),
+            tips_lamports: state.competition_stats.total_tips_lamports.load(Ordering::Relaxed),
+            txns: state.metrics.total_txns.load(Ordering::Relaxed),
+            dex_txns: state.program_stats.dex_txn_count.load(Ordering::Relaxed),
+            arb_candidates: state.competition_stats.arbitrage_count.load(Ordering::Relaxed),
         }
     }
+}
 
-    pub fn log(&self, level: LogLevel, message: impl Into<String>) {
-        let mut logs = self.logs.write();
-        if logs.len() >= MAX_LOG_ENTRIES {
-            logs.pop_front();
+/// A 0–100 summary of how much MEV activity the stream is showing.
+///
+/// Each second the deltas of four counters are scaled against a saturation
+/// level, clamped to 1.0 and combined as a weighted sum:
+///
+/// | Component                          | Saturates at | Weight |
+/// |------------------------------------|--------------|--------|
+/// | Bundles per second                 | 20/s         | 30%    |
+/// | DEX share of transactions          | 50%          | 20%    |
+/// | Arbitrage transactions             | 50/s         | 20%    |
+/// | Tips paid                          | 0.5 SOL/s    | 30%    |
+///
+/// Arbitrage transactions are those invoking two or more distinct DEX programs.
+#[derive(Debug)]
+pub struct MevIndex {
+    last: RwLock<Option<MevTotals>>,
+    pub history: RwLock<VecDeque<u64>>,
+}
+
+impl Default for MevIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MevIndex {
+    pub fn new() -> Self {
+        Self {
+            last: RwLock::new(None),
+            history: RwLock::new(VecDeque::with_capacity(MAX_MEV_INDEX_HISTORY)),
         }
-        logs.push_back(LogEntry {
-            timestamp: Local::now(),
-            level,
-            message: message.into(),
-        });
     }
 
-    pub fn log_info(&self, message: impl Into<String>) {
-        self.log(LogLevel::Info, message);
+    pub fn reset(&self) {
+        *self.last.write() = None;
+        self.history.write().clear();
     }
 
-    pub fn log_warn(&self, message: impl Into<String>) {
-        self.log(LogLevel::Warn, message);
+    /// Latest index value, once a full second has been observed
+    pub fn current(&self) -> Option<u64> {
+        self.history.read().back().copied()
     }
 
-    pub fn log_error(&self, message: impl Into<String>) {
-        self.log(LogLevel::Error, message);
+    fn score(prev: &MevTotals, now: &MevTotals) -> u64 {
+        let secs = now.at.duration_since(prev.at).as_secs_f64();
+        let per_sec = |a: u64, b: u64| b.saturating_sub(a) as f64 / secs;
+        let txns = now.txns.saturating_sub(prev.txns);
+        let dex_share = if txns == 0 {
+            0.0
+        } else {
+            now.dex_txns.saturating_sub(prev.dex_txns) as f64 / txns as f64
+        };
+        let tip_sol_per_sec = per_sec(prev.tips_lamports, now.tips_lamports) / 1_000_000_000.0;
+
+        let components = [
+            (per_sec(prev.bundles, now.bundles) / MEV_FULL_BUNDLES_PER_SEC, MEV_WEIGHT_BUNDLES),
+            (dex_share / MEV_FULL_DEX_SHARE, MEV_WEIGHT_DEX_SHARE),
+            (per_sec(prev.arb_candidates, now.arb_candidates) / MEV_FULL_ARB_CANDIDATES_PER_SEC, MEV_WEIGHT_ARB_CANDIDATES),
+            (tip_sol_per_sec / MEV_FULL_TIP_SOL_PER_SEC, MEV_WEIGHT_TIPS),
+        ];
+        let weighted: f64 = components.iter().map(|(level, weight)| level.min(1.0) * weight).sum();
+        (weighted * 100.0).round() as u64
     }
+}
 
-    pub fn set_connection_state(&self, state: ConnectionState) {
-        let mut conn_state = self.connection_state.write();
-        if *conn_state != state {
-            self.log_info(format!("Connection state: {}", state));
-            *conn_state = state.clone();
-            if state == ConnectionState::Connected {
-                *self.connected_at.write() = Some(Instant::now());
-            }
-        }
+// ============================================================================
+// Trailing Rates
+// ============================================================================
+
+/// Counts bucketed by whole second, for rates over a trailing window.
+///
+/// Only completed seconds are used, so a rate doesn't dip while the current
+/// second is still filling up.
+#[derive(Debug)]
+pub struct RateTracker {
+    origin: Instant,
+    /// (seconds since `origin`, count) for the last `RATE_TRACKER_SECS` seconds with activity
+    buckets: RwLock<VecDeque<(u64, u64)>>,
+}
+
+impl Default for RateTracker {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    pub fn add_slot(&self, slot: Slot, entry_count: u64, txn_count: u64) {
-        let current = self.current_slot.load(Ordering::Relaxed);
-        if slot > current {
-            self.current_slot.store(slot, Ordering::Relaxed);
+impl RateTracker {
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    pub fn starting_at(origin: Instant) -> Self {
+        Self {
+            origin,
+            buckets: RwLock::new(VecDeque::with_capacity(RATE_TRACKER_SECS as usize)),
         }
+    }
 
-        let mut history = self.slot_history.write();
+    pub fn reset(&self) {
+        self.buckets.write().clear();
+    }
+
+    fn second(&self, at: Instant) -> u64 {
+        at.saturating_duration_since(self.origin).as_secs()
+    }
+
+    pub fn record(&self, count: u64) {
+        self.record_at(Instant::now(), count);
+    }
+
+    pub fn record_at(&self, at: Instant, count: u64) {
+        let second = self.second(at);
+        let mut buckets = self.buckets.write();
+        match buckets.back_mut() {
+            Some((last, total)) if *last == second => *total += count,
+            _ => buckets.push_back((second, count)),
+        }
+        while buckets.front().is_some_and(|(s, _)| *s + RATE_TRACKER_SECS <= second) {
+            buckets.pop_front();
+        }
+    }
+
+    /// Per-second rate over the last `secs` completed seconds, or `None` before the first one completes
+    pub fn rate(&self, secs: u64) -> Option<f64> {
+        self.rate_at(Instant::now(), secs)
+    }
+
+    pub fn rate_at(&self, at: Instant, secs: u64) -> Option<f64> {
+        let current = self.second(at);
+        // Young trackers average over the seconds they have
+        let secs = secs.min(current).min(RATE_TRACKER_SECS);
+        if secs == 0 {
+            return None;
+        }
+        let total: u64 = self.buckets.read()
+            .iter()
+            .filter(|(s, _)| *s < current && *s >= current - secs)
+            .map(|(_, count)| count)
+            .sum();
+        Some(total as f64 / secs as f64)
+    }
+}
+
+// ============================================================================
+// Paused View
+// ============================================================================
+
+/// Copies of the scrolling lists taken when the UI is paused.
+///
+/// Only the lists are frozen; counters and rates keep advancing underneath.
+#[derive(Debug, Clone)]
+pub struct FrozenLists {
+    pub slot_history: VecDeque<SlotInfo>,
+    pub txn_rate_history: VecDeque<u64>,
+    pub slot_latency: VecDeque<SlotLatency>,
+    pub turbine_samples: VecDeque<TurbineInfo>,
+    pub bundles: VecDeque<BundleInfo>,
+    pub txn_samples: VecDeque<TxnSample>,
+    pub duplicates: VecDeque<DuplicateInfo>,
+    pub logs: VecDeque<LogEntry>,
+    pub wallet_txns: VecDeque<WalletTxn>,
+}
+
+impl FrozenLists {
+    pub fn capture(state: &AppState) -> Self {
+        Self {
+            slot_history: state.slot_history.read().clone(),
+            txn_rate_history: state.txn_rate_history.read().clone(),
+            slot_latency: state.latency_stats.slot_history.read().clone(),
+            turbine_samples: state.turbine_stats.samples.read().clone(),
+            bundles: state.competition_stats.bundles.read().clone(),
+            txn_samples: state.txn_samples.read().clone(),
+            duplicates: state.competition_stats.duplicate_txns.read().clone(),
+            logs: state.logs.read().clone(),
+            wallet_txns: state.wallet_monitor.transactions.read().clone(),
+        }
+    }
+}
+
+// ============================================================================
+// Main Application State
+// ============================================================================
+
+/// Whether `signature` starts with `prefix`. Base58 is case-sensitive, so case is not folded.
+fn signature_matches(signature: &str, prefix: &str) -> bool {
+    signature.starts_with(prefix)
+}
+
+/// What an open text input box edits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputTarget {
+    /// The monitored wallet, on the Wallet tab
+    Wallet,
+    /// The display name of a program, on the Programs tab
+    ProgramLabel(Pubkey),
+    /// The signature prefix Recent Transactions is filtered by, on the Programs tab
+    SignatureSearch,
+}
+
+/// Contents of a text input box while it's open
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    pub target: InputTarget,
+    pub text: String,
+    /// Why the last submission was rejected
+    pub error: Option<String>,
+}
+
+/// Unit used when displaying rates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateUnit {
+    PerSecond,
+    PerSlot,
+}
+
+impl RateUnit {
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            RateUnit::PerSecond => "/s",
+            RateUnit::PerSlot => "/slot",
+        }
+    }
+}
+
+/// What the Overview counters count from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterScope {
+    /// Since the process started (or the last full reset)
+    AllTime,
+    /// Since the current connection was established
+    Connection,
+}
+
+/// Column the Latency tab's leader table is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaderSort {
+    /// Lowest average latency first
+    Avg,
+    /// Lowest worst-case latency first
+    Max,
+    /// Most samples first
+    Samples,
+}
+
+impl LeaderSort {
+    pub fn next(self) -> Self {
+        match self {
+            LeaderSort::Avg => LeaderSort::Max,
+            LeaderSort::Max => LeaderSort::Samples,
+            LeaderSort::Samples => LeaderSort::Avg,
+        }
+    }
+
+    pub fn sort(self, leaders: &mut [LeaderLatencyStats]) {
+        match self {
+            LeaderSort::Avg => leaders.sort_by(|a, b| a.avg_latency_ms().total_cmp(&b.avg_latency_ms())),
+            LeaderSort::Max => leaders.sort_by_key(|l| l.max_latency_us),
+            LeaderSort::Samples => leaders.sort_by(|a, b| b.sample_count.cmp(&a.sample_count)),
+        }
+    }
+}
+
+pub struct AppState {
+    /// Proxy endpoint currently in use
+    pub proxy_url: RwLock<String>,
+    /// Region label of the active endpoint, attached to its latency samples
+    pub region: RwLock<Option<String>>,
+    pub connection_state: RwLock<ConnectionState>,
+    /// Connection state transitions, oldest first
+    pub connection_events: RwLock<VecDeque<(DateTime<Local>, ConnectionState)>>,
+    pub connected_at: RwLock<Option<Instant>>,
+    pub reconnect_count: AtomicU64,
+    pub next_retry_at: RwLock<Option<Instant>>,
+    /// Wakes a client that gave up under `--max-reconnects`
+    pub reconnect_requested: tokio::sync::Notify,
+
+    pub metrics: ShredMetrics,
+    pub metrics_window_start: RwLock<Instant>,
+    /// How long a metrics window runs before it's reset (`--metrics-window`)
+    pub metrics_window: Duration,
+    /// Smoothed txn/s for the Overview tab; `None` until the first usable sample
+    pub ema_txns_per_sec: RwLock<Option<f64>>,
+    /// Weight of each new sample in `ema_txns_per_sec`
+    pub ema_alpha: f64,
+    /// Transactions per second over trailing windows, independent of metrics window resets
+    pub txn_rate: RateTracker,
+    /// Entry payload bytes per second over trailing windows
+    pub byte_rate: RateTracker,
+
+    pub current_slot: AtomicU64,
+    /// First slot seen and when it arrived; slot drift is measured from here
+    pub slot_anchor: RwLock<Option<(Slot, Instant)>>,
+    /// Average ms per slot beyond the nominal 400ms since the anchor; negative when slots arrive fast
+    pub slot_drift_ms: RwLock<Option<f64>>,
+    /// Newest slot the client has received off the stream
+    pub last_received_slot: AtomicU64,
+    /// Newest slot the UI loop has drained from the client channel
+    pub last_rendered_slot: AtomicU64,
+    pub slot_history: RwLock<VecDeque<SlotInfo>>,
+    /// Per-slot transaction counts for the rate sparkline
+    pub txn_rate_history: RwLock<VecDeque<u64>>,
+    /// (minute start, entries, txns) per wall-clock minute, newest last
+    pub minute_buckets: RwLock<VecDeque<(DateTime<Local>, u64, u64)>>,
+    pub txn_samples: RwLock<VecDeque<TxnSample>>,
+    pub txn_sampler: RwLock<ReservoirSampler>,
+    /// Sampling weight of DEX and bundle transactions relative to others (`--flagged-sample-weight`)
+    pub flagged_sample_weight: f64,
+    pub coverage: TxnCoverage,
+
+    pub latency_stats: LatencyStats,
+    pub program_stats: ProgramStats,
+    pub account_stats: AccountStats,
+    pub leader_tracker: LeaderTracker,
+    pub turbine_stats: TurbineStats,
+    pub competition_stats: CompetitionStats,
+    pub mev_index: MevIndex,
+    pub wallet_monitor: WalletMonitor,
+    pub network_health: NetworkHealth,
+    pub rpc_health: RwLock<RpcHealth>,
+
+    pub logs: RwLock<VecDeque<LogEntry>>,
+    pub alert_thresholds: AlertThresholds,
+    /// Thresholds currently exceeded, at most one per kind
+    pub active_alerts: RwLock<Vec<Alert>>,
+    /// Remaining ticks the footer flashes for a newly raised alert
+    pub alert_flash_ticks: AtomicU64,
+    /// Short confirmation shown in the footer, with the time it was set
+    pub notice: RwLock<Option<(String, Instant)>>,
+
+    pub selected_tab: RwLock<usize>,
+    /// Highlighted row on tabs with selectable rows; reset on tab switch
+    pub selected_row: RwLock<usize>,
+    /// Rows the selectable list on screen showed when last drawn, for page jumps
+    pub page_rows: AtomicUsize,
+    pub show_help: RwLock<bool>,
+    pub show_info: RwLock<bool>,
+    pub text_input: RwLock<Option<TextInput>>,
+    /// The bundle shown in the detail popup, copied when it was opened
+    pub bundle_detail: RwLock<Option<BundleInfo>>,
+    /// Program whose detail popup is open, from Enter on the Programs tab
+    pub program_detail: RwLock<Option<Pubkey>>,
+    /// Ask before quitting on `q` (`--confirm-quit`)
+    pub confirm_quit: bool,
+    /// Set while the "Quit? (y/n)" prompt is open
+    pub quit_pending: RwLock<bool>,
+    /// Set while the UI is paused, holding the lists as they were at pause time
+    pub paused: RwLock<Option<FrozenLists>>,
+    pub rate_unit: RwLock<RateUnit>,
+    pub counter_scope: RwLock<CounterScope>,
+    /// Cumulative counters when the current connection was established
+    pub connection_baseline: RwLock<CounterTotals>,
+    pub leader_sort: RwLock<LeaderSort>,
+    /// Region whose leaders the Latency tab's leader table shows; `None` for all regions
+    pub latency_region: RwLock<Option<String>>,
+    /// Smallest tip shown in Recent Bundles (`--min-tip-sol`); counters include every bundle
+    pub min_tip_lamports: Option<u64>,
+    /// Whether `min_tip_lamports` is applied, toggled from the Competition tab
+    pub tip_filter_enabled: RwLock<bool>,
+    /// Hide slots and sampled transactions without DEX or bundle activity (`--dex-only`, toggled with d)
+    pub dex_only: RwLock<bool>,
+    /// Signature prefix Recent Transactions is narrowed to, set with `/`
+    pub signature_search: RwLock<Option<String>>,
+    /// UI redraw interval in ms, read by the UI loop every iteration
+    pub tick_rate_ms: AtomicU64,
+    pub category_colors: CategoryColors,
+    pub theme: Theme,
+    /// Startup settings listed in the info overlay, as label/value pairs
+    pub runtime_config: Vec<(&'static str, String)>,
+    /// Digit grouping used when formatting counts
+    pub locale: Locale,
+    /// Where program labels are saved (`--program-cache`)
+    pub program_cache: Option<PathBuf>,
+
+    pub start_time: Instant,
+}
+
+impl AppState {
+    pub fn new(proxy_url: String) -> Self {
+        Self {
+            proxy_url: RwLock::new(proxy_url),
+            region: RwLock::new(None),
+            connection_state: RwLock::new(ConnectionState::Disconnected),
+            connection_events: RwLock::new(VecDeque::with_capacity(MAX_CONNECTION_EVENTS)),
+            connected_at: RwLock::new(None),
+            reconnect_count: AtomicU64::new(0),
+            next_retry_at: RwLock::new(None),
+            reconnect_requested: tokio::sync::Notify::new(),
+            metrics: ShredMetrics::new(),
+            metrics_window_start: RwLock::new(Instant::now()),
+            metrics_window: Duration::from_secs(DEFAULT_METRICS_WINDOW_SECS),
+            ema_txns_per_sec: RwLock::new(None),
+            ema_alpha: DEFAULT_EMA_ALPHA,
+            txn_rate: RateTracker::new(),
+            byte_rate: RateTracker::new(),
+            current_slot: AtomicU64::new(0),
+            slot_anchor: RwLock::new(None),
+            slot_drift_ms: RwLock::new(None),
+            last_received_slot: AtomicU64::new(0),
+            last_rendered_slot: AtomicU64::new(0),
+            slot_history: RwLock::new(VecDeque::with_capacity(MAX_SLOT_HISTORY)),
+            txn_rate_history: RwLock::new(VecDeque::with_capacity(MAX_TXN_RATE_HISTORY)),
+            minute_buckets: RwLock::new(VecDeque::with_capacity(MAX_MINUTE_BUCKETS)),
+            txn_samples: RwLock::new(VecDeque::with_capacity(MAX_TXN_SAMPLES)),
+            txn_sampler: RwLock::new(ReservoirSampler::new(MAX_TXN_SAMPLES, SAMPLE_HORIZON_WEIGHT)),
+            flagged_sample_weight: DEFAULT_FLAGGED_SAMPLE_WEIGHT,
+            coverage: TxnCoverage::default(),
+            latency_stats: LatencyStats::new(),
+            program_stats: ProgramStats::new(),
+            account_stats: AccountStats::new(),
+            leader_tracker: LeaderTracker::new(),
+            turbine_stats: TurbineStats::new(),
+            competition_stats: CompetitionStats::new(),
+            mev_index: MevIndex::new(),
+            wallet_monitor: WalletMonitor::new(),
+            network_health: NetworkHealth::new(),
+            rpc_health: RwLock::new(RpcHealth::Disabled),
+            logs: RwLock::new(VecDeque::with_capacity(MAX_LOG_ENTRIES)),
+            alert_thresholds: AlertThresholds::default(),
+            active_alerts: RwLock::new(Vec::new()),
+            alert_flash_ticks: AtomicU64::new(0),
+            notice: RwLock::new(None),
+            selected_tab: RwLock::new(0),
+            selected_row: RwLock::new(0),
+            page_rows: AtomicUsize::new(1),
+            show_help: RwLock::new(false),
+            show_info: RwLock::new(false),
+            text_input: RwLock::new(None),
+            bundle_detail: RwLock::new(None),
+            program_detail: RwLock::new(None),
+            confirm_quit: false,
+            quit_pending: RwLock::new(false),
+            paused: RwLock::new(None),
+            rate_unit: RwLock::new(RateUnit::PerSecond),
+            counter_scope: RwLock::new(CounterScope::AllTime),
+            connection_baseline: RwLock::new(CounterTotals::default()),
+            leader_sort: RwLock::new(LeaderSort::Avg),
+            latency_region: RwLock::new(None),
+            min_tip_lamports: None,
+            tip_filter_enabled: RwLock::new(false),
+            dex_only: RwLock::new(false),
+            signature_search: RwLock::new(None),
+            tick_rate_ms: AtomicU64::new(100),
+            category_colors: CategoryColors::default(),
+            theme: Theme::default(),
+            runtime_config: Vec::new(),
+            locale: Locale::en,
+            program_cache: None,
+            start_time: Instant::now(),
+        }
+    }
+
+    pub fn log(&self, level: LogLevel, message: impl Into<String>) {
+        let mut logs = self.logs.write();
+        if logs.len() >= MAX_LOG_ENTRIES {
+            logs.pop_front();
+        }
+        logs.push_back(LogEntry {
+            timestamp: Local::now(),
+            level,
+            message: message.into(),
+        });
+    }
+
+    pub fn log_info(&self, message: impl Into<String>) {
+        self.log(LogLevel::Info, message);
+    }
+
+    pub fn log_warn(&self, message: impl Into<String>) {
+        self.log(LogLevel::Warn, message);
+    }
+
+    pub fn log_error(&self, message: impl Into<String>) {
+        self.log(LogLevel::Error, message);
+    }
+
+    pub fn set_connection_state(&self, state: ConnectionState) {
+        let mut conn_state = self.connection_state.write();
+        if *conn_state != state {
+            self.log_info(format!("Connection state: {}", state));
+            *conn_state = state.clone();
+            let mut events = self.connection_events.write();
+            if events.len() >= MAX_CONNECTION_EVENTS {
+                events.pop_front();
+            }
+            events.push_back((Local::now(), state.clone()));
+            drop(events);
+            if state == ConnectionState::Connected {
+                *self.connected_at.write() = Some(Instant::now());
+                *self.connection_baseline.write() = self.metrics.totals();
+            }
+        }
+    }
+
+    /// Record a batch of entries for `slot`, returning the slot's first-shred delay if this is
+    /// the first batch of a new slot and the slot pace is known
+    pub fn add_slot(&self, slot: Slot, entry_count: u64, txn_count: u64, dex_txn_count: u64, jito_bundle_count: u64) -> Option<f64> {
+        let now = Instant::now();
+        let current = self.current_slot.load(Ordering::Relaxed);
+        let complete = slot >= current;
+        let mut first_shred_delay_ms = None;
+        if let Some(gap) = self.network_health.take_outage_gap(slot) {
+            if gap > 0 {
+                self.log_warn(format!("Missed ~{} slots during outage", gap));
+            }
+        }
+        if slot > current {
+            self.current_slot.store(slot, Ordering::Relaxed);
+            // Measured against the pace before this slot, which the drift update then folds it into
+            first_shred_delay_ms = self.slot_anchor.read()
+                .zip(*self.slot_drift_ms.read())
+                .and_then(|(anchor, drift)| slot_arrival_delay_ms(anchor, drift, slot, now));
+            self.update_slot_drift(slot, now);
+            // Nothing to compare against until the first slot arrives
+            if current > 0 {
+                self.network_health.record_gap(current, slot);
+            }
+        } else if slot < current {
+            self.network_health.out_of_order_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let leader = self.leader_tracker.leader_for_slot(slot);
+        if let Some(delay_ms) = first_shred_delay_ms {
+            self.latency_stats.add_sample(LatencySample {
+                slot,
+                timestamp: Local::now(),
+                // Early arrivals stay negative so averages, percentiles and the SLA aren't skewed
+                arrival_delay_us: (delay_ms * 1000.0).round() as i64,
+                leader,
+                region: self.region.read().clone(),
+                turbine_index: None,
+            });
+        }
+
+        let mut history = self.slot_history.write();
+        if !complete {
+            // The batches already listed were cut off when the later slot started
+            for info in history.iter_mut().filter(|info| info.slot == slot) {
+                info.complete = false;
+            }
+        }
         if history.len() >= MAX_SLOT_HISTORY {
             history.pop_front();
         }
@@ -837,58 +2714,352 @@ impl AppState {
             slot,
             entry_count,
             txn_count,
-            received_at: Instant::now(),
+            received_at: now,
             timestamp: Local::now(),
-            first_shred_delay_ms: None,
-            leader: None,
-            dex_txn_count: 0,
-            jito_bundle_count: 0,
+            first_shred_delay_ms,
+            leader,
+            dex_txn_count,
+            jito_bundle_count,
             turbine_index: None,
+            complete,
         });
+        drop(history);
+
+        {
+            let mut rate_history = self.txn_rate_history.write();
+            if rate_history.len() >= MAX_TXN_RATE_HISTORY {
+                rate_history.pop_front();
+            }
+            rate_history.push_back(txn_count);
+        }
 
         self.metrics.add_entry(entry_count, txn_count);
+        self.txn_rate.record(txn_count);
+        self.record_minute(Local::now(), entry_count, txn_count);
+        first_shred_delay_ms
     }
 
-    pub fn add_txn_sample(&self, slot: Slot, signature: String, programs: Vec<String>, is_bundle: bool, tip_amount: Option<u64>) {
-        let mut samples = self.txn_samples.write();
-        if samples.len() >= MAX_TXN_SAMPLES {
-            samples.pop_front();
+    /// Count a raw entries payload as it comes off the stream, whether or not it decodes
+    pub fn record_payload(&self, bytes: u64) {
+        self.metrics.add_bytes(bytes);
+        self.byte_rate.record(bytes);
+    }
+
+    /// Add counts to the bucket for `at`'s minute, starting a new bucket when the minute changes
+    pub fn record_minute(&self, at: DateTime<Local>, entry_count: u64, txn_count: u64) {
+        let minute = at.with_second(0).and_then(|t| t.with_nanosecond(0)).unwrap_or(at);
+        let mut buckets = self.minute_buckets.write();
+        match buckets.back_mut() {
+            // Late arrivals for an earlier minute land in the current bucket rather than reopening an old one
+            Some((start, entries, txns)) if *start >= minute => {
+                *entries += entry_count;
+                *txns += txn_count;
+            }
+            _ => {
+                if buckets.len() >= MAX_MINUTE_BUCKETS {
+                    buckets.pop_front();
+                }
+                buckets.push_back((minute, entry_count, txn_count));
+            }
         }
-        samples.push_back(TxnSample {
+    }
+
+    /// Offer a transaction to the sampler, weighting DEX and bundle transactions up; returns
+    /// whether it was kept
+    pub fn add_txn_sample(&self, slot: Slot, signature: String, programs: Vec<String>, is_dex: bool, is_bundle: bool, tip_amount: Option<u64>) -> bool {
+        let weight = if is_dex || is_bundle { self.flagged_sample_weight } else { 1.0 };
+        let sample = TxnSample {
             slot,
             signature,
             received_at: Local::now(),
             programs,
+            is_dex,
             is_bundle,
             tip_amount,
-        });
+        };
+        self.txn_sampler.write().offer(&mut self.txn_samples.write(), sample, weight)
     }
 
     pub fn uptime(&self) -> Duration {
         self.start_time.elapsed()
     }
 
+    /// Connection state transitions, newest first, each with how long that state lasted (or has
+    /// lasted so far, for the current one)
+    pub fn connection_timeline(&self) -> Vec<(DateTime<Local>, ConnectionState, Duration)> {
+        self.connection_timeline_at(Local::now())
+    }
+
+    fn connection_timeline_at(&self, now: DateTime<Local>) -> Vec<(DateTime<Local>, ConnectionState, Duration)> {
+        let events = self.connection_events.read();
+        let mut until = now;
+        events.iter().rev().map(|(at, state)| {
+            let lasted = (until - *at).to_std().unwrap_or_default();
+            until = *at;
+            (*at, state.clone(), lasted)
+        }).collect()
+    }
+
     pub fn connection_duration(&self) -> Option<Duration> {
         self.connected_at.read().map(|t| t.elapsed())
     }
 
+    /// Time until the client's next connection attempt, while it's waiting to retry
+    pub fn retry_countdown(&self) -> Option<Duration> {
+        self.next_retry_at.read().map(|at| at.saturating_duration_since(Instant::now()))
+    }
+
+    /// Ask a client that gave up to try again; returns false if it's still retrying on its own
+    pub fn request_reconnect(&self) -> bool {
+        if !matches!(*self.connection_state.read(), ConnectionState::Error(_)) {
+            return false;
+        }
+        self.reconnect_requested.notify_one();
+        true
+    }
+
     pub fn metrics_window_secs(&self) -> f64 {
         self.metrics_window_start.read().elapsed().as_secs_f64()
     }
 
+    /// Time left before the metrics window resets, zero once it's due
+    pub fn metrics_window_remaining(&self) -> Duration {
+        window_remaining(*self.metrics_window_start.read(), self.metrics_window, Instant::now())
+    }
+
+    /// Fold the current window rate into the smoothed rate, seeding it from the first sample
+    pub fn update_ema_txns_per_sec(&self) {
+        let window_secs = self.metrics_window_secs();
+        // Rates over a sub-second window are too noisy to use, e.g. right after a reset
+        if window_secs < 1.0 {
+            return;
+        }
+        let sample = self.metrics.get_txns_per_sec(window_secs);
+        let mut ema = self.ema_txns_per_sec.write();
+        *ema = Some(ema_update(*ema, sample, self.ema_alpha));
+    }
+
+    /// Add a point to the MEV index once at least a second has passed since the last one
+    pub fn update_mev_index(&self) {
+        let now = MevTotals::capture(self);
+        let mut last = self.mev_index.last.write();
+        let Some(prev) = *last else {
+            *last = Some(now);
+            return;
+        };
+        if now.at.duration_since(prev.at) < Duration::from_secs(1) {
+            return;
+        }
+        *last = Some(now);
+        drop(last);
+
+        let score = MevIndex::score(&prev, &now);
+        let mut history = self.mev_index.history.write();
+        if history.len() >= MAX_MEV_INDEX_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(score);
+    }
+
+    /// How many slots the UI loop is behind the stream
+    pub fn ui_lag_slots(&self) -> u64 {
+        self.last_received_slot
+            .load(Ordering::Relaxed)
+            .saturating_sub(self.last_rendered_slot.load(Ordering::Relaxed))
+    }
+
     pub fn reset_metrics_window(&self) {
         *self.metrics_window_start.write() = Instant::now();
         self.metrics.reset_window();
+        if self.latency_stats.sla_resets_with_window {
+            self.latency_stats.reset_sla();
+        }
+    }
+
+    pub fn set_notice(&self, message: impl Into<String>) {
+        *self.notice.write() = Some((message.into(), Instant::now()));
+    }
+
+    /// The footer notice, until it's `NOTICE_DURATION` old
+    pub fn current_notice(&self) -> Option<String> {
+        self.notice.read().as_ref()
+            .filter(|(_, set_at)| set_at.elapsed() < NOTICE_DURATION)
+            .map(|(message, _)| message.clone())
+    }
+
+    /// Clear every accumulated statistic, as if freshly started. The connection, uptime, current
+    /// slot, logs, settings and the monitored wallet are kept.
+    pub fn reset_all(&self) {
+        self.reset_metrics_window();
+        self.metrics.reset();
+        *self.connection_baseline.write() = CounterTotals::default();
+        *self.ema_txns_per_sec.write() = None;
+        self.txn_rate.reset();
+        self.byte_rate.reset();
+        self.slot_history.write().clear();
+        self.txn_rate_history.write().clear();
+        self.minute_buckets.write().clear();
+        self.txn_samples.write().clear();
+        self.txn_sampler.write().reset();
+        self.latency_stats.reset();
+        self.program_stats.reset();
+        self.account_stats.reset();
+        self.coverage.reset();
+        self.leader_tracker.reset();
+        self.turbine_stats.reset();
+        self.competition_stats.reset();
+        self.mev_index.reset();
+        self.wallet_monitor.reset();
+        self.network_health.reset();
+        *self.slot_anchor.write() = None;
+        *self.slot_drift_ms.write() = None;
+        self.active_alerts.write().clear();
+        self.alert_flash_ticks.store(0, Ordering::Relaxed);
+        *self.selected_row.write() = 0;
+        if self.is_paused() {
+            *self.paused.write() = Some(FrozenLists::capture(self));
+        }
+    }
+
+    fn update_slot_drift(&self, slot: Slot, now: Instant) {
+        let mut anchor = self.slot_anchor.write();
+        let Some(start) = *anchor else {
+            *anchor = Some((slot, now));
+            return;
+        };
+        match slot_drift_ms(start, slot, now) {
+            Some(drift) if drift.abs() > MAX_DRIFT_MS_PER_SLOT => {
+                *anchor = Some((slot, now));
+                *self.slot_drift_ms.write() = None;
+            }
+            Some(drift) => *self.slot_drift_ms.write() = Some(drift),
+            None => {}
+        }
+    }
+
+    /// Observed slot rate from recent slot history, or the nominal rate if too few slots
+    pub fn slots_per_sec(&self) -> f64 {
+        let history = self.slot_history.read();
+        if let (Some(first), Some(last)) = (history.front(), history.back()) {
+            let elapsed = last.received_at.duration_since(first.received_at).as_secs_f64();
+            if last.slot > first.slot && elapsed >= 1.0 {
+                return (last.slot - first.slot) as f64 / elapsed;
+            }
+        }
+        NOMINAL_SLOTS_PER_SEC
+    }
+
+    /// Convert a per-second rate into the selected display unit
+    pub fn rate_in_unit(&self, per_sec: f64) -> f64 {
+        match *self.rate_unit.read() {
+            RateUnit::PerSecond => per_sec,
+            RateUnit::PerSlot => per_sec / self.slots_per_sec(),
+        }
+    }
+
+    pub fn toggle_rate_unit(&self) {
+        let mut unit = self.rate_unit.write();
+        *unit = match *unit {
+            RateUnit::PerSecond => RateUnit::PerSlot,
+            RateUnit::PerSlot => RateUnit::PerSecond,
+        };
+    }
+
+    pub fn toggle_counter_scope(&self) -> CounterScope {
+        let mut scope = self.counter_scope.write();
+        *scope = match *scope {
+            CounterScope::AllTime => CounterScope::Connection,
+            CounterScope::Connection => CounterScope::AllTime,
+        };
+        *scope
+    }
+
+    /// Cumulative counters in the selected scope
+    pub fn overview_totals(&self) -> CounterTotals {
+        let totals = self.metrics.totals();
+        match *self.counter_scope.read() {
+            CounterScope::AllTime => totals,
+            CounterScope::Connection => totals.since(&self.connection_baseline.read()),
+        }
+    }
+
+    pub fn tick_rate(&self) -> Duration {
+        Duration::from_millis(self.tick_rate_ms.load(Ordering::Relaxed))
+    }
+
+    /// Halve (`faster`) or double the tick rate within its bounds, returning the new rate in ms
+    pub fn adjust_tick_rate(&self, faster: bool) -> u64 {
+        let current = self.tick_rate_ms.load(Ordering::Relaxed);
+        let next = clamp_tick_rate(if faster { current / 2 } else { current.saturating_mul(2) });
+        self.tick_rate_ms.store(next, Ordering::Relaxed);
+        next
+    }
+
+    /// The tip threshold while the filter is on
+    pub fn active_tip_filter(&self) -> Option<u64> {
+        self.min_tip_lamports.filter(|_| *self.tip_filter_enabled.read())
+    }
+
+    /// Whether Recent Bundles lists `bundle` under the current tip filter
+    pub fn shows_bundle(&self, bundle: &BundleInfo) -> bool {
+        self.active_tip_filter().is_none_or(|min| bundle.tip_amount >= min)
+    }
+
+    /// Whether Recent Slots lists `slot` in the current mode
+    pub fn shows_slot(&self, slot: &SlotInfo) -> bool {
+        !*self.dex_only.read() || slot.has_dex_activity()
+    }
+
+    /// Whether Recent Transactions lists `sample` in the current mode and search
+    pub fn shows_txn_sample(&self, sample: &TxnSample) -> bool {
+        let dex_ok = !*self.dex_only.read() || sample.is_dex || sample.is_bundle;
+        dex_ok && self.signature_search.read().as_deref().is_none_or(|prefix| signature_matches(&sample.signature, prefix))
+    }
+
+    pub fn cycle_leader_sort(&self) {
+        let mut sort = self.leader_sort.write();
+        *sort = sort.next();
+    }
+
+    /// Step the leader table through all regions, then each region with samples, in name order
+    pub fn cycle_latency_region(&self) {
+        let regions = self.latency_stats.leader_regions();
+        let mut selected = self.latency_region.write();
+        let next = match selected.as_ref() {
+            None => 0,
+            Some(current) => regions.iter().position(|r| r == current).map_or(0, |i| i + 1),
+        };
+        *selected = regions.get(next).cloned();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.read().is_some()
+    }
+
+    pub fn toggle_pause(&self) {
+        let frozen = match self.is_paused() {
+            true => None,
+            false => Some(FrozenLists::capture(self)),
+        };
+        *self.paused.write() = frozen;
     }
 
     pub fn next_tab(&self) {
         let mut tab = self.selected_tab.write();
-        *tab = (*tab + 1) % 8;
+        *tab = (*tab + 1) % TAB_COUNT;
+        *self.selected_row.write() = 0;
     }
 
     pub fn prev_tab(&self) {
         let mut tab = self.selected_tab.write();
-        *tab = if *tab == 0 { 7 } else { *tab - 1 };
+        *tab = if *tab == 0 { TAB_COUNT - 1 } else { *tab - 1 };
+        *self.selected_row.write() = 0;
+    }
+
+    /// Select a tab by index, clamped to the last tab
+    pub fn set_tab(&self, index: usize) {
+        *self.selected_tab.write() = index.min(TAB_COUNT - 1);
+        *self.selected_row.write() = 0;
     }
 
     pub fn toggle_help(&self) {
@@ -896,13 +3067,1281 @@ impl AppState {
         *show = !*show;
     }
 
+    pub fn toggle_info(&self) {
+        let mut show = self.show_info.write();
+        *show = !*show;
+    }
+
+    pub fn close_overlays(&self) {
+        *self.show_help.write() = false;
+        *self.show_info.write() = false;
+        *self.text_input.write() = None;
+        *self.bundle_detail.write() = None;
+        *self.program_detail.write() = None;
+    }
+
+    pub fn is_editing_text(&self) -> bool {
+        self.text_input.read().is_some()
+    }
+
+    pub fn is_quit_pending(&self) -> bool {
+        *self.quit_pending.read()
+    }
+
+    /// Handle a quit request: true to exit now, false if the confirmation prompt was opened instead
+    pub fn request_quit(&self) -> bool {
+        if !self.confirm_quit {
+            return true;
+        }
+        *self.quit_pending.write() = true;
+        false
+    }
+
+    /// Answer the open quit prompt: true to exit on `y`, any other key dismisses it
+    pub fn answer_quit(&self, code: KeyCode) -> bool {
+        *self.quit_pending.write() = false;
+        matches!(code, KeyCode::Char('y' | 'Y'))
+    }
+
+    pub fn open_wallet_input(&self) {
+        *self.text_input.write() = Some(TextInput { target: InputTarget::Wallet, text: String::new(), error: None });
+    }
+
+    /// Open the label box for `program`, starting from its current label if it has one
+    pub fn open_label_input(&self, program: Pubkey) {
+        let text = self.program_stats.labels.read().get(&program).cloned().unwrap_or_default();
+        *self.text_input.write() = Some(TextInput { target: InputTarget::ProgramLabel(program), text, error: None });
+    }
+
+    /// Open the signature search box, starting from the current search
+    pub fn open_signature_search(&self) {
+        let text = self.signature_search.read().clone().unwrap_or_default();
+        *self.text_input.write() = Some(TextInput { target: InputTarget::SignatureSearch, text, error: None });
+    }
+
+    /// Apply a key press to the open text input box
+    pub fn text_input_key(&self, code: KeyCode) {
+        match code {
+            KeyCode::Esc => self.close_overlays(),
+            KeyCode::Enter => self.submit_text_input(),
+            KeyCode::Backspace => {
+                if let Some(input) = self.text_input.write().as_mut() {
+                    input.text.pop();
+                    input.error = None;
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(input) = self.text_input.write().as_mut() {
+                    // Pubkeys and signatures never contain spaces; labels may
+                    if !matches!(input.target, InputTarget::ProgramLabel(_)) && c.is_whitespace() {
+                        return;
+                    }
+                    input.text.push(c);
+                    input.error = None;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Apply the typed value, or keep the box open with the reason it was rejected
+    pub fn submit_text_input(&self) {
+        let mut input = self.text_input.write();
+        let Some(current) = input.as_mut() else {
+            return;
+        };
+        match current.target {
+            InputTarget::Wallet => match current.text.trim().parse::<Pubkey>() {
+                Ok(wallet) => {
+                    *input = None;
+                    drop(input);
+                    if self.wallet_monitor.add_wallet(wallet) {
+                        self.log_info(format!("Monitoring wallet {}", wallet));
+                    } else {
+                        self.wallet_monitor.remove_wallet(&wallet);
+                        self.log_info(format!("Stopped monitoring wallet {}", wallet));
+                    }
+                }
+                Err(e) => current.error = Some(format!("Invalid pubkey: {}", e)),
+            },
+            InputTarget::ProgramLabel(program) => {
+                let label = current.text.trim().to_string();
+                if label.is_empty() {
+                    current.error = Some("Label can't be empty".to_string());
+                    return;
+                }
+                *input = None;
+                drop(input);
+                self.program_stats.label_program(program, label.clone());
+                self.log_info(format!("Labeled {} as {}", program, label));
+                if let Some(path) = &self.program_cache {
+                    if let Err(e) = save_program_cache(path, &self.program_stats.labels.read()) {
+                        self.log_error(format!("Failed to save program cache: {:#}", e));
+                    }
+                }
+            }
+            InputTarget::SignatureSearch => {
+                let prefix = current.text.trim().to_string();
+                *input = None;
+                drop(input);
+                *self.signature_search.write() = (!prefix.is_empty()).then_some(prefix);
+                *self.selected_row.write() = 0;
+            }
+        }
+    }
+
     pub fn scroll_up(&self) {
-        let mut offset = self.scroll_offset.write();
-        *offset = offset.saturating_sub(1);
+        let mut row = self.selected_row.write();
+        *row = row.saturating_sub(1);
+    }
+
+    /// Move the selection down, stopping at the last of `row_count` rows
+    pub fn scroll_down(&self, row_count: usize) {
+        let mut row = self.selected_row.write();
+        *row = row.saturating_add(1).min(row_count.saturating_sub(1));
+    }
+
+    pub fn set_page_rows(&self, rows: usize) {
+        self.page_rows.store(rows.max(1), Ordering::Relaxed);
+    }
+
+    /// Move the selection up by a page, stopping at the first row
+    pub fn page_up(&self) {
+        let page = self.page_rows.load(Ordering::Relaxed);
+        let mut row = self.selected_row.write();
+        *row = row.saturating_sub(page);
+    }
+
+    /// Move the selection down by a page, stopping at the last of `row_count` rows
+    pub fn page_down(&self, row_count: usize) {
+        let page = self.page_rows.load(Ordering::Relaxed);
+        let mut row = self.selected_row.write();
+        *row = row.saturating_add(page).min(row_count.saturating_sub(1));
+    }
+
+    pub fn scroll_top(&self) {
+        *self.selected_row.write() = 0;
+    }
+
+    pub fn scroll_bottom(&self, row_count: usize) {
+        *self.selected_row.write() = row_count.saturating_sub(1);
+    }
+
+    /// The selected row clamped to a list of `row_count` rows, which may have shrunk since it was selected
+    pub fn selected_row_in(&self, row_count: usize) -> Option<usize> {
+        match row_count {
+            0 => None,
+            n => Some((*self.selected_row.read()).min(n - 1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latency_sample(slot: Slot, latency_us: i64, leader: Option<Pubkey>, region: Option<&str>) -> LatencySample {
+        LatencySample {
+            slot,
+            timestamp: Local::now(),
+            arrival_delay_us: latency_us,
+            leader,
+            region: region.map(str::to_string),
+            turbine_index: None,
+        }
+    }
+
+    #[test]
+    fn region_latencies_average_per_region_fastest_first() {
+        let latency = LatencyStats::new();
+        latency.add_sample(latency_sample(1, 8_000, None, Some("ny")));
+        latency.add_sample(latency_sample(2, 12_000, None, Some("ny")));
+        latency.add_sample(latency_sample(3, 3_000, None, Some("amsterdam")));
+        latency.add_sample(latency_sample(4, 5_000, None, Some("amsterdam")));
+        latency.add_sample(latency_sample(5, 1_000, None, None));
+
+        let regions = latency.regions_by_latency();
+        let names: Vec<&str> = regions.iter().map(|r| r.region.as_str()).collect();
+        assert_eq!(names, ["amsterdam", "ny"]);
+        assert_eq!(regions[0].avg_latency_ms(), 4.0);
+        assert_eq!(regions[0].sample_count, 2);
+        assert_eq!(regions[1].avg_latency_ms(), 10.0);
+        assert_eq!((regions[1].min_latency_us, regions[1].max_latency_us), (8_000, 12_000));
+        // Untagged samples still count overall
+        assert_eq!(latency.sample_count.load(Ordering::Relaxed), 5);
+    }
+
+    #[test]
+    fn slot_latency_history_is_bounded_and_keeps_newest_slots() {
+        let latency = LatencyStats::new();
+        let total = MAX_SLOT_LATENCY_HISTORY as u64 + 10;
+        for slot in 1..=total {
+            latency.add_sample(latency_sample(slot, slot as i64 * 10, None, None));
+        }
+        // A second sample for the newest slot is averaged in rather than appended
+        latency.add_sample(latency_sample(total, 0, None, None));
+
+        let history = latency.slot_history.read();
+        assert_eq!(history.len(), MAX_SLOT_LATENCY_HISTORY);
+        assert_eq!(history.front().unwrap().slot, 11);
+        let newest = history.back().unwrap();
+        assert_eq!((newest.slot, newest.total_us, newest.count), (total, total as i64 * 10, 2));
+    }
+
+    #[test]
+    fn region_leader_latencies_nest_by_region_then_leader() {
+        let latency = LatencyStats::new();
+        let (fast, slow) = (Pubkey::new_unique(), Pubkey::new_unique());
+        latency.add_sample(latency_sample(1, 2_000, Some(fast), Some("ny")));
+        latency.add_sample(latency_sample(2, 4_000, Some(fast), Some("ny")));
+        latency.add_sample(latency_sample(3, 9_000, Some(slow), Some("ny")));
+        latency.add_sample(latency_sample(4, 7_000, Some(fast), Some("tokyo")));
+        // Without a leader there's nothing to nest under the region
+        latency.add_sample(latency_sample(5, 1_000, None, Some("frankfurt")));
+
+        assert_eq!(latency.leader_regions(), ["ny", "tokyo"]);
+        let regions = latency.region_leader_latencies.read();
+        let ny = &regions["ny"];
+        assert_eq!(ny.len(), 2);
+        assert_eq!(ny[&fast].sample_count, 2);
+        assert_eq!(ny[&fast].avg_latency_ms(), 3.0);
+        assert_eq!(ny[&slow].avg_latency_ms(), 9.0);
+        let tokyo = &regions["tokyo"];
+        assert_eq!(tokyo.len(), 1);
+        assert_eq!(tokyo[&fast].avg_latency_ms(), 7.0);
+        // The all-regions view merges the same leader across regions
+        assert_eq!(latency.leader_latencies.read()[&fast].sample_count, 3);
+    }
+
+    #[test]
+    fn region_leader_latencies_evict_quietest_leader_at_capacity() {
+        let latency = LatencyStats::new();
+        let busy = Pubkey::new_unique();
+        latency.add_sample(latency_sample(0, 1_000, Some(busy), Some("ny")));
+        latency.add_sample(latency_sample(0, 1_000, Some(busy), Some("ny")));
+        for slot in 1..=MAX_LEADERS_PER_REGION as u64 {
+            latency.add_sample(latency_sample(slot, 1_000, Some(Pubkey::new_unique()), Some("ny")));
+        }
+
+        let regions = latency.region_leader_latencies.read();
+        assert_eq!(regions["ny"].len(), MAX_LEADERS_PER_REGION);
+        assert!(regions["ny"].contains_key(&busy));
+    }
+
+    fn bundle(signatures: &[&str], timestamp: DateTime<Local>) -> BundleInfo {
+        BundleInfo {
+            slot: 1,
+            txn_count: signatures.len() as u32,
+            tip_amount: 1_000,
+            tip_account: "tip".to_string(),
+            signatures: signatures.iter().map(|sig| sig.to_string()).collect(),
+            timestamp,
+            status: BundleStatus::Pending,
+        }
+    }
+
+    #[test]
+    fn settle_bundles_counts_land_rate_from_confirmed_bundles() {
+        let mut competition = CompetitionStats::new();
+        competition.track_landing = true;
+        let now = Local::now();
+        let old = now - chrono::Duration::minutes(5);
+        competition.add_bundle(bundle(&["a1", "a2"], old));
+        competition.add_bundle(bundle(&["b1", "b2"], old));
+        competition.add_bundle(bundle(&["c1"], old));
+        competition.add_bundle(bundle(&["d1"], old));
+        competition.add_bundle(bundle(&["e1"], now));
+        assert_eq!(competition.land_rate_pct(), None);
+
+        let statuses = HashMap::from([
+            ("a1".to_string(), TxnStatus::Success),
+            ("a2".to_string(), TxnStatus::Success),
+            ("b1".to_string(), TxnStatus::Failed),
+        ]);
+        // d1 went unchecked this poll, so it can't be told apart from a bundle still landing
+        let looked_up: HashSet<String> = ["a1", "a2", "b1", "b2", "c1", "e1"].iter().map(|sig| sig.to_string()).collect();
+        competition.settle_bundles(&statuses, &looked_up, now - chrono::Duration::minutes(2));
+
+        assert_eq!(competition.bundles_landed.load(Ordering::Relaxed), 1);
+        assert_eq!(competition.bundles_failed.load(Ordering::Relaxed), 1);
+        assert_eq!(competition.bundles_dropped.load(Ordering::Relaxed), 1);
+        let land_rate = competition.land_rate_pct().unwrap();
+        assert!((land_rate - 100.0 / 3.0).abs() < 1e-9, "{}", land_rate);
+        assert_eq!(competition.pending_bundle_signatures(), ["d1", "e1"]);
+
+        let statuses: Vec<BundleStatus> = competition.bundles.read().iter().map(|b| b.status).collect();
+        assert_eq!(statuses, [BundleStatus::Landed, BundleStatus::Failed, BundleStatus::Dropped, BundleStatus::Pending, BundleStatus::Pending]);
+    }
+
+    fn leader_slot(slot: Slot, leader: Option<Pubkey>, txn_count: u64) -> LeaderSlotInfo {
+        LeaderSlotInfo {
+            slot,
+            leader,
+            entry_count: 1,
+            txn_count,
+            skip: false,
+            first_shred_delay_ms: None,
+            timestamp: Local::now(),
+        }
+    }
+
+    #[test]
+    fn top_leaders_are_ordered_by_slots_seen() {
+        let tracker = LeaderTracker::new();
+        let (busy, quiet) = (Pubkey::new_unique(), Pubkey::new_unique());
+        tracker.record_slot(leader_slot(100, Some(quiet), 50));
+        for slot in 101..104 {
+            tracker.record_slot(leader_slot(slot, Some(busy), 10));
+        }
+        // A second batch for a slot adds its transactions without counting the slot again
+        tracker.record_slot(leader_slot(103, Some(busy), 5));
+
+        let top = tracker.get_top_leaders(10);
+        assert_eq!(top.iter().map(|l| l.leader).collect::<Vec<_>>(), [busy, quiet]);
+        assert_eq!((top[0].slots_seen, top[0].total_txns, top[0].last_slot), (3, 35, Some(103)));
+        assert_eq!((top[1].slots_seen, top[1].total_txns), (1, 50));
+        assert_eq!(tracker.get_top_leaders(1).len(), 1);
+    }
+
+    #[test]
+    fn slots_without_a_known_leader_are_credited_once_the_schedule_arrives() {
+        let tracker = LeaderTracker::new();
+        let leader = Pubkey::new_unique();
+        tracker.record_slot(leader_slot(200, None, 7));
+        tracker.record_slot(leader_slot(201, None, 3));
+        assert_eq!(tracker.slot_history.read().len(), 2);
+        assert!(tracker.get_top_leaders(10).is_empty());
+
+        tracker.set_schedule(vec![(200, leader), (201, leader)]);
+        let top = tracker.get_top_leaders(10);
+        assert_eq!(top.len(), 1);
+        assert_eq!((top[0].leader, top[0].slots_seen, top[0].total_txns), (leader, 2, 10));
+        assert!(tracker.slot_history.read().iter().all(|s| s.leader == Some(leader)));
+    }
+
+    #[test]
+    fn window_remaining_counts_down_to_zero() {
+        let start = Instant::now();
+        let window = Duration::from_secs(60);
+        assert_eq!(window_remaining(start, window, start), window);
+        assert_eq!(window_remaining(start, window, start + Duration::from_secs(45)), Duration::from_secs(15));
+        assert!(window_remaining(start, window, start + window).is_zero());
+        assert!(window_remaining(start, window, start + Duration::from_secs(90)).is_zero());
+    }
+
+    #[test]
+    fn late_batches_mark_their_slot_incomplete() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.add_slot(100, 4, 10, 0, 0);
+        state.add_slot(100, 2, 5, 0, 0);
+        state.add_slot(101, 3, 8, 0, 0);
+        // A batch for slot 100 after slot 101 started
+        state.add_slot(100, 1, 2, 0, 0);
+
+        let history = state.slot_history.read();
+        let completeness: Vec<(Slot, bool)> = history.iter().map(|info| (info.slot, info.complete)).collect();
+        assert_eq!(completeness, vec![(100, false), (100, false), (101, true), (100, false)]);
+        assert_eq!(state.network_health.out_of_order_count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn dex_only_mode_hides_slots_and_txns_without_dex_or_bundle_activity() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.add_slot(100, 4, 10, 0, 0);
+        state.add_slot(101, 4, 10, 3, 0);
+        state.add_slot(102, 4, 10, 0, 1);
+        let sample = |is_dex, is_bundle| TxnSample {
+            slot: 100,
+            signature: "sig".to_string(),
+            received_at: Local::now(),
+            programs: Vec::new(),
+            is_dex,
+            is_bundle,
+            tip_amount: None,
+        };
+        let samples = [sample(false, false), sample(true, false), sample(false, true)];
+
+        let shown = |state: &AppState| {
+            let slots: Vec<Slot> = state.slot_history.read().iter().filter(|s| state.shows_slot(s)).map(|s| s.slot).collect();
+            let txns = samples.iter().filter(|s| state.shows_txn_sample(s)).count();
+            (slots, txns)
+        };
+        assert_eq!(shown(&state), (vec![100, 101, 102], 3));
+        *state.dex_only.write() = true;
+        assert_eq!(shown(&state), (vec![101, 102], 2));
+    }
+
+    #[test]
+    fn wallet_counters_are_kept_per_watched_wallet() {
+        let monitor = WalletMonitor::new();
+        let (busy, quiet) = (Pubkey::new_unique(), Pubkey::new_unique());
+        monitor.add_wallet(busy);
+        monitor.add_wallet(quiet);
+        let txn = |wallet, signature: &str, status| WalletTxn {
+            wallet,
+            slot: 100,
+            signature: signature.to_string(),
+            timestamp: Local::now(),
+            status,
+            programs: Vec::new(),
+        };
+        monitor.add_txn(txn(busy, "a", TxnStatus::Unknown));
+        monitor.add_txn(txn(busy, "b", TxnStatus::Success));
+        monitor.add_txn(txn(quiet, "b", TxnStatus::Success));
+
+        let stats = monitor.stats.read();
+        assert_eq!((stats[&busy].txn_count, stats[&busy].unknown_count, stats[&busy].success_count), (2, 1, 1));
+        assert_eq!((stats[&quiet].txn_count, stats[&quiet].unknown_count, stats[&quiet].success_count), (1, 0, 1));
+        drop(stats);
+        assert_eq!(monitor.totals().txn_count, 3);
+        let order: Vec<Pubkey> = monitor.breakdown().into_iter().map(|(wallet, _)| wallet).collect();
+        assert_eq!(order, vec![busy, quiet]);
+    }
+
+    #[test]
+    fn scheduled_slots_without_entries_are_skipped_until_they_arrive() {
+        let tracker = LeaderTracker::new();
+        let leaders = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        tracker.set_schedule((100..112).map(|slot| (slot, leaders[(slot as usize - 100) / 4])).collect());
+
+        let mut skipped = Vec::new();
+        for slot in (100..=115).filter(|slot| ![102, 106, 107].contains(slot)) {
+            skipped.extend(tracker.observe_slot(slot));
+            tracker.record_slot(leader_slot(slot, tracker.leader_for_slot(slot), 1));
+        }
+        assert_eq!(skipped, vec![102, 106, 107]);
+        let skips = |leader: &Pubkey| {
+            let stats = &tracker.leader_stats.read()[leader];
+            (stats.slots_seen, stats.slots_skipped)
+        };
+        assert_eq!(skips(&leaders[0]), (4, 1));
+        assert_eq!(skips(&leaders[1]), (4, 2));
+        assert_eq!(skips(&leaders[2]), (4, 0));
+
+        // Slot 102's entries turn up after it was given up on
+        assert!(tracker.observe_slot(102).is_empty());
+        tracker.record_slot(leader_slot(102, Some(leaders[0]), 1));
+        assert_eq!(skips(&leaders[0]), (4, 0));
+        assert_eq!(tracker.leader_stats.read()[&leaders[0]].last_slot, Some(103));
+    }
+
+    #[test]
+    fn histogram_counts_samples_into_their_buckets() {
+        let stats = LatencyStats::new();
+        for (slot, latency_us) in [-3_000, -1, 0, 500, 1_000, 1_999, 4_000, 9_999, 15_000, 20_000, 50_000].into_iter().enumerate() {
+            stats.add_sample(latency_sample(slot as Slot, latency_us, None, None));
+        }
+        let histogram = stats.histogram();
+        // Early arrivals have their own bucket rather than being counted as no delay
+        let expected = [("<0", 2), ("0–1", 2), ("1–2", 2), ("2–5", 1), ("5–10", 1), ("10–20", 1), ("20+", 2)];
+        assert_eq!(histogram, expected.map(|(label, count)| (label.to_string(), count)).to_vec());
+    }
+
+    #[test]
+    fn stddev_is_the_population_deviation_of_recent_samples() {
+        let stats = LatencyStats::new();
+        stats.add_sample(latency_sample(1, 2_000, None, None));
+        assert_eq!(stats.stddev_ms(), 0.0);
+
+        for (slot, ms) in [4, 4, 4, 5, 5, 7, 9].into_iter().enumerate() {
+            stats.add_sample(latency_sample(slot as Slot + 2, ms * 1_000, None, None));
+        }
+        assert!((stats.stddev_ms() - 2.0).abs() < 1e-9, "{}", stats.stddev_ms());
+    }
+
+    #[test]
+    fn sla_percentage_counts_samples_at_or_under_the_target() {
+        let mut stats = LatencyStats::new();
+        stats.add_sample(latency_sample(1, 1_000, None, None));
+        assert_eq!(stats.sla_met_pct(), None);
+
+        stats.sla_target_us = Some(5_000);
+        for (slot, latency_us) in [1_000, 5_000, 5_001, 20_000].into_iter().enumerate() {
+            stats.add_sample(latency_sample(slot as Slot + 2, latency_us, None, None));
+        }
+        assert_eq!(stats.sla_met_pct(), Some(50.0));
+    }
+
+    #[test]
+    fn early_slot_arrivals_keep_a_negative_delay() {
+        let mut state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.latency_stats.sla_target_us = Some(5_000);
+        // Ten slots on from the anchor, arriving at once, lands ~4s ahead of schedule
+        *state.slot_anchor.write() = Some((100, Instant::now()));
+        *state.slot_drift_ms.write() = Some(0.0);
+        let delay_ms = state.add_slot(110, 1, 1, 0, 0).unwrap();
+        assert!(delay_ms < -3_900.0, "{}", delay_ms);
+
+        let stats = &state.latency_stats;
+        assert!(stats.avg_latency_ms() < -3_900.0, "{}", stats.avg_latency_ms());
+        assert_eq!(stats.sla_met_pct(), Some(100.0));
+        assert_eq!(stats.histogram()[0], ("<0".to_string(), 1));
+    }
+
+    #[test]
+    fn sla_counts_reset_with_the_window_only_when_asked() {
+        let mut state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.latency_stats.sla_target_us = Some(5_000);
+        state.latency_stats.add_sample(latency_sample(1, 1_000, None, None));
+        state.reset_metrics_window();
+        assert_eq!(state.latency_stats.sla_met_pct(), Some(100.0));
+
+        state.latency_stats.sla_resets_with_window = true;
+        state.reset_metrics_window();
+        assert_eq!(state.latency_stats.sla_met_pct(), None);
+        state.latency_stats.add_sample(latency_sample(2, 9_000, None, None));
+        assert_eq!(state.latency_stats.sla_met_pct(), Some(0.0));
+    }
+
+    #[test]
+    fn turbine_stats_track_index_range_and_layers() {
+        let stats = TurbineStats::new();
+        assert_eq!((stats.min_index(), stats.max_index(), stats.avg_index()), (0, 0, 0.0));
+
+        for (turbine_index, layer) in [(0, 0), (2, 0), (5, 1), (9, 2), (14, 3), (30, 7)] {
+            stats.add_sample(TurbineInfo {
+                slot: 100,
+                shred_index: 0,
+                turbine_index,
+                layer,
+                timestamp: Local::now(),
+                source_ip: None,
+            });
+        }
+        assert_eq!(stats.avg_index(), 10.0);
+        assert_eq!((stats.min_index(), stats.max_index()), (0, 30));
+        let layers = [&stats.layer_0_count, &stats.layer_1_count, &stats.layer_2_count, &stats.layer_3_plus_count]
+            .map(|count| count.load(Ordering::Relaxed));
+        assert_eq!(layers, [2, 1, 1, 2]);
+    }
+
+    #[test]
+    fn txn_rate_history_is_bounded_and_keeps_newest_counts() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        let total = MAX_TXN_RATE_HISTORY as u64 + 25;
+        for slot in 1..=total {
+            state.add_slot(slot, 1, slot * 10, 0, 0);
+        }
+        let history = state.txn_rate_history.read();
+        assert_eq!(history.len(), MAX_TXN_RATE_HISTORY);
+        assert_eq!(history.front(), Some(&(26 * 10)));
+        assert_eq!(history.back(), Some(&(total * 10)));
+    }
+
+    #[test]
+    fn paused_lists_stay_as_captured_while_state_moves_on() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.add_slot(100, 2, 20, 0, 0);
+        state.log_info("before pause");
+        state.toggle_pause();
+        assert!(state.is_paused());
+
+        state.add_slot(101, 3, 30, 0, 0);
+        state.log_info("after pause");
+        {
+            let paused = state.paused.read();
+            let frozen = paused.as_ref().unwrap();
+            assert_eq!(frozen.slot_history.iter().map(|s| s.slot).collect::<Vec<_>>(), vec![100]);
+            assert_eq!(frozen.txn_rate_history, VecDeque::from([20]));
+            assert_eq!(frozen.logs.len(), state.logs.read().len() - 1);
+        }
+        assert_eq!(state.slot_history.read().len(), 2);
+        assert_eq!(state.metrics.total_txns.load(Ordering::Relaxed), 50);
+
+        state.toggle_pause();
+        assert!(!state.is_paused());
+    }
+
+    #[test]
+    fn ema_seeds_from_the_first_sample_then_smooths() {
+        let mut ema = None;
+        let mut seen = Vec::new();
+        for sample in [100.0, 200.0, 200.0, 0.0] {
+            let next = ema_update(ema, sample, 0.5);
+            seen.push(next);
+            ema = Some(next);
+        }
+        assert_eq!(seen, vec![100.0, 150.0, 175.0, 87.5]);
+        assert_eq!(ema_update(Some(40.0), 140.0, 0.2), 60.0);
+    }
+
+    #[test]
+    fn selection_is_clamped_to_the_list_and_reset_by_tab_switches() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        assert_eq!(state.selected_row_in(0), None);
+        for _ in 0..10 {
+            state.scroll_down(5);
+        }
+        assert_eq!(state.selected_row_in(5), Some(4));
+        // The list shrank underneath the selection
+        assert_eq!(state.selected_row_in(3), Some(2));
+        assert_eq!(state.selected_row_in(0), None);
+
+        state.next_tab();
+        assert_eq!(state.selected_row_in(5), Some(0));
+        state.scroll_bottom(7);
+        state.prev_tab();
+        assert_eq!(state.selected_row_in(7), Some(0));
+        state.scroll_bottom(7);
+        state.set_tab(3);
+        assert_eq!(state.selected_row_in(7), Some(0));
+    }
+
+    #[test]
+    fn gaps_in_the_slot_stream_are_recorded_as_missed() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        for slot in [100, 101, 105] {
+            state.add_slot(slot, 1, 1, 0, 0);
+        }
+        let health = &state.network_health;
+        assert_eq!(health.missed_slots.read().iter().copied().collect::<Vec<_>>(), vec![102, 103, 104]);
+        assert_eq!(health.missed_slot_count.load(Ordering::Relaxed), 3);
+        assert_eq!(health.slot_span.load(Ordering::Relaxed), 5);
+
+        // An older slot arriving late opens no negative gap
+        health.record_gap(105, 101);
+        assert_eq!(health.missed_slot_count.load(Ordering::Relaxed), 3);
+        assert_eq!(health.slot_span.load(Ordering::Relaxed), 5);
+    }
+
+    #[test]
+    fn tip_accounts_are_totalled_and_ranked_by_tips() {
+        let competition = CompetitionStats::new();
+        for (account, tip) in [("small", 5_000), ("big", 2_000), ("big", 9_000), ("small", 1_000), ("big", 500)] {
+            competition.add_bundle(BundleInfo {
+                tip_amount: tip,
+                tip_account: account.to_string(),
+                ..bundle(&["sig"], Local::now())
+            });
+        }
+        let totals: Vec<(String, u64, u64, u64)> = competition.get_top_tip_accounts(10).into_iter()
+            .map(|(account, s)| (account, s.total_lamports, s.bundle_count, s.max_tip_lamports))
+            .collect();
+        assert_eq!(totals, vec![
+            ("big".to_string(), 11_500, 3, 9_000),
+            ("small".to_string(), 6_000, 2, 5_000),
+        ]);
+        assert_eq!(competition.get_top_tip_accounts(1).len(), 1);
+        assert_eq!(competition.total_tips_lamports.load(Ordering::Relaxed), 17_500);
+    }
+
+    #[test]
+    fn set_tab_clamps_to_the_last_tab() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.set_tab(2);
+        assert_eq!(*state.selected_tab.read(), 2);
+        state.set_tab(TAB_COUNT);
+        assert_eq!(*state.selected_tab.read(), TAB_COUNT - 1);
+        state.set_tab(usize::MAX);
+        assert_eq!(*state.selected_tab.read(), TAB_COUNT - 1);
+        state.set_tab(0);
+        assert_eq!(*state.selected_tab.read(), 0);
+    }
+
+    #[test]
+    fn trailing_rates_cover_only_completed_seconds_in_the_window() {
+        let start = Instant::now();
+        let at = |millis: u64| start + Duration::from_millis(millis);
+        let rates = RateTracker::starting_at(start);
+        assert_eq!(rates.rate_at(at(500), 1), None);
+
+        for second in 0..10 {
+            let per_second = if second < 5 { 10 } else { 100 };
+            rates.record_at(at(second * 1000 + 200), per_second / 2);
+            rates.record_at(at(second * 1000 + 700), per_second / 2);
+        }
+        // The current second is still filling up
+        rates.record_at(at(10_100), 1_000);
+
+        let now = at(10_500);
+        assert_eq!(rates.rate_at(now, 1), Some(100.0));
+        assert_eq!(rates.rate_at(now, 5), Some(100.0));
+        assert_eq!(rates.rate_at(now, 10), Some(55.0));
+        // A young tracker averages over the seconds it has
+        assert_eq!(rates.rate_at(now, 15), Some(55.0));
+
+        // Old seconds age out of the window
+        rates.record_at(at(80_000), 0);
+        assert_eq!(rates.rate_at(at(80_500), 15), Some(0.0));
+        assert!(rates.buckets.read().iter().all(|(second, _)| *second + RATE_TRACKER_SECS > 80));
     }
 
-    pub fn scroll_down(&self) {
-        let mut offset = self.scroll_offset.write();
-        *offset = offset.saturating_add(1);
+    #[test]
+    fn outage_gap_is_measured_across_a_reconnect() {
+        let health = NetworkHealth::new();
+        assert_eq!(health.take_outage_gap(500), None);
+
+        health.mark_disconnected(200);
+        assert_eq!(health.take_outage_gap(210), Some(9));
+        // Only the first slot after the reconnect is measured
+        assert_eq!(health.take_outage_gap(300), None);
+
+        // A proxy that comes back at a lower slot missed nothing
+        health.mark_disconnected(400);
+        assert_eq!(health.take_outage_gap(390), Some(0));
+        health.mark_disconnected(400);
+        assert_eq!(health.take_outage_gap(401), Some(0));
+        assert_eq!(health.outage_missed_slots.load(Ordering::Relaxed), 9);
+
+        // Nothing to measure from before the first slot
+        health.mark_disconnected(0);
+        assert_eq!(health.take_outage_gap(50), None);
+    }
+
+    fn type_text(state: &AppState, text: &str) {
+        for c in text.chars() {
+            state.text_input_key(KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn wallet_input_applies_valid_pubkeys_and_keeps_invalid_ones_open() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.open_wallet_input();
+        type_text(&state, "not a pubkey");
+        state.text_input_key(KeyCode::Enter);
+        {
+            let input = state.text_input.read();
+            let input = input.as_ref().expect("box stays open");
+            assert_eq!(input.text, "notapubkey");
+            assert!(input.error.as_deref().is_some_and(|e| e.starts_with("Invalid pubkey")), "{:?}", input.error);
+        }
+        assert!(state.wallet_monitor.wallets.read().is_empty());
+
+        let wallet = Pubkey::new_unique();
+        for _ in 0.."notapubkey".len() {
+            state.text_input_key(KeyCode::Backspace);
+        }
+        type_text(&state, &wallet.to_string());
+        assert_eq!(state.text_input.read().as_ref().unwrap().error, None);
+        state.text_input_key(KeyCode::Enter);
+        assert!(state.text_input.read().is_none());
+        assert!(state.wallet_monitor.wallets.read().contains(&wallet));
+
+        // Entering a watched wallet again stops watching it
+        state.open_wallet_input();
+        type_text(&state, &wallet.to_string());
+        state.text_input_key(KeyCode::Enter);
+        assert!(state.wallet_monitor.wallets.read().is_empty());
+    }
+
+    #[test]
+    fn leader_sort_modes_order_fixed_stats() {
+        let stats = |leader: Pubkey, total_latency_us, sample_count, max_latency_us| LeaderLatencyStats {
+            leader,
+            total_latency_us,
+            sample_count,
+            min_latency_us: 1_000,
+            max_latency_us,
+        };
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        // avg: a=30ms b=10ms c=20ms; max: a=40ms b=90ms c=25ms; samples: a=1 b=5 c=3
+        let fixed = vec![
+            stats(a, 30_000, 1, 40_000),
+            stats(b, 50_000, 5, 90_000),
+            stats(c, 60_000, 3, 25_000),
+        ];
+        let order = |sort: LeaderSort| {
+            let mut leaders = fixed.clone();
+            sort.sort(&mut leaders);
+            leaders.iter().map(|l| l.leader).collect::<Vec<_>>()
+        };
+
+        assert_eq!(order(LeaderSort::Avg), vec![b, c, a]);
+        assert_eq!(order(LeaderSort::Max), vec![c, a, b]);
+        assert_eq!(order(LeaderSort::Samples), vec![b, c, a]);
+        assert_eq!(LeaderSort::Avg.next().next().next(), LeaderSort::Avg);
+    }
+
+    #[test]
+    fn minute_buckets_split_at_minute_boundaries() {
+        use chrono::TimeZone;
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        let at = |min, sec, ms| Local.with_ymd_and_hms(2026, 1, 5, 12, min, sec).unwrap()
+            + chrono::Duration::milliseconds(ms);
+
+        state.record_minute(at(0, 0, 0), 1, 10);
+        state.record_minute(at(0, 59, 999), 2, 20);
+        state.record_minute(at(1, 0, 0), 4, 40);
+        // A late batch stamped in the previous minute is not counted twice or reopened
+        state.record_minute(at(0, 59, 500), 8, 80);
+        state.record_minute(at(3, 30, 0), 16, 160);
+
+        let buckets: Vec<_> = state.minute_buckets.read().iter().map(|(start, e, t)| (start.minute(), start.second(), *e, *t)).collect();
+        assert_eq!(buckets, vec![(0, 0, 3, 30), (1, 0, 12, 120), (3, 0, 16, 160)]);
+
+        for i in 0..MAX_MINUTE_BUCKETS as i64 {
+            state.record_minute(at(4, 0, 0) + chrono::Duration::minutes(i), 1, 1);
+        }
+        let buckets = state.minute_buckets.read();
+        assert_eq!(buckets.len(), MAX_MINUTE_BUCKETS);
+        assert_eq!(buckets.front().unwrap().0, at(4, 0, 0));
+    }
+
+    #[test]
+    fn reset_all_clears_every_substructure_but_keeps_the_connection() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        let (leader, program, mint, wallet) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        state.set_connection_state(ConnectionState::Connected);
+        state.add_slot(100, 5, 20, 3, 1);
+        state.add_slot(103, 5, 20, 3, 1);
+        state.record_payload(1024);
+        state.metrics.add_votes(4);
+        state.add_txn_sample(100, "sig".to_string(), vec!["Raydium".to_string()], true, false, None);
+        state.update_ema_txns_per_sec();
+        state.update_mev_index();
+        state.latency_stats.add_sample(latency_sample(100, 5_000, Some(leader), Some("ny")));
+        state.program_stats.record_program(program, 100);
+        state.program_stats.record_mints(&[mint]);
+        state.program_stats.record_swap(true);
+        state.account_stats.record_writes(&[program]);
+        state.coverage.record_batch(10, 1, 2, 3);
+        state.leader_tracker.record_slot(leader_slot(100, Some(leader), 20));
+        state.turbine_stats.add_sample(TurbineInfo {
+            slot: 100,
+            shred_index: 0,
+            turbine_index: 2,
+            layer: 1,
+            timestamp: Local::now(),
+            source_ip: None,
+        });
+        state.competition_stats.add_bundle(bundle(&["a"], Local::now()));
+        state.competition_stats.add_duplicate(DuplicateInfo {
+            signature: "a".to_string(),
+            first_slot: 100,
+            dup_slot: 103,
+            timestamp: Local::now(),
+        });
+        state.competition_stats.record_priority_fee(100, 5_000);
+        state.competition_stats.add_arbitrage("b".to_string());
+        state.wallet_monitor.add_wallet(wallet);
+        state.wallet_monitor.add_txn(WalletTxn {
+            wallet,
+            slot: 100,
+            signature: "c".to_string(),
+            timestamp: Local::now(),
+            status: TxnStatus::Unknown,
+            programs: Vec::new(),
+        });
+        state.network_health.record_gap(100, 103);
+        *state.selected_row.write() = 3;
+
+        state.reset_all();
+
+        assert_eq!(state.metrics.totals(), CounterTotals::default());
+        assert!(state.slot_history.read().is_empty());
+        assert!(state.minute_buckets.read().is_empty());
+        assert!(state.txn_samples.read().is_empty());
+        assert!(state.txn_rate_history.read().is_empty());
+        assert!(state.ema_txns_per_sec.read().is_none());
+        assert!(state.mev_index.history.read().is_empty());
+        assert_eq!(state.latency_stats.sample_count.load(Ordering::Relaxed), 0);
+        assert!(state.latency_stats.leader_latencies.read().is_empty());
+        assert!(state.program_stats.get_top_programs(10).is_empty());
+        assert!(state.program_stats.get_top_mints(10).is_empty());
+        assert_eq!(state.program_stats.routed_swap_count.load(Ordering::Relaxed), 0);
+        assert!(state.account_stats.top_accounts(10).is_empty());
+        assert_eq!(state.coverage.analyzed(), 0);
+        assert!(state.leader_tracker.get_top_leaders(10).is_empty());
+        assert_eq!(state.turbine_stats.total_samples.load(Ordering::Relaxed), 0);
+        assert_eq!(state.competition_stats.bundle_count.load(Ordering::Relaxed), 0);
+        assert!(state.competition_stats.bundles.read().is_empty());
+        assert!(state.competition_stats.duplicate_txns.read().is_empty());
+        assert!(state.competition_stats.priority_fee_summary().is_none());
+        assert_eq!(state.competition_stats.arbitrage_count.load(Ordering::Relaxed), 0);
+        assert_eq!(state.wallet_monitor.totals().txn_count, 0);
+        assert!(state.wallet_monitor.transactions.read().is_empty());
+        assert_eq!(state.network_health.missed_slot_count.load(Ordering::Relaxed), 0);
+        assert!(state.network_health.missed_slots.read().is_empty());
+        assert_eq!(*state.selected_row.read(), 0);
+
+        // Connection state, uptime and the watch list survive
+        assert_eq!(*state.connection_state.read(), ConnectionState::Connected);
+        assert!(state.connected_at.read().is_some());
+        assert!(state.wallet_monitor.wallets.read().contains(&wallet));
+    }
+
+    #[test]
+    fn category_rates_trail_a_burst_of_recorded_programs() {
+        let stats = ProgramStats::new();
+        let kamino: Pubkey = KnownPrograms::KAMINO_LENDING.parse().unwrap();
+        let raydium: Pubkey = KnownPrograms::RAYDIUM_V4.parse().unwrap();
+        // A liquidation wave: 20 lending transactions in one second, alongside 5 swaps
+        for slot in 0..20 {
+            stats.record_program(kamino, slot);
+        }
+        for slot in 0..5 {
+            stats.record_program(raydium, slot);
+        }
+        stats.record_program(Pubkey::new_unique(), 1);
+
+        let later = Instant::now() + Duration::from_secs(5);
+        assert_eq!(stats.lending_rate.rate_at(later, 5), Some(4.0));
+        assert_eq!(stats.dex_rate.rate_at(later, 5), Some(1.0));
+        assert_eq!(stats.mev_rate.rate_at(later, 5), Some(0.0));
+        // The burst ages out of the trailing window
+        assert_eq!(stats.lending_rate.rate_at(later + Duration::from_secs(5), 5), Some(0.0));
+    }
+
+    #[test]
+    fn confirmed_failure_flips_an_unknown_wallet_txn() {
+        let monitor = WalletMonitor::new();
+        let wallet = Pubkey::new_unique();
+        monitor.add_wallet(wallet);
+        let since = Local::now() - chrono::Duration::seconds(1);
+        for signature in ["pending", "other"] {
+            monitor.add_txn(WalletTxn {
+                wallet,
+                slot: 100,
+                signature: signature.to_string(),
+                timestamp: Local::now(),
+                status: TxnStatus::Unknown,
+                programs: Vec::new(),
+            });
+        }
+        assert_eq!(monitor.unknown_signatures(since).len(), 2);
+
+        assert!(monitor.set_status("pending", TxnStatus::Failed));
+        let txns = monitor.transactions.read();
+        let flipped = txns.iter().find(|t| t.signature == "pending").unwrap();
+        assert_eq!(flipped.status, TxnStatus::Failed);
+        drop(txns);
+        let totals = monitor.totals();
+        assert_eq!((totals.txn_count, totals.unknown_count, totals.fail_count, totals.success_count), (2, 1, 1, 0));
+        assert_eq!(monitor.unknown_signatures(since), vec!["other".to_string()]);
+
+        // Settled records aren't counted twice
+        assert!(!monitor.set_status("pending", TxnStatus::Success));
+        assert_eq!(monitor.totals().fail_count, 1);
+    }
+
+    #[test]
+    fn slot_drift_is_measured_against_the_nominal_slot_time() {
+        let start = Instant::now();
+        let anchor = (1_000, start);
+        let at = |ms| start + Duration::from_millis(ms);
+
+        // 20 slots in 5.2 s is 10 ms per slot slower than 400 ms
+        assert!((slot_drift_ms(anchor, 1_020, at(8_200)).unwrap() - 10.0).abs() < 1e-9);
+        assert!((slot_drift_ms(anchor, 1_020, at(7_800)).unwrap() + 10.0).abs() < 1e-9);
+        // Too few slots to judge, an older slot, or a clock that went backwards
+        assert_eq!(slot_drift_ms(anchor, 1_005, at(2_000)), None);
+        assert_eq!(slot_drift_ms(anchor, 999, at(8_000)), None);
+        assert_eq!(slot_drift_ms((1_000, at(10_000)), 1_020, start), None);
+    }
+
+    #[test]
+    fn implausible_drift_reanchors_instead_of_showing() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        let start = Instant::now();
+        state.update_slot_drift(1_000, start);
+        state.update_slot_drift(1_020, start + Duration::from_millis(8_200));
+        assert!((state.slot_drift_ms.read().unwrap() - 10.0).abs() < 1e-9);
+
+        // Over twice the nominal slot time (a clock jump or a long stall) starts over
+        state.update_slot_drift(1_040, start + Duration::from_secs(40));
+        assert_eq!(*state.slot_drift_ms.read(), None);
+        assert_eq!(state.slot_anchor.read().map(|(slot, _)| slot), Some(1_040));
+    }
+
+    #[test]
+    fn tick_rate_is_clamped_at_both_ends() {
+        assert_eq!(clamp_tick_rate(0), 16);
+        assert_eq!(clamp_tick_rate(100), 100);
+        assert_eq!(clamp_tick_rate(60_000), 2000);
+
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.tick_rate_ms.store(40, Ordering::Relaxed);
+        assert_eq!(state.adjust_tick_rate(true), 20);
+        assert_eq!(state.adjust_tick_rate(true), 16);
+        assert_eq!(state.adjust_tick_rate(true), 16);
+        state.tick_rate_ms.store(1500, Ordering::Relaxed);
+        assert_eq!(state.adjust_tick_rate(false), 2000);
+        assert_eq!(state.tick_rate(), Duration::from_millis(2000));
+    }
+
+    #[test]
+    fn health_score_for_synthetic_snapshots() {
+        let snapshot = |heartbeats: (u64, u64), shreds: (u64, u64), slots: (u64, u64)| {
+            let health = NetworkHealth::default();
+            health.heartbeat_success.store(heartbeats.0, Ordering::Relaxed);
+            health.heartbeat_fail.store(heartbeats.1, Ordering::Relaxed);
+            health.fec_recovery_count.store(shreds.0, Ordering::Relaxed);
+            health.direct_receive_count.store(shreds.1, Ordering::Relaxed);
+            health.missed_slot_count.store(slots.0, Ordering::Relaxed);
+            health.slot_span.store(slots.1, Ordering::Relaxed);
+            health
+        };
+        let hour = Duration::from_secs(3600);
+        let close = |score: f64, expected: f64| assert!((score - expected).abs() < 1e-9, "{} != {}", score, expected);
+
+        // Cold start is neutral
+        close(snapshot((0, 0), (0, 0), (0, 0)).score(0, Duration::ZERO), 50.0);
+        // Perfect on every factor
+        close(snapshot((10, 0), (0, 100), (0, 100)).score(0, hour), 100.0);
+        // Only heartbeats so far; the other factors are left out rather than scored
+        close(snapshot((3, 1), (0, 0), (0, 10)).score(10, Duration::from_secs(30)), 75.0);
+        // Heartbeats fine (0.25), 10% FEC (0.20 x 0.5), 3 reconnects/h (0.25 x 0.5), 5% slots missed (0.30 x 0)
+        close(snapshot((10, 0), (10, 90), (5, 100)).score(3, hour), 47.5);
+        // Everything at or past its bad level
+        close(snapshot((0, 10), (50, 50), (50, 100)).score(60, hour), 0.0);
+    }
+
+    #[test]
+    fn paging_moves_by_the_visible_rows_and_stops_at_the_ends() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        let row = || *state.selected_row.read();
+        state.set_page_rows(10);
+
+        state.page_down(25);
+        assert_eq!(row(), 10);
+        state.page_down(25);
+        assert_eq!(row(), 20);
+        state.page_down(25);
+        assert_eq!(row(), 24);
+        state.page_up();
+        assert_eq!(row(), 14);
+        state.page_up();
+        state.page_up();
+        assert_eq!(row(), 0);
+
+        state.scroll_bottom(25);
+        assert_eq!(row(), 24);
+        state.scroll_top();
+        assert_eq!(row(), 0);
+
+        // Empty lists and a zero-height pane don't underflow or stall
+        state.scroll_bottom(0);
+        state.page_down(0);
+        assert_eq!(row(), 0);
+        state.set_page_rows(0);
+        state.page_down(25);
+        assert_eq!(row(), 1);
+    }
+
+    #[test]
+    fn tip_filter_hides_only_bundles_below_the_threshold() {
+        let mut state = AppState::new("http://127.0.0.1:50051".to_string());
+        let with_tip = |lamports| BundleInfo { tip_amount: lamports, ..bundle(&["a"], Local::now()) };
+        let (small, exact, large) = (with_tip(999_999), with_tip(1_000_000), with_tip(5_000_000));
+
+        // No threshold configured: everything shows, even with the toggle on
+        *state.tip_filter_enabled.get_mut() = true;
+        assert!(state.shows_bundle(&small));
+
+        state.min_tip_lamports = Some(1_000_000);
+        assert!(!state.shows_bundle(&small));
+        assert!(state.shows_bundle(&exact));
+        assert!(state.shows_bundle(&large));
+
+        // Toggled off at runtime, the threshold is kept but not applied
+        *state.tip_filter_enabled.write() = false;
+        assert_eq!(state.active_tip_filter(), None);
+        assert!(state.shows_bundle(&small));
+    }
+
+    #[test]
+    fn top_accounts_keeps_the_busiest_from_a_stream_of_writes() {
+        let stats = AccountStats::new();
+        let accounts: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        // Account i is written i + 1 times, interleaved
+        for round in 0..6 {
+            let touched: Vec<Pubkey> = accounts.iter().enumerate().filter(|(i, _)| *i >= round).map(|(_, a)| *a).collect();
+            stats.record_writes(&touched);
+        }
+
+        assert_eq!(stats.top_accounts(3), vec![(accounts[5], 6), (accounts[4], 5), (accounts[3], 4)]);
+        assert_eq!(stats.top_accounts(10).len(), 6);
+        assert!(stats.top_accounts(0).is_empty());
+
+        // Past the tracking bound, one-off accounts are dropped but the busy ones survive
+        for _ in 0..MAX_TRACKED_ACCOUNTS {
+            stats.record_writes(&[Pubkey::new_unique()]);
+        }
+        assert!(stats.writes.read().len() <= MAX_TRACKED_ACCOUNTS);
+        assert_eq!(stats.top_accounts(1), vec![(accounts[5], 6)]);
+
+        // When every account has the same count, eviction still keeps half of them
+        let even = AccountStats::new();
+        for _ in 0..=MAX_TRACKED_ACCOUNTS {
+            even.record_writes(&[Pubkey::new_unique()]);
+        }
+        assert_eq!(even.writes.read().len(), MAX_TRACKED_ACCOUNTS / 2);
+    }
+
+    #[test]
+    fn quit_prompt_transitions() {
+        let mut state = AppState::new("http://127.0.0.1:50051".to_string());
+        // Without --confirm-quit, quitting is immediate
+        assert!(state.request_quit());
+        assert!(!state.is_quit_pending());
+
+        state.confirm_quit = true;
+        assert!(!state.request_quit());
+        assert!(state.is_quit_pending());
+        // Anything but y dismisses the prompt and keeps running
+        assert!(!state.answer_quit(KeyCode::Char('n')));
+        assert!(!state.is_quit_pending());
+        assert!(!state.request_quit());
+        assert!(!state.answer_quit(KeyCode::Esc));
+
+        assert!(!state.request_quit());
+        assert!(state.answer_quit(KeyCode::Char('Y')));
+        assert!(!state.is_quit_pending());
+    }
+
+    #[test]
+    fn first_shred_delay_is_computed_and_averaged_per_leader() {
+        let start = Instant::now();
+        let anchor = (1_000, start);
+        // Slot 1_010 is due 4 s after the anchor at 400 ms per slot; at 410 ms per slot, 4.1 s
+        let delay = slot_arrival_delay_ms(anchor, 0.0, 1_010, start + Duration::from_millis(4_150)).unwrap();
+        assert!((delay - 150.0).abs() < 1e-9);
+        let delay = slot_arrival_delay_ms(anchor, 10.0, 1_010, start + Duration::from_millis(4_050)).unwrap();
+        assert!((delay + 50.0).abs() < 1e-9);
+        assert_eq!(slot_arrival_delay_ms(anchor, 0.0, 999, start + Duration::from_secs(1)), None);
+
+        let tracker = LeaderTracker::new();
+        let leader = Pubkey::new_unique();
+        for (slot, delay) in [(1_010, Some(150.0)), (1_011, Some(-50.0)), (1_012, None)] {
+            tracker.record_slot(LeaderSlotInfo { first_shred_delay_ms: delay, ..leader_slot(slot, Some(leader), 1) });
+        }
+        let stats = tracker.leader_stats.read()[&leader].clone();
+        assert_eq!(stats.first_shred_delay_count, 2);
+        assert_eq!(stats.avg_first_shred_delay_ms(), Some(50.0));
+        assert_eq!(LeaderStats::default().avg_first_shred_delay_ms(), None);
+    }
+
+    #[test]
+    fn connection_scope_counts_restart_on_each_reconnect() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.metrics.add_entry(5, 50);
+        state.set_connection_state(ConnectionState::Connected);
+        state.metrics.add_entry(2, 20);
+        assert_eq!(state.toggle_counter_scope(), CounterScope::Connection);
+        assert_eq!((state.overview_totals().entries, state.overview_totals().txns), (2, 20));
+
+        // Counts from the dropped connection don't carry over
+        state.set_connection_state(ConnectionState::Reconnecting);
+        state.metrics.add_entry(1, 10);
+        state.set_connection_state(ConnectionState::Connected);
+        assert_eq!(state.overview_totals(), CounterTotals::default());
+        state.metrics.add_entry(3, 30);
+        assert_eq!((state.overview_totals().entries, state.overview_totals().txns), (3, 30));
+
+        assert_eq!(state.toggle_counter_scope(), CounterScope::AllTime);
+        assert_eq!((state.overview_totals().entries, state.overview_totals().txns), (11, 110));
+    }
+
+    #[test]
+    fn signature_search_matches_case_sensitive_prefixes() {
+        let sig = "5KtPn1LGuxhFiwjxErkxTb7XxtLVYUBe6Cn33ej7ATNK";
+        assert!(signature_matches(sig, "5KtP"));
+        assert!(signature_matches(sig, sig));
+        assert!(signature_matches(sig, ""));
+        assert!(!signature_matches(sig, "5ktp"));
+        assert!(!signature_matches(sig, "KtPn"));
+        assert!(!signature_matches(sig, &format!("{}x", sig)));
+
+        // Applied through the search box, only matching samples are listed
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        let sample = |signature: &str| TxnSample {
+            slot: 1,
+            signature: signature.to_string(),
+            received_at: Local::now(),
+            programs: Vec::new(),
+            is_dex: false,
+            is_bundle: false,
+            tip_amount: None,
+        };
+        state.open_signature_search();
+        type_text(&state, "5Kt");
+        state.text_input_key(KeyCode::Enter);
+        assert!(state.shows_txn_sample(&sample(sig)));
+        assert!(!state.shows_txn_sample(&sample("3yZe7d8Wq4hBN1nnSsxuVyfJ7zXfyjHfLbk4J1dXDjFq")));
+    }
+
+    #[test]
+    fn reservoir_stays_bounded_and_favors_flagged_items() {
+        // Every tenth item is flagged; returns how many flagged items the buffer ends up with
+        let flagged_kept = |flagged_weight: f64| {
+            let mut sampler = ReservoirSampler::new(200, SAMPLE_HORIZON_WEIGHT);
+            let mut buffer = VecDeque::new();
+            for i in 0..50_000 {
+                let flagged = i % 10 == 0;
+                sampler.offer(&mut buffer, flagged, if flagged { flagged_weight } else { 1.0 });
+                assert!(buffer.len() <= 200);
+            }
+            assert_eq!(buffer.len(), 200);
+            buffer.iter().filter(|&&flagged| flagged).count()
+        };
+
+        // Unweighted, about 20 of 200; at weight 4, about 0.4 / 1.3 of them, or 62
+        assert!(flagged_kept(1.0) < 40);
+        assert!(flagged_kept(4.0) > 40);
+    }
+
+    #[test]
+    fn connection_timeline_orders_transitions_with_durations() {
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        for conn_state in [
+            ConnectionState::Connecting,
+            ConnectionState::Connected,
+            ConnectionState::Connected,
+            ConnectionState::Reconnecting,
+            ConnectionState::Connected,
+        ] {
+            state.set_connection_state(conn_state);
+        }
+        // Repeating the current state isn't a transition
+        assert_eq!(state.connection_events.read().len(), 4);
+
+        let start = Local::now() - chrono::Duration::minutes(10);
+        for (i, (at, _)) in state.connection_events.write().iter_mut().enumerate() {
+            *at = start + chrono::Duration::seconds([0, 2, 302, 305][i]);
+        }
+        let timeline = state.connection_timeline_at(start + chrono::Duration::seconds(365));
+        let summary: Vec<_> = timeline.iter().map(|(_, conn_state, lasted)| (conn_state.clone(), lasted.as_secs())).collect();
+        assert_eq!(summary, vec![
+            (ConnectionState::Connected, 60),
+            (ConnectionState::Reconnecting, 3),
+            (ConnectionState::Connected, 300),
+            (ConnectionState::Connecting, 2),
+        ]);
+
+        for i in 0..MAX_CONNECTION_EVENTS {
+            state.set_connection_state(if i % 2 == 0 { ConnectionState::Reconnecting } else { ConnectionState::Connected });
+        }
+        assert_eq!(state.connection_events.read().len(), MAX_CONNECTION_EVENTS);
+    }
+
+    #[test]
+    fn program_slot_rings_count_per_slot_and_stay_bounded() {
+        let stats = ProgramStats::new();
+        let program = Pubkey::new_unique();
+        for slot in [100, 100, 101, 103, 103, 103] {
+            stats.record_program(program, slot);
+        }
+        // A late batch for an earlier slot lands in that slot
+        stats.record_program(program, 101);
+
+        let counts = stats.program_slot_counts(&program, 103);
+        assert_eq!(counts.len(), PROGRAM_SLOT_HISTORY);
+        assert_eq!(counts[PROGRAM_SLOT_HISTORY - 4..], [2, 2, 0, 3]);
+
+        // Only the newest PROGRAM_SLOT_HISTORY slots are kept
+        for slot in 104..104 + PROGRAM_SLOT_HISTORY as u64 {
+            stats.record_program(program, slot);
+        }
+        assert_eq!(stats.slot_activity.read()[&program].len(), PROGRAM_SLOT_HISTORY);
+        assert_eq!(stats.program_slot_counts(&program, 103 + PROGRAM_SLOT_HISTORY as u64), vec![1; PROGRAM_SLOT_HISTORY]);
+
+        // Past the ring limit, the program idle the longest gives up its ring
+        for i in 0..MAX_PROGRAM_SLOT_RINGS as u64 {
+            stats.record_program(Pubkey::new_unique(), 1_000 + i);
+        }
+        let rings = stats.slot_activity.read();
+        assert_eq!(rings.len(), MAX_PROGRAM_SLOT_RINGS);
+        assert!(!rings.contains_key(&program));
     }
 }
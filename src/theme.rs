@@ -0,0 +1,200 @@
+use std::str::FromStr;
+
+use clap::ValueEnum;
+use ratatui::style::Color;
+
+use crate::programs::ProgramCategory;
+
+/// Built-in color themes selectable with `--theme`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ThemeName {
+    /// Bright colors for dark terminal backgrounds
+    Dark,
+    /// Darker colors for light terminal backgrounds
+    Light,
+    /// Terminal default colors only
+    Mono,
+}
+
+/// Semantic colors consulted by the draw functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Titles, headings and primary highlights
+    pub accent: Color,
+    /// Secondary highlight for charts and counts
+    pub highlight: Color,
+    pub success: Color,
+    pub warn: Color,
+    pub error: Color,
+    /// Borders and secondary text
+    pub dim: Color,
+    /// Field labels
+    pub label: Color,
+    /// Values and body text
+    pub text: Color,
+    /// Overlay background
+    pub bg: Color,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            accent: Color::Cyan,
+            highlight: Color::Magenta,
+            success: Color::Green,
+            warn: Color::Yellow,
+            error: Color::Red,
+            dim: Color::DarkGray,
+            label: Color::Gray,
+            text: Color::White,
+            bg: Color::Black,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            accent: Color::Blue,
+            highlight: Color::Magenta,
+            success: Color::Rgb(0, 128, 0),
+            warn: Color::Rgb(176, 112, 0),
+            error: Color::Red,
+            dim: Color::Gray,
+            label: Color::DarkGray,
+            text: Color::Black,
+            bg: Color::White,
+        }
+    }
+
+    pub fn mono() -> Self {
+        Self {
+            accent: Color::Reset,
+            highlight: Color::Reset,
+            success: Color::Reset,
+            warn: Color::Reset,
+            error: Color::Reset,
+            dim: Color::Reset,
+            label: Color::Reset,
+            text: Color::Reset,
+            bg: Color::Reset,
+        }
+    }
+
+    pub fn from_name(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::Mono => Self::mono(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Colors used for each program category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryColors {
+    pub dex: Color,
+    pub lending: Color,
+    pub staking: Color,
+    pub mev: Color,
+    pub token: Color,
+    pub other: Color,
+}
+
+impl Default for CategoryColors {
+    fn default() -> Self {
+        Self {
+            dex: Color::Green,
+            lending: Color::Blue,
+            staking: Color::Magenta,
+            mev: Color::Yellow,
+            token: Color::Gray,
+            other: Color::Gray,
+        }
+    }
+}
+
+impl CategoryColors {
+    /// Terminal default color for every category
+    pub fn mono() -> Self {
+        Self {
+            dex: Color::Reset,
+            lending: Color::Reset,
+            staking: Color::Reset,
+            mev: Color::Reset,
+            token: Color::Reset,
+            other: Color::Reset,
+        }
+    }
+
+    pub fn color(&self, category: ProgramCategory) -> Color {
+        match category {
+            ProgramCategory::Dex => self.dex,
+            ProgramCategory::Lending => self.lending,
+            ProgramCategory::Staking => self.staking,
+            ProgramCategory::Mev => self.mev,
+            ProgramCategory::Token => self.token,
+            ProgramCategory::Other => self.other,
+        }
+    }
+}
+
+impl FromStr for CategoryColors {
+    type Err = String;
+
+    /// Parse overrides like `dex=green,lending=#3366ff`; unlisted categories keep their defaults
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut colors = Self::default();
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (category, color) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected `category=color`, got `{}`", pair))?;
+            let color = Color::from_str(color.trim())
+                .map_err(|_| format!("unknown color `{}`", color.trim()))?;
+
+            let slot = match category.trim().to_ascii_lowercase().as_str() {
+                "dex" => &mut colors.dex,
+                "lending" => &mut colors.lending,
+                "staking" => &mut colors.staking,
+                "mev" => &mut colors.mev,
+                "token" => &mut colors.token,
+                "other" => &mut colors.other,
+                other => return Err(format!("unknown category `{}`", other)),
+            };
+            *slot = color;
+        }
+
+        Ok(colors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every color of a theme, in field order
+    fn colors(theme: Theme) -> [Color; 9] {
+        [theme.accent, theme.highlight, theme.success, theme.warn, theme.error, theme.dim, theme.label, theme.text, theme.bg]
+    }
+
+    #[test]
+    fn themes_keep_their_semantic_colors_apart() {
+        for name in [ThemeName::Dark, ThemeName::Light] {
+            let theme = Theme::from_name(name);
+            let status = [theme.success, theme.warn, theme.error];
+            assert!(status.iter().all(|c| *c != theme.text), "{:?}", name);
+            assert_ne!(theme.success, theme.warn, "{:?}", name);
+            assert_ne!(theme.warn, theme.error, "{:?}", name);
+            assert_ne!(theme.text, theme.bg, "{:?}", name);
+        }
+        assert_ne!(Theme::dark().text, Theme::light().text);
+        assert_ne!(Theme::dark().bg, Theme::light().bg);
+        assert!(colors(Theme::mono()).iter().all(|c| *c == Color::Reset));
+        assert_eq!(Theme::default(), Theme::dark());
+    }
+}
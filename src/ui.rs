@@ -5,19 +5,65 @@ use std::time::Duration;
 use num_format::{Locale, ToFormattedString};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     symbols,
     text::{Line, Span},
     widgets::{
-        Block, Borders, Cell, List, ListItem, Paragraph, Row,
-        Sparkline, Table, Tabs, Clear,
+        BarChart, Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row,
+        Sparkline, Table, Tabs, Clear, Wrap,
     },
     Frame,
 };
+use solana_sdk::pubkey::Pubkey;
 
-use crate::state::{AppState, ConnectionState, LogLevel};
+use crate::state::{AppState, BundleInfo, BundleStatus, ConnectionState, CounterScope, InputTarget, LeaderLatencyStats, LeaderSort, LogLevel, RateTracker, RpcHealth, TextInput, TxnSample, TxnStatus, PROGRAM_SLOT_HISTORY};
 use crate::programs::ProgramCategory;
 
+/// Rows shown in the selectable tables and lists
+const LATENCY_LEADER_ROWS: usize = 20;
+const TOP_PROGRAM_ROWS: usize = 30;
+const TOP_MINT_ROWS: usize = 15;
+const HOT_ACCOUNT_ROWS: usize = 15;
+const RECENT_TXN_SAMPLE_ROWS: usize = 20;
+const TOP_LEADER_ROWS: usize = 30;
+const OBSERVED_SLOT_ROWS: usize = 8;
+const RECENT_BUNDLE_ROWS: usize = 15;
+const TOP_TIP_ACCOUNT_ROWS: usize = 8;
+const RECENT_DUPLICATE_ROWS: usize = 10;
+const WALLET_TXN_ROWS: usize = 15;
+/// Columns of the Logs tab given to the connection timeline
+const CONNECTION_TIMELINE_WIDTH: u16 = 36;
+/// Columns of the Wallet tab given to the totals, beside the per-wallet table
+const WALLET_SUMMARY_WIDTH: u16 = 38;
+
+/// UI lag (in slots) at which the header starts showing it
+const UI_LAG_WARN_SLOTS: u64 = 2;
+/// Missed slot count shown in red above this
+const MISSED_SLOTS_ALERT: u64 = 10;
+/// Connection health score shown green from the first and yellow from the second, red below
+const HEALTH_GOOD_SCORE: f64 = 80.0;
+const HEALTH_FAIR_SCORE: f64 = 50.0;
+/// Slot drift (ms/slot, either direction) shown green below the first and yellow below the second
+const DRIFT_OK_MS: f64 = 10.0;
+const DRIFT_WARN_MS: f64 = 40.0;
+
+/// Trailing window for the header txn rate
+const HEADER_RATE_SECS: u64 = 5;
+/// Trailing window for the per-category rates on the Programs tab
+const CATEGORY_RATE_SECS: u64 = 5;
+/// Trailing windows shown on the Overview tab
+const TRAILING_RATE_SECS: [u64; 3] = [1, 5, 15];
+
+/// Heights of the header and tab bands at the top of the screen
+const HEADER_HEIGHT: u16 = 3;
+const TABS_HEIGHT: u16 = 3;
+const FOOTER_HEIGHT: u16 = 3;
+const MIN_CONTENT_HEIGHT: u16 = 10;
+
+/// Below this size the layout would overlap, so only a "too small" message is drawn
+const MIN_TERMINAL_WIDTH: u16 = 60;
+const MIN_TERMINAL_HEIGHT: u16 = HEADER_HEIGHT + TABS_HEIGHT + MIN_CONTENT_HEIGHT + FOOTER_HEIGHT;
+
 /// Tab titles - 8 tabs total
 const TAB_TITLES: [&str; 8] = [
     "📊 Overview",
@@ -30,8 +76,24 @@ const TAB_TITLES: [&str; 8] = [
     "💰 Wallet",
 ];
 
-fn format_number(n: u64) -> String {
-    n.to_formatted_string(&Locale::en)
+fn format_number(n: u64, locale: Locale) -> String {
+    n.to_formatted_string(&locale)
+}
+
+/// Byte count in binary units, e.g. `1.5 MB`
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{:.0} {}", value, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
 }
 
 fn format_duration(d: Duration) -> String {
@@ -53,37 +115,239 @@ fn truncate_pubkey(s: &str) -> String {
     }
 }
 
+/// A validator's name from `--validators-file`, or its truncated identity
+fn leader_label(state: &AppState, leader: &Pubkey) -> String {
+    match state.leader_tracker.validator_names.read().get(leader) {
+        Some(name) => name.clone(),
+        None => truncate_pubkey(&leader.to_string()),
+    }
+}
+
+/// Centered popup of at most `width` x `height` within `area`
+fn centered_popup(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Leaders in the selected region and sort order, as shown on the Latency tab
+fn leaders_by_latency(state: &AppState) -> Vec<LeaderLatencyStats> {
+    let latency = &state.latency_stats;
+    let mut leaders: Vec<_> = match state.latency_region.read().as_ref() {
+        Some(region) => latency.region_leader_latencies.read()
+            .get(region)
+            .map(|leaders| leaders.values().cloned().collect())
+            .unwrap_or_default(),
+        None => latency.leader_latencies.read().values().cloned().collect(),
+    };
+    state.leader_sort.read().sort(&mut leaders);
+    leaders.truncate(LATENCY_LEADER_ROWS);
+    leaders
+}
+
+/// Full identifiers of the selectable rows on the current tab, in display order
+pub fn selectable_rows(state: &AppState) -> Vec<String> {
+    let paused = state.paused.read();
+    match *state.selected_tab.read() {
+        1 => leaders_by_latency(state).iter().map(|l| l.leader.to_string()).collect(),
+        3 => state.program_stats.get_top_programs(TOP_PROGRAM_ROWS).iter().map(|p| p.program_id.to_string()).collect(),
+        4 => state.leader_tracker.get_top_leaders(TOP_LEADER_ROWS).iter().map(|l| l.leader.to_string()).collect(),
+        5 => {
+            let live = state.competition_stats.bundles.read();
+            let bundles = paused.as_ref().map_or(&*live, |f| &f.bundles);
+            bundles.iter().rev().filter(|b| state.shows_bundle(b)).take(RECENT_BUNDLE_ROWS)
+                .map(|b| b.signatures.join("\n"))
+                .collect()
+        }
+        6 => {
+            let live = state.logs.read();
+            let logs = paused.as_ref().map_or(&*live, |f| &f.logs);
+            logs.iter().rev().map(|log| log.message.clone()).collect()
+        }
+        7 => {
+            let live = state.wallet_monitor.transactions.read();
+            let txns = paused.as_ref().map_or(&*live, |f| &f.wallet_txns);
+            txns.iter().rev().take(WALLET_TXN_ROWS).map(|t| t.signature.clone()).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// The bundle selected in Recent Bundles, as listed there
+pub fn selected_bundle(state: &AppState) -> Option<BundleInfo> {
+    let paused = state.paused.read();
+    let live = state.competition_stats.bundles.read();
+    let bundles = paused.as_ref().map_or(&*live, |f| &f.bundles);
+    let shown: Vec<&BundleInfo> = bundles.iter().rev().filter(|b| state.shows_bundle(b)).take(RECENT_BUNDLE_ROWS).collect();
+    state.selected_row_in(shown.len()).map(|i| shown[i].clone())
+}
+
+/// One line for a sampled transaction: slot, signature, bundle tip and the programs it invoked
+fn format_txn_sample(sample: &TxnSample, state: &AppState) -> Line<'static> {
+    let theme = &state.theme;
+    let mut spans = vec![
+        Span::styled(format!("{}", sample.slot), Style::default().fg(theme.text)),
+        Span::raw(" │ "),
+    ];
+    match state.signature_search.read().as_deref() {
+        // Listed samples all match, so the search text is the start of the signature
+        Some(prefix) => {
+            let sig = &sample.signature;
+            let shown = truncate_pubkey(sig);
+            let rest = if prefix.len() <= 6 {
+                shown[prefix.len().min(shown.len())..].to_string()
+            } else {
+                format!("..{}", &sig[sig.len().saturating_sub(4)..])
+            };
+            spans.push(Span::styled(prefix.to_string(), Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)));
+            spans.push(Span::styled(rest, Style::default().fg(theme.accent)));
+        }
+        None => spans.push(Span::styled(truncate_pubkey(&sample.signature), Style::default().fg(theme.accent))),
+    }
+    spans.push(Span::raw(" │ "));
+    if sample.is_bundle {
+        let tip = match sample.tip_amount {
+            Some(lamports) => format!("bundle {:.6} SOL", lamports as f64 / 1e9),
+            None => "bundle".to_string(),
+        };
+        spans.push(Span::styled(tip, Style::default().fg(theme.success)));
+        spans.push(Span::raw(" │ "));
+    }
+    let programs = if sample.programs.is_empty() { "–".to_string() } else { sample.programs.join(", ") };
+    spans.push(Span::styled(programs, Style::default().fg(theme.dim)));
+    Line::from(spans)
+}
+
+/// Rows a bordered list in `area` shows at once
+fn list_page_rows(area: Rect) -> usize {
+    area.height.saturating_sub(2) as usize
+}
+
+/// Rows a bordered table with a header row in `area` shows at once
+fn table_page_rows(area: Rect) -> usize {
+    area.height.saturating_sub(3) as usize
+}
+
+/// Style for a row, highlighted when it's the selected one
+fn row_style(state: &AppState, index: usize, row_count: usize) -> Style {
+    if state.selected_row_in(row_count) == Some(index) {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::default()
+    }
+}
+
 /// Main UI rendering function
 pub fn draw(f: &mut Frame, state: &Arc<AppState>) {
     let size = f.area();
+    // Nothing fits in a zero-sized terminal
+    if size.is_empty() {
+        return;
+    }
+    if size.width < MIN_TERMINAL_WIDTH || size.height < MIN_TERMINAL_HEIGHT {
+        draw_too_small(f, state, size);
+        return;
+    }
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),  // Header
-            Constraint::Length(3),  // Tabs
-            Constraint::Min(10),    // Content
-            Constraint::Length(3),  // Footer
+            Constraint::Length(HEADER_HEIGHT),    // Header
+            Constraint::Length(TABS_HEIGHT),      // Tabs
+            Constraint::Min(MIN_CONTENT_HEIGHT),  // Content
+            Constraint::Length(FOOTER_HEIGHT),    // Footer
         ])
         .split(size);
 
     draw_header(f, state, chunks[0]);
     draw_tabs(f, state, chunks[1]);
-    draw_content(f, state, chunks[2]);
+    // The client stopped retrying, so keep the reason on screen until the user retries
+    let conn_state = state.connection_state.read().clone();
+    let content_area = match conn_state {
+        ConnectionState::Error(error) => {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(chunks[2]);
+            draw_error_banner(f, state, &error, parts[0]);
+            parts[1]
+        }
+        _ => chunks[2],
+    };
+    draw_content(f, state, content_area);
     draw_footer(f, state, chunks[3]);
 
     if *state.show_help.read() {
         draw_help_overlay(f, state);
+    } else if *state.show_info.read() {
+        draw_info_overlay(f, state);
+    } else if let Some(input) = state.text_input.read().as_ref() {
+        draw_text_input_overlay(f, state, input);
+    } else if let Some(bundle) = state.bundle_detail.read().as_ref() {
+        draw_bundle_detail_overlay(f, state, bundle);
+    } else if let Some(program) = *state.program_detail.read() {
+        draw_program_detail_overlay(f, state, &program);
+    }
+
+    if state.is_quit_pending() {
+        draw_quit_prompt(f, state);
     }
 }
 
+/// One-line notice shown while the client has given up on the proxy
+fn draw_error_banner(f: &mut Frame, state: &AppState, error: &str, area: Rect) {
+    let theme = &state.theme;
+    let banner = Paragraph::new(Line::from(vec![
+        Span::styled(" ✖ Gave up connecting: ", Style::default().fg(theme.error).add_modifier(Modifier::BOLD)),
+        Span::styled(error, Style::default().fg(theme.text)),
+        Span::styled("  (p: retry)", Style::default().fg(theme.dim)),
+    ]));
+    f.render_widget(banner, area);
+}
+
+/// Stand-in for the whole UI when the terminal is below the minimum size
+fn draw_too_small(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let mut text = vec![
+        Line::from(Span::styled("Terminal too small", Style::default().fg(theme.warn).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled(
+            format!("{}x{}, need {}x{}", area.width, area.height, MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT),
+            Style::default().fg(theme.dim),
+        )),
+    ];
+    // The prompt still takes keys, so it has to stay visible
+    if state.is_quit_pending() {
+        text.push(Line::from(Span::styled("Quit? (y/n)", Style::default().fg(theme.text).add_modifier(Modifier::BOLD))));
+    }
+
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
 fn draw_header(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let conn_state = state.connection_state.read().clone();
     let (status_color, status_icon) = match &conn_state {
-        ConnectionState::Connected => (Color::Green, "●"),
-        ConnectionState::Connecting | ConnectionState::Reconnecting => (Color::Yellow, "◐"),
-        ConnectionState::Disconnected => (Color::Gray, "○"),
-        ConnectionState::Error(_) => (Color::Red, "✖"),
+        ConnectionState::Connected => (theme.success, "●"),
+        ConnectionState::Connecting | ConnectionState::Reconnecting => (theme.warn, "◐"),
+        ConnectionState::Disconnected => (theme.label, "○"),
+        ConnectionState::Stalled => (theme.error, "◌"),
+        ConnectionState::Error(_) => (theme.error, "✖"),
+    };
+
+    let status_text = match state.retry_countdown() {
+        Some(wait) if conn_state == ConnectionState::Connecting => {
+            format!("Waiting for proxy (retry in {}s)", wait.as_secs() + 1)
+        }
+        Some(wait) => format!("{} (retry in {}s)", conn_state, wait.as_secs() + 1),
+        None => conn_state.to_string(),
     };
 
     let uptime = format_duration(state.uptime());
@@ -91,55 +355,113 @@ fn draw_header(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
     
     let window_secs = state.metrics_window_secs();
     let entries_per_sec = state.metrics.get_entries_per_sec(window_secs);
-    let txns_per_sec = state.metrics.get_txns_per_sec(window_secs);
+    let txn_rate = state.txn_rate.rate(HEADER_RATE_SECS).map(|rate| state.rate_in_unit(rate));
+    let rate_suffix = state.rate_unit.read().suffix();
     
     // MEV metrics
     let dex_count = state.program_stats.dex_txn_count.load(Ordering::Relaxed);
     let bundles = state.competition_stats.bundle_count.load(Ordering::Relaxed);
     let avg_latency = state.latency_stats.avg_latency_ms();
     let turbine_avg = state.turbine_stats.avg_index();
+    let mev_index = state.mev_index.current();
+
+    let ui_lag = state.ui_lag_slots();
+    let health = state.network_health.score(state.reconnect_count.load(Ordering::Relaxed), state.uptime());
+    let health_color = if health >= HEALTH_GOOD_SCORE {
+        theme.success
+    } else if health >= HEALTH_FAIR_SCORE {
+        theme.warn
+    } else {
+        theme.error
+    };
 
-    let header_text = vec![
-        Span::styled("🔗 ShredStream MEV ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+    let mut header_text = vec![
+        Span::styled("🔗 ShredStream MEV ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
         Span::styled(status_icon, Style::default().fg(status_color)),
         Span::raw(" "),
-        Span::styled(format!("{}", conn_state), Style::default().fg(status_color)),
+        Span::styled(status_text, Style::default().fg(status_color)),
+        Span::styled(format!(" {}", state.proxy_url.read()), Style::default().fg(theme.dim)),
+    ];
+    if let Some(region) = state.region.read().as_ref() {
+        header_text.push(Span::styled(format!(" ({})", region), Style::default().fg(theme.dim)));
+    }
+    header_text.extend([
+        Span::raw(" │ "),
+        Span::styled(format!("Health:{:.0}", health), Style::default().fg(health_color).add_modifier(Modifier::BOLD)),
+        Span::raw(" │ "),
+        Span::styled("Slot: ", Style::default().fg(theme.label)),
+        Span::styled(format_number(current_slot, state.locale), Style::default().fg(theme.text).add_modifier(Modifier::BOLD)),
+    ]);
+    if ui_lag >= UI_LAG_WARN_SLOTS {
+        header_text.push(Span::styled(format!(" (UI lag: {} slots)", ui_lag), Style::default().fg(theme.warn)));
+    }
+    header_text.extend([
         Span::raw(" │ "),
-        Span::styled("Slot: ", Style::default().fg(Color::Gray)),
-        Span::styled(format_number(current_slot), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        Span::styled(
+            match txn_rate {
+                Some(rate) => format!("{:.0} txn{}", rate, rate_suffix),
+                None => format!("– txn{}", rate_suffix),
+            },
+            Style::default().fg(theme.highlight),
+        ),
         Span::raw(" │ "),
-        Span::styled(format!("{:.0} txn/s", txns_per_sec), Style::default().fg(Color::Magenta)),
+        Span::styled(format!("delay {:+.1}ms", avg_latency), Style::default().fg(theme.warn)),
         Span::raw(" │ "),
-        Span::styled(format!("{:.1}ms", avg_latency), Style::default().fg(Color::Yellow)),
+        Span::styled(format!("T:{:.0}", turbine_avg), Style::default().fg(theme.accent)),
         Span::raw(" │ "),
-        Span::styled(format!("T:{:.0}", turbine_avg), Style::default().fg(Color::Cyan)),
+        Span::styled(format!("DEX:{}", format_number(dex_count, state.locale)), Style::default().fg(theme.success)),
         Span::raw(" │ "),
-        Span::styled(format!("DEX:{}", format_number(dex_count)), Style::default().fg(Color::Green)),
+        Span::styled(
+            match mev_index {
+                Some(index) => format!("MEV:{}", index),
+                None => "MEV:–".to_string(),
+            },
+            Style::default().fg(theme.warn).add_modifier(Modifier::BOLD),
+        ),
         Span::raw(" │ "),
-        Span::styled(uptime, Style::default().fg(Color::DarkGray)),
-    ];
+        Span::styled(uptime, Style::default().fg(theme.dim)),
+    ]);
 
     let header = Paragraph::new(Line::from(header_text))
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
 
     f.render_widget(header, area);
 }
 
 fn draw_tabs(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let selected = *state.selected_tab.read();
     
     let titles: Vec<Line> = TAB_TITLES.iter().map(|t| Line::from(*t)).collect();
 
     let tabs = Tabs::new(titles)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)))
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.dim)))
         .select(selected)
-        .style(Style::default().fg(Color::Gray))
-        .highlight_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .style(Style::default().fg(theme.label))
+        .highlight_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
         .divider(symbols::line::VERTICAL);
 
     f.render_widget(tabs, area);
 }
 
+/// Tab under a click at (`column`, `row`), matching how `draw_tabs` lays the titles out:
+/// a border column, then each title padded by a space on both sides, separated by a divider
+pub fn tab_at(column: u16, row: u16) -> Option<usize> {
+    if !(HEADER_HEIGHT..HEADER_HEIGHT + TABS_HEIGHT).contains(&row) {
+        return None;
+    }
+
+    let mut start = 1;
+    for (index, title) in TAB_TITLES.iter().enumerate() {
+        let end = start + 1 + Line::from(*title).width() as u16 + 1;
+        if (start..end).contains(&column) {
+            return Some(index);
+        }
+        start = end + 1;
+    }
+    None
+}
+
 fn draw_content(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
     let selected = *state.selected_tab.read();
     
@@ -169,7 +491,7 @@ fn draw_overview_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
     let left_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(8),   // Connection + Core metrics
+            Constraint::Length(10),  // Connection + Core metrics
             Constraint::Length(10),  // MEV metrics
             Constraint::Min(5),      // Sparkline
         ])
@@ -179,176 +501,427 @@ fn draw_overview_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(10), // Network health
+            Constraint::Length(8),  // Coverage
             Constraint::Min(5),     // Recent slots
         ])
         .split(chunks[1]);
 
     draw_connection_metrics(f, state, left_chunks[0]);
     draw_mev_summary(f, state, left_chunks[1]);
-    draw_rate_sparkline(f, state, left_chunks[2]);
+    let volume_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(left_chunks[2]);
+    let sparkline_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(volume_chunks[0]);
+    draw_rate_sparkline(f, state, sparkline_chunks[0]);
+    draw_latency_sparkline(f, state, sparkline_chunks[1]);
+    draw_minute_volume(f, state, volume_chunks[1]);
     draw_network_health(f, state, right_chunks[0]);
-    draw_recent_slots(f, state, right_chunks[1]);
+    draw_coverage(f, state, right_chunks[1]);
+    draw_recent_slots(f, state, right_chunks[2]);
 }
 
 fn draw_connection_metrics(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let window_secs = state.metrics_window_secs();
     let metrics = &state.metrics;
+    let rate_suffix = state.rate_unit.read().suffix();
 
     let conn_duration = state.connection_duration()
         .map(format_duration)
         .unwrap_or_else(|| "N/A".to_string());
 
+    let mut trailing_rates = vec![Span::styled("Txn rate: ", Style::default().fg(theme.label))];
+    for (i, secs) in TRAILING_RATE_SECS.iter().enumerate() {
+        if i > 0 {
+            trailing_rates.push(Span::styled(" │ ", Style::default().fg(theme.dim)));
+        }
+        let rate = match state.txn_rate.rate(*secs) {
+            Some(rate) => format!("{:.0}", state.rate_in_unit(rate)),
+            None => "–".to_string(),
+        };
+        trailing_rates.push(Span::styled(format!("{}s ", secs), Style::default().fg(theme.dim)));
+        trailing_rates.push(Span::styled(rate, Style::default().fg(theme.highlight)));
+    }
+    let ema = match *state.ema_txns_per_sec.read() {
+        Some(ema) => format!("{:.0}", state.rate_in_unit(ema)),
+        None => "–".to_string(),
+    };
+    trailing_rates.push(Span::styled(" │ EMA ", Style::default().fg(theme.dim)));
+    trailing_rates.push(Span::styled(ema, Style::default().fg(theme.highlight)));
+    trailing_rates.push(Span::styled(rate_suffix, Style::default().fg(theme.dim)));
+    let trailing_rates = Line::from(trailing_rates);
+
+    let unique_programs = state.program_stats.activities.read().len();
+    let unique_leaders = state.leader_tracker.leader_stats.read().len();
+    let totals = state.overview_totals();
+    let decode_errors = totals.decode_errors;
+
     let text = vec![
         Line::from(vec![
-            Span::styled("Entries: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(metrics.total_entries.load(Ordering::Relaxed)), Style::default().fg(Color::Cyan)),
-            Span::styled(format!(" ({:.1}/s)", metrics.get_entries_per_sec(window_secs)), Style::default().fg(Color::DarkGray)),
+            Span::styled("Entries: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(totals.entries, state.locale), Style::default().fg(theme.accent)),
+            Span::styled(format!(" ({:.1}{})", state.rate_in_unit(metrics.get_entries_per_sec(window_secs)), rate_suffix), Style::default().fg(theme.dim)),
+        ]),
+        Line::from(vec![
+            Span::styled("Transactions: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(totals.txns, state.locale), Style::default().fg(theme.highlight)),
+            Span::styled(format!(" ({:.1}{})", state.rate_in_unit(metrics.get_txns_per_sec(window_secs)), rate_suffix), Style::default().fg(theme.dim)),
+        ]),
+        trailing_rates,
+        Line::from(vec![
+            Span::styled("Throughput: ", Style::default().fg(theme.label)),
+            Span::styled(
+                match state.byte_rate.rate(HEADER_RATE_SECS) {
+                    Some(rate) => format!("{}/s", format_bytes(rate)),
+                    None => "–".to_string(),
+                },
+                Style::default().fg(theme.accent),
+            ),
+            Span::styled(
+                format!(" ({} total)", format_bytes(totals.bytes as f64)),
+                Style::default().fg(theme.dim),
+            ),
         ]),
         Line::from(vec![
-            Span::styled("Transactions: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(metrics.total_txns.load(Ordering::Relaxed)), Style::default().fg(Color::Magenta)),
-            Span::styled(format!(" ({:.1}/s)", metrics.get_txns_per_sec(window_secs)), Style::default().fg(Color::DarkGray)),
+            Span::styled("Non-vote: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.1} txn{}", state.rate_in_unit(metrics.get_non_vote_txns_per_sec(window_secs)), rate_suffix), Style::default().fg(theme.highlight)),
+            Span::styled(format!(" ({} votes)", format_number(totals.vote_txns, state.locale)), Style::default().fg(theme.dim)),
         ]),
         Line::from(vec![
-            Span::styled("Connected: ", Style::default().fg(Color::Gray)),
-            Span::styled(conn_duration, Style::default().fg(Color::White)),
+            Span::styled("Connected: ", Style::default().fg(theme.label)),
+            Span::styled(conn_duration, Style::default().fg(theme.text)),
+            Span::styled(" │ ", Style::default().fg(theme.dim)),
+            Span::styled("Drift: ", Style::default().fg(theme.label)),
+            match *state.slot_drift_ms.read() {
+                Some(drift) => Span::styled(
+                    format!("{:+.1} ms/slot", drift),
+                    Style::default().fg(if drift.abs() < DRIFT_OK_MS {
+                        theme.success
+                    } else if drift.abs() < DRIFT_WARN_MS {
+                        theme.warn
+                    } else {
+                        theme.error
+                    }),
+                ),
+                None => Span::styled("–", Style::default().fg(theme.dim)),
+            },
         ]),
         Line::from(vec![
-            Span::styled("Reconnects: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(state.reconnect_count.load(Ordering::Relaxed)), Style::default().fg(Color::Yellow)),
+            Span::styled("Reconnects: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(state.reconnect_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.warn)),
+            Span::styled(" │ ", Style::default().fg(theme.dim)),
+            Span::styled("Decode errors: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(decode_errors, state.locale), Style::default().fg(if decode_errors > 0 { theme.error } else { theme.text })),
+        ]),
+        Line::from(vec![
+            Span::styled("Seen: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{} programs", format_number(unique_programs as u64, state.locale)), Style::default().fg(theme.success)),
+            Span::styled(" / ", Style::default().fg(theme.dim)),
+            Span::styled(format!("{} leaders", format_number(unique_leaders as u64, state.locale)), Style::default().fg(theme.accent)),
         ]),
     ];
 
+    let title = match *state.counter_scope.read() {
+        CounterScope::AllTime => " Core Metrics (c: all time) ",
+        CounterScope::Connection => " Core Metrics (c: since connect) ",
+    };
     let block = Block::default()
-        .title(" Core Metrics ")
+        .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     let paragraph = Paragraph::new(text).block(block);
     f.render_widget(paragraph, area);
 }
 
 fn draw_mev_summary(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let program_stats = &state.program_stats;
     let competition = &state.competition_stats;
     let latency = &state.latency_stats;
     let turbine = &state.turbine_stats;
 
     let text = vec![
-        Line::from(Span::styled("── DEX Activity ──", Style::default().fg(Color::Green))),
+        Line::from(Span::styled("── DEX Activity ──", Style::default().fg(theme.success))),
         Line::from(vec![
-            Span::styled("DEX Txns: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(program_stats.dex_txn_count.load(Ordering::Relaxed)), Style::default().fg(Color::Green)),
+            Span::styled("DEX Txns: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(program_stats.dex_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.success)),
         ]),
         Line::from(vec![
-            Span::styled("Lending: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(program_stats.lending_txn_count.load(Ordering::Relaxed)), Style::default().fg(Color::Blue)),
+            Span::styled("Lending: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(program_stats.lending_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(state.category_colors.color(ProgramCategory::Lending))),
         ]),
-        Line::from(Span::styled("── Competition ──", Style::default().fg(Color::Yellow))),
+        Line::from(Span::styled("── Competition ──", Style::default().fg(theme.warn))),
         Line::from(vec![
-            Span::styled("Bundles: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(competition.bundle_count.load(Ordering::Relaxed)), Style::default().fg(Color::Yellow)),
-            Span::styled(format!(" ({:.4} SOL tips)", competition.total_tips_sol()), Style::default().fg(Color::DarkGray)),
+            Span::styled("Bundles: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(competition.bundle_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.warn)),
+            Span::styled(format!(" ({:.4} SOL tips)", competition.total_tips_sol()), Style::default().fg(theme.dim)),
         ]),
         Line::from(vec![
-            Span::styled("Duplicates: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(competition.duplicate_count.load(Ordering::Relaxed)), Style::default().fg(Color::Red)),
+            Span::styled("Duplicates: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(competition.duplicate_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.error)),
         ]),
     ];
 
     let block = Block::default()
         .title(" MEV Summary ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     let paragraph = Paragraph::new(text).block(block);
     f.render_widget(paragraph, area);
 }
 
+/// Entries and txns per minute; the newest bar is the minute still in progress
+fn draw_minute_volume(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let buckets = state.minute_buckets.read();
+    // Newest bars that fit at 3 columns plus a 1-column gap
+    let bars = (area.width.saturating_sub(2) / 4) as usize;
+    let recent: Vec<_> = buckets.iter().skip(buckets.len().saturating_sub(bars)).collect();
+    let labels: Vec<String> = recent.iter().map(|(start, _, _)| start.format("%M").to_string()).collect();
+
+    let charts = [
+        (" Entries / min ", recent.iter().map(|(_, entries, _)| *entries).collect::<Vec<_>>(), theme.accent),
+        (" Txns / min ", recent.iter().map(|(_, _, txns)| *txns).collect::<Vec<_>>(), theme.highlight),
+    ];
+    for ((title, values, color), chunk) in charts.into_iter().zip(chunks.iter()) {
+        let data: Vec<(&str, u64)> = labels.iter().map(String::as_str).zip(values).collect();
+        let chart = BarChart::default()
+            .block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(theme.dim)))
+            .data(data.as_slice())
+            .bar_width(3)
+            .bar_gap(1)
+            .bar_style(Style::default().fg(color))
+            .value_style(Style::default().fg(theme.bg).bg(color))
+            .label_style(Style::default().fg(theme.label));
+        f.render_widget(chart, *chunk);
+    }
+}
+
 fn draw_rate_sparkline(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
-    let slot_history = state.slot_history.read();
-    let data: Vec<u64> = slot_history.iter().map(|s| s.txn_count).collect();
+    let theme = &state.theme;
+    let live = state.txn_rate_history.read();
+    let paused = state.paused.read();
+    let rate_history = paused.as_ref().map_or(&*live, |f| &f.txn_rate_history);
+    // Show the newest samples that fit in the panel
+    let width = area.width.saturating_sub(2) as usize;
+    let skip = rate_history.len().saturating_sub(width);
+    let data: Vec<u64> = rate_history.iter().skip(skip).copied().collect();
 
     let block = Block::default()
         .title(" Transaction Rate ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     let sparkline = Sparkline::default()
         .block(block)
         .data(&data)
-        .style(Style::default().fg(Color::Magenta));
+        .style(Style::default().fg(theme.highlight));
+
+    f.render_widget(sparkline, area);
+}
+
+fn draw_latency_sparkline(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let live = state.latency_stats.slot_history.read();
+    let paused = state.paused.read();
+    let history = paused.as_ref().map_or(&*live, |f| &f.slot_latency);
+    let width = area.width.saturating_sub(2) as usize;
+    let skip = history.len().saturating_sub(width);
+    let delays: Vec<i64> = history.iter().skip(skip).map(|s| s.avg_us()).collect();
+    // Bars can't go below zero, so early arrivals lower the baseline instead
+    let floor = delays.iter().copied().min().unwrap_or(0).min(0);
+    let data: Vec<u64> = delays.iter().map(|&us| (us - floor) as u64).collect();
+
+    let title = match delays.last() {
+        Some(&last_us) => format!(" Slot Arrival Delay ({:+.2} ms) ", last_us as f64 / 1000.0),
+        None => " Slot Arrival Delay ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
+
+    let sparkline = Sparkline::default()
+        .block(block)
+        .data(&data)
+        .style(Style::default().fg(theme.warn));
 
     f.render_widget(sparkline, area);
 }
 
 fn draw_network_health(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let health = &state.network_health;
     let latency = &state.latency_stats;
     let turbine = &state.turbine_stats;
 
     let fec_rate = health.fec_recovery_rate();
     let hb_rate = health.heartbeat_success_rate();
+    let heartbeats = health.heartbeat_success.load(Ordering::Relaxed) + health.heartbeat_fail.load(Ordering::Relaxed);
+    let rpc_health = *state.rpc_health.read();
+    let out_of_order = health.out_of_order_count.load(Ordering::Relaxed);
+    let missed = health.missed_slot_count.load(Ordering::Relaxed);
+    let outage_missed = health.outage_missed_slots.load(Ordering::Relaxed);
 
     let text = vec![
         Line::from(vec![
-            Span::styled("Avg Latency: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.2} ms", latency.avg_latency_ms()), Style::default().fg(Color::Yellow)),
+            Span::styled("Arrival Delay: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:+.2} ms", latency.avg_latency_ms()), Style::default().fg(theme.warn)),
+        ]),
+        Line::from(vec![
+            Span::styled("Min/Max: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:+.2}/{:+.2} ms", latency.min_latency_ms(), latency.max_latency_ms()), Style::default().fg(theme.dim)),
+        ]),
+        Line::from(vec![
+            Span::styled("Turbine Idx: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.1} avg", turbine.avg_index()), Style::default().fg(theme.accent)),
+            Span::styled(format!(" ({}–{})", turbine.min_index(), turbine.max_index()), Style::default().fg(theme.dim)),
+        ]),
+        Line::from(vec![
+            Span::styled("FEC Recovery: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.1}%", fec_rate), Style::default().fg(if fec_rate < 10.0 { theme.success } else { theme.warn })),
         ]),
         Line::from(vec![
-            Span::styled("Min/Max: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.2}/{:.2} ms", latency.min_latency_ms(), latency.max_latency_ms()), Style::default().fg(Color::DarkGray)),
+            Span::styled("Heartbeat: ", Style::default().fg(theme.label)),
+            if heartbeats == 0 {
+                Span::styled("–", Style::default().fg(theme.dim))
+            } else {
+                Span::styled(format!("{:.1}%", hb_rate), Style::default().fg(if hb_rate > 95.0 { theme.success } else { theme.error }))
+            },
         ]),
         Line::from(vec![
-            Span::styled("Turbine Idx: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.1} avg", turbine.avg_index()), Style::default().fg(Color::Cyan)),
-            Span::styled(format!(" ({}–{})", turbine.min_index(), turbine.max_index()), Style::default().fg(Color::DarkGray)),
+            Span::styled("Out of Order: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(out_of_order, state.locale), Style::default().fg(if out_of_order == 0 { theme.success } else { theme.warn })),
         ]),
         Line::from(vec![
-            Span::styled("FEC Recovery: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.1}%", fec_rate), Style::default().fg(if fec_rate < 10.0 { Color::Green } else { Color::Yellow })),
+            Span::styled("Missed: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(missed, state.locale), Style::default().fg(match missed {
+                0 => theme.success,
+                n if n > MISSED_SLOTS_ALERT => theme.error,
+                _ => theme.warn,
+            })),
+            Span::styled(format!(" ({} in outages)", format_number(outage_missed, state.locale)), Style::default().fg(theme.dim)),
         ]),
         Line::from(vec![
-            Span::styled("Heartbeat: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.1}%", hb_rate), Style::default().fg(if hb_rate > 95.0 { Color::Green } else { Color::Red })),
+            Span::styled("RPC: ", Style::default().fg(theme.label)),
+            Span::styled(rpc_health.to_string(), Style::default().fg(match rpc_health {
+                RpcHealth::Disabled => theme.dim,
+                RpcHealth::Healthy => theme.success,
+                RpcHealth::Degraded(_) => theme.warn,
+                RpcHealth::CircuitOpen => theme.error,
+            })),
         ]),
     ];
 
     let block = Block::default()
         .title(" Network Health ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     let paragraph = Paragraph::new(text).block(block);
     f.render_widget(paragraph, area);
 }
 
+/// How many of the transactions seen were analyzed, skipped or sampled, since start or the last reset
+fn draw_coverage(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let coverage = &state.coverage;
+    let unsigned = coverage.unsigned.load(Ordering::Relaxed);
+    let dropped = state.metrics.deserialize_failures.load(Ordering::Relaxed);
+
+    let analyzed_pct = match coverage.analyzed_pct() {
+        Some(pct) => Span::styled(format!(" ({:.1}%)", pct), Style::default().fg(if pct >= 99.9 { theme.success } else { theme.warn })),
+        None => Span::styled(" (–)", Style::default().fg(theme.dim)),
+    };
+    let text = vec![
+        Line::from(vec![
+            Span::styled("Seen: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(coverage.seen.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
+        ]),
+        Line::from(vec![
+            Span::styled("Analyzed: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(coverage.analyzed(), state.locale), Style::default().fg(theme.text)),
+            analyzed_pct,
+        ]),
+        Line::from(vec![
+            Span::styled("Votes: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(coverage.votes.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.dim)),
+            Span::styled("  No signature: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(unsigned, state.locale), Style::default().fg(if unsigned > 0 { theme.warn } else { theme.text })),
+        ]),
+        Line::from(vec![
+            Span::styled("Sampled: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(coverage.sampled.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.accent)),
+        ]),
+        Line::from(vec![
+            Span::styled("Undecodable payloads: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(dropped, state.locale), Style::default().fg(if dropped > 0 { theme.error } else { theme.text })),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title(" Coverage ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
+
+    f.render_widget(Paragraph::new(text).block(block), area);
+}
+
 fn draw_recent_slots(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
-    let slot_history = state.slot_history.read();
+    let theme = &state.theme;
+    let live = state.slot_history.read();
+    let paused = state.paused.read();
+    let slot_history = paused.as_ref().map_or(&*live, |f| &f.slot_history);
     
     let items: Vec<ListItem> = slot_history.iter()
         .rev()
+        .filter(|slot| state.shows_slot(slot))
         .take(15)
         .map(|slot| {
             let mut spans = vec![
-                Span::styled(format!("{}", slot.slot), Style::default().fg(Color::White)),
+                Span::styled(format!("{}", slot.slot), Style::default().fg(theme.text)),
                 Span::raw(" │ "),
-                Span::styled(format!("{} ent", slot.entry_count), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{} ent", slot.entry_count), Style::default().fg(theme.accent)),
                 Span::raw(", "),
-                Span::styled(format!("{} txn", slot.txn_count), Style::default().fg(Color::Magenta)),
+                Span::styled(format!("{} txn", slot.txn_count), Style::default().fg(theme.highlight)),
             ];
             if slot.dex_txn_count > 0 {
                 spans.push(Span::raw(" │ "));
-                spans.push(Span::styled(format!("{} dex", slot.dex_txn_count), Style::default().fg(Color::Green)));
+                spans.push(Span::styled(format!("{} dex", slot.dex_txn_count), Style::default().fg(theme.success)));
+            }
+            spans.push(Span::raw(" │ "));
+            spans.push(match &slot.leader {
+                // Validators named in --validators-file are the ones the user is watching
+                Some(leader) if state.leader_tracker.validator_names.read().contains_key(leader) => {
+                    Span::styled(leader_label(state, leader), Style::default().fg(theme.warn).add_modifier(Modifier::BOLD))
+                }
+                Some(leader) => Span::styled(leader_label(state, leader), Style::default().fg(theme.label)),
+                None => Span::styled("leader ?", Style::default().fg(theme.dim)),
+            });
+            if !slot.complete {
+                spans.push(Span::raw(" │ "));
+                spans.push(Span::styled("partial", Style::default().fg(theme.warn)));
             }
             ListItem::new(Line::from(spans))
         })
         .collect();
 
+    let title = if *state.dex_only.read() { " Recent Slots (DEX only) " } else { " Recent Slots " };
     let block = Block::default()
-        .title(" Recent Slots ")
+        .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     let list = List::new(items).block(block);
     f.render_widget(list, area);
@@ -359,6 +932,7 @@ fn draw_recent_slots(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 // ============================================================================
 
 fn draw_latency_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -366,81 +940,109 @@ fn draw_latency_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 
     let left_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(12), Constraint::Min(5)])
+        .constraints([Constraint::Length(12), Constraint::Length(6), Constraint::Min(5)])
         .split(chunks[0]);
 
-    // Global latency stats
+    // Slot arrival delay across all samples
     let latency = &state.latency_stats;
-    let stats_text = vec![
-        Line::from(Span::styled("── Global Latency ──", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+    let mut stats_text = vec![
+        Line::from(Span::styled("── Slot Arrival Delay ──", Style::default().fg(theme.warn).add_modifier(Modifier::BOLD))),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Average: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.2} ms", latency.avg_latency_ms()), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled("Average: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:+.2} ms", latency.avg_latency_ms()), Style::default().fg(theme.warn).add_modifier(Modifier::BOLD)),
         ]),
         Line::from(vec![
-            Span::styled("Minimum: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.2} ms", latency.min_latency_ms()), Style::default().fg(Color::Green)),
+            Span::styled("Minimum: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:+.2} ms", latency.min_latency_ms()), Style::default().fg(theme.success)),
         ]),
         Line::from(vec![
-            Span::styled("Maximum: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.2} ms", latency.max_latency_ms()), Style::default().fg(Color::Red)),
+            Span::styled("Maximum: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:+.2} ms", latency.max_latency_ms()), Style::default().fg(theme.error)),
         ]),
         Line::from(vec![
-            Span::styled("Samples: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(latency.sample_count.load(Ordering::Relaxed)), Style::default().fg(Color::White)),
+            Span::styled("Jitter:  ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.2} ms", latency.stddev_ms()), Style::default().fg(theme.text)),
+        ]),
+        Line::from(vec![
+            Span::styled("Samples: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(latency.sample_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
         ]),
     ];
 
+    if let Some(target_us) = latency.sla_target_us {
+        let (sla_text, sla_color) = match latency.sla_met_pct() {
+            Some(pct) if pct >= 99.0 => (format!("{:.1}%", pct), theme.success),
+            Some(pct) if pct >= 95.0 => (format!("{:.1}%", pct), theme.warn),
+            Some(pct) => (format!("{:.1}%", pct), theme.error),
+            None => ("N/A".to_string(), theme.dim),
+        };
+        stats_text.push(Line::from(vec![
+            Span::styled("SLA met: ", Style::default().fg(theme.label)),
+            Span::styled(sla_text, Style::default().fg(sla_color).add_modifier(Modifier::BOLD)),
+            Span::styled(format!(" (delay ≤ {:.1} ms)", target_us as f64 / 1000.0), Style::default().fg(theme.dim)),
+        ]));
+    }
+
     let stats_block = Block::default()
-        .title(" Latency Statistics ")
+        .title(" Slot Arrival Delay ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     f.render_widget(Paragraph::new(stats_text).block(stats_block), left_chunks[0]);
 
-    // Region latencies
-    let region_stats = latency.region_latencies.read();
-    let mut regions: Vec<_> = region_stats.values().collect();
-    regions.sort_by(|a, b| a.avg_latency_ms().partial_cmp(&b.avg_latency_ms()).unwrap());
+    draw_latency_budget(f, state, left_chunks[1]);
+
+    // Arrival delay by region
+    let regions = latency.regions_by_latency();
 
+    let selected_region = state.latency_region.read().clone();
     let region_items: Vec<ListItem> = regions.iter().map(|r| {
+        let marker = if selected_region.as_ref() == Some(&r.region) { "▸ " } else { "  " };
         ListItem::new(Line::from(vec![
-            Span::styled(&r.region, Style::default().fg(Color::Cyan)),
+            Span::styled(marker, Style::default().fg(theme.highlight)),
+            Span::styled(&r.region, Style::default().fg(theme.accent)),
             Span::raw(": "),
-            Span::styled(format!("{:.2} ms avg", r.avg_latency_ms()), Style::default().fg(Color::Yellow)),
-            Span::styled(format!(" ({} samples)", r.sample_count), Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{:+.2} ms avg", r.avg_latency_ms()), Style::default().fg(theme.warn)),
+            Span::styled(format!(" ({} samples)", r.sample_count), Style::default().fg(theme.dim)),
         ]))
     }).collect();
 
     let region_block = Block::default()
-        .title(" By Region ")
+        .title(" Arrival Delay by Region ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
-    f.render_widget(List::new(region_items).block(region_block), left_chunks[1]);
+    f.render_widget(List::new(region_items).block(region_block), left_chunks[2]);
 
-    // Leader latencies
-    let leader_stats = latency.leader_latencies.read();
-    let mut leaders: Vec<_> = leader_stats.values().collect();
-    leaders.sort_by(|a, b| a.avg_latency_ms().partial_cmp(&b.avg_latency_ms()).unwrap());
+    // Arrival delay by leader
+    let leaders = leaders_by_latency(state);
 
+    let sort = *state.leader_sort.read();
+    let sorted_by = |column: &str, by: LeaderSort, arrow: &str| {
+        if sort == by { format!("{} {}", column, arrow) } else { column.to_string() }
+    };
     let header = Row::new(vec![
-        Cell::from("Leader").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Avg").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Min").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Max").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Count").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Cell::from("Leader").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from(sorted_by("Avg", LeaderSort::Avg, "▲")).style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Min").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from(sorted_by("Max", LeaderSort::Max, "▲")).style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from(sorted_by("Count", LeaderSort::Samples, "▼")).style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
     ]);
 
-    let rows: Vec<Row> = leaders.iter().take(20).map(|l| {
+    let leader_title = match &selected_region {
+        Some(region) => format!(" Arrival Delay by Leader in {} (o: sort, f: region) ", region),
+        None => " Arrival Delay by Leader (o: sort, f: region) ".to_string(),
+    };
+
+    let rows: Vec<Row> = leaders.iter().enumerate().map(|(i, l)| {
         Row::new(vec![
-            Cell::from(truncate_pubkey(&l.leader.to_string())).style(Style::default().fg(Color::White)),
-            Cell::from(format!("{:.2}ms", l.avg_latency_ms())).style(Style::default().fg(Color::Yellow)),
-            Cell::from(format!("{:.2}ms", l.min_latency_us as f64 / 1000.0)).style(Style::default().fg(Color::Green)),
-            Cell::from(format!("{:.2}ms", l.max_latency_us as f64 / 1000.0)).style(Style::default().fg(Color::Red)),
-            Cell::from(format!("{}", l.sample_count)).style(Style::default().fg(Color::Gray)),
-        ])
+            Cell::from(truncate_pubkey(&l.leader.to_string())).style(Style::default().fg(theme.text)),
+            Cell::from(format!("{:+.2}ms", l.avg_latency_ms())).style(Style::default().fg(theme.warn)),
+            Cell::from(format!("{:+.2}ms", l.min_latency_us as f64 / 1000.0)).style(Style::default().fg(theme.success)),
+            Cell::from(format!("{:+.2}ms", l.max_latency_us as f64 / 1000.0)).style(Style::default().fg(theme.error)),
+            Cell::from(format!("{}", l.sample_count)).style(Style::default().fg(theme.label)),
+        ]).style(row_style(state, i, leaders.len()))
     }).collect();
 
     let table = Table::new(rows, [
@@ -451,9 +1053,87 @@ fn draw_latency_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
         Constraint::Length(8),
     ])
     .header(header)
-    .block(Block::default().title(" By Leader ").borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+    .block(Block::default().title(leader_title).borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
+
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(10), Constraint::Min(5)])
+        .split(chunks[1]);
 
-    f.render_widget(table, chunks[1]);
+    draw_latency_histogram(f, state, right_chunks[0]);
+    state.set_page_rows(table_page_rows(right_chunks[1]));
+    f.render_widget(table, right_chunks[1]);
+}
+
+fn draw_latency_histogram(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let histogram = state.latency_stats.histogram();
+    let data: Vec<(&str, u64)> = histogram.iter().map(|(label, count)| (label.as_str(), *count)).collect();
+
+    // Spread the bars across the panel, leaving a one-column gap between them
+    let inner_width = area.width.saturating_sub(2);
+    let bar_width = (inner_width / data.len() as u16).saturating_sub(1).max(1);
+
+    let chart = BarChart::default()
+        .block(Block::default().title(" Arrival Delay Distribution (ms) ").borders(Borders::ALL).border_style(Style::default().fg(theme.dim)))
+        .data(data.as_slice())
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(theme.warn))
+        .value_style(Style::default().fg(theme.bg).bg(theme.warn))
+        .label_style(Style::default().fg(theme.label));
+
+    f.render_widget(chart, area);
+}
+
+/// Stacked bar of where tool-side time goes per message
+fn draw_latency_budget(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let budget = &state.latency_stats.budget;
+    let components = [
+        ("Decode", budget.avg_decode_ms(), theme.accent),
+        ("Process", budget.avg_process_ms(), theme.highlight),
+        ("UI queue", budget.avg_queue_ms(), theme.warn),
+    ];
+    let total: f64 = components.iter().map(|(_, ms, _)| ms).sum();
+
+    let width = area.width.saturating_sub(2) as usize;
+    let bar: Vec<Span> = if total > 0.0 {
+        components.iter()
+            .map(|(_, ms, color)| {
+                let cells = ((ms / total) * width as f64).round() as usize;
+                Span::styled("█".repeat(cells), Style::default().fg(*color))
+            })
+            .collect()
+    } else {
+        vec![Span::styled("░".repeat(width), Style::default().fg(theme.dim))]
+    };
+
+    let mut legend = Vec::new();
+    for (label, ms, color) in components {
+        legend.push(Span::styled("■ ", Style::default().fg(color)));
+        legend.push(Span::styled(format!("{} {:.2} ms  ", label, ms), Style::default().fg(theme.label)));
+    }
+
+    let text = vec![
+        Line::from(bar),
+        Line::from(legend),
+        Line::from(vec![
+            Span::styled("Network: ", Style::default().fg(theme.label)),
+            Span::styled("not measurable from the proxy API", Style::default().fg(theme.dim)),
+        ]),
+        Line::from(vec![
+            Span::styled("Tool total: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.2} ms", total), Style::default().fg(theme.text)),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title(" Latency Budget ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
+
+    f.render_widget(Paragraph::new(text).block(block), area);
 }
 
 // ============================================================================
@@ -461,6 +1141,7 @@ fn draw_latency_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 // ============================================================================
 
 fn draw_turbine_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(14), Constraint::Min(5)])
@@ -480,71 +1161,73 @@ fn draw_turbine_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
     let layer3_pct = if total > 0.0 { (layer3 as f64 / total) * 100.0 } else { 0.0 };
 
     let text = vec![
-        Line::from(Span::styled("── Turbine Tree Position ──", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("── Turbine Tree Position ──", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Average Index: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.1}", turbine.avg_index()), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-            Span::styled(" (lower = earlier in propagation)", Style::default().fg(Color::DarkGray)),
+            Span::styled("Average Index: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.1}", turbine.avg_index()), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+            Span::styled(" (lower = earlier in propagation)", Style::default().fg(theme.dim)),
         ]),
         Line::from(vec![
-            Span::styled("Range: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{} – {}", turbine.min_index(), turbine.max_index()), Style::default().fg(Color::White)),
+            Span::styled("Range: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{} – {}", turbine.min_index(), turbine.max_index()), Style::default().fg(theme.text)),
         ]),
         Line::from(vec![
-            Span::styled("Samples: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(turbine.total_samples.load(Ordering::Relaxed)), Style::default().fg(Color::White)),
+            Span::styled("Samples: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(turbine.total_samples.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
         ]),
         Line::from(""),
-        Line::from(Span::styled("── Layer Distribution ──", Style::default().fg(Color::Yellow))),
+        Line::from(Span::styled("── Layer Distribution ──", Style::default().fg(theme.warn))),
         Line::from(vec![
-            Span::styled("Layer 0 (Root): ", Style::default().fg(Color::Green)),
-            Span::styled(format!("{} ({:.1}%)", format_number(layer0), layer0_pct), Style::default().fg(Color::White)),
+            Span::styled("Layer 0 (Root): ", Style::default().fg(theme.success)),
+            Span::styled(format!("{} ({:.1}%)", format_number(layer0, state.locale), layer0_pct), Style::default().fg(theme.text)),
         ]),
         Line::from(vec![
-            Span::styled("Layer 1: ", Style::default().fg(Color::Cyan)),
-            Span::styled(format!("{} ({:.1}%)", format_number(layer1), layer1_pct), Style::default().fg(Color::White)),
+            Span::styled("Layer 1: ", Style::default().fg(theme.accent)),
+            Span::styled(format!("{} ({:.1}%)", format_number(layer1, state.locale), layer1_pct), Style::default().fg(theme.text)),
         ]),
         Line::from(vec![
-            Span::styled("Layer 2: ", Style::default().fg(Color::Yellow)),
-            Span::styled(format!("{} ({:.1}%)", format_number(layer2), layer2_pct), Style::default().fg(Color::White)),
+            Span::styled("Layer 2: ", Style::default().fg(theme.warn)),
+            Span::styled(format!("{} ({:.1}%)", format_number(layer2, state.locale), layer2_pct), Style::default().fg(theme.text)),
         ]),
         Line::from(vec![
-            Span::styled("Layer 3+: ", Style::default().fg(Color::Red)),
-            Span::styled(format!("{} ({:.1}%)", format_number(layer3), layer3_pct), Style::default().fg(Color::White)),
+            Span::styled("Layer 3+: ", Style::default().fg(theme.error)),
+            Span::styled(format!("{} ({:.1}%)", format_number(layer3, state.locale), layer3_pct), Style::default().fg(theme.text)),
         ]),
     ];
 
     let block = Block::default()
         .title(" Turbine Tree Analysis ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     f.render_widget(Paragraph::new(text).block(block), chunks[0]);
 
     // Recent samples
-    let samples = turbine.samples.read();
+    let live = turbine.samples.read();
+    let paused = state.paused.read();
+    let samples = paused.as_ref().map_or(&*live, |f| &f.turbine_samples);
     let items: Vec<ListItem> = samples.iter().rev().take(20).map(|s| {
         ListItem::new(Line::from(vec![
-            Span::styled(format!("Slot {}", s.slot), Style::default().fg(Color::White)),
+            Span::styled(format!("Slot {}", s.slot), Style::default().fg(theme.text)),
             Span::raw(" │ "),
-            Span::styled(format!("idx:{}", s.turbine_index), Style::default().fg(Color::Cyan)),
+            Span::styled(format!("idx:{}", s.turbine_index), Style::default().fg(theme.accent)),
             Span::raw(" │ "),
             Span::styled(format!("layer:{}", s.layer), Style::default().fg(match s.layer {
-                0 => Color::Green,
-                1 => Color::Cyan,
-                2 => Color::Yellow,
-                _ => Color::Red,
+                0 => theme.success,
+                1 => theme.accent,
+                2 => theme.warn,
+                _ => theme.error,
             })),
             Span::raw(" │ "),
-            Span::styled(s.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(Color::DarkGray)),
+            Span::styled(s.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(theme.dim)),
         ]))
     }).collect();
 
     let samples_block = Block::default()
         .title(" Recent Samples ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
     f.render_widget(List::new(items).block(samples_block), chunks[1]);
 }
@@ -554,35 +1237,31 @@ fn draw_turbine_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 // ============================================================================
 
 fn draw_programs_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
     // Top programs table
-    let programs = state.program_stats.get_top_programs(30);
+    let programs = state.program_stats.get_top_programs(TOP_PROGRAM_ROWS);
     
     let header = Row::new(vec![
-        Cell::from("Program").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Category").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Txns").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Last Seen").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Cell::from("Program").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Category").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Txns").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Last Seen").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
     ]);
 
-    let rows: Vec<Row> = programs.iter().map(|p| {
-        let cat_color = match p.category {
-            ProgramCategory::Dex => Color::Green,
-            ProgramCategory::Lending => Color::Blue,
-            ProgramCategory::Mev => Color::Yellow,
-            ProgramCategory::Staking => Color::Magenta,
-            _ => Color::Gray,
-        };
+    let colors = &state.category_colors;
+    let rows: Vec<Row> = programs.iter().enumerate().map(|(i, p)| {
+        let cat_color = colors.color(p.category);
         Row::new(vec![
-            Cell::from(p.name.clone()).style(Style::default().fg(Color::White)),
+            Cell::from(p.name.clone()).style(Style::default().fg(theme.text)),
             Cell::from(format!("{}", p.category)).style(Style::default().fg(cat_color)),
-            Cell::from(format_number(p.txn_count)).style(Style::default().fg(Color::Cyan)),
-            Cell::from(p.last_seen.format("%H:%M:%S").to_string()).style(Style::default().fg(Color::DarkGray)),
-        ])
+            Cell::from(format_number(p.txn_count, state.locale)).style(Style::default().fg(theme.accent)),
+            Cell::from(p.last_seen.format("%H:%M:%S").to_string()).style(Style::default().fg(theme.dim)),
+        ]).style(row_style(state, i, programs.len()))
     }).collect();
 
     let table = Table::new(rows, [
@@ -592,39 +1271,163 @@ fn draw_programs_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
         Constraint::Length(10),
     ])
     .header(header)
-    .block(Block::default().title(" Top Programs ").borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+    .block(Block::default().title(" Top Programs ").borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
 
-    f.render_widget(table, chunks[0]);
+    let left = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[0]);
+
+    state.set_page_rows(table_page_rows(left[0]));
+    f.render_widget(table, left[0]);
+
+    // Sampled transactions, newest first
+    let live = state.txn_samples.read();
+    let paused = state.paused.read();
+    let samples = paused.as_ref().map_or(&*live, |f| &f.txn_samples);
+    let items: Vec<ListItem> = samples.iter().rev().filter(|s| state.shows_txn_sample(s)).take(RECENT_TXN_SAMPLE_ROWS)
+        .map(|sample| ListItem::new(format_txn_sample(sample, state)))
+        .collect();
+
+    let mut title = " Recent Transactions".to_string();
+    if *state.dex_only.read() {
+        title.push_str(" (DEX only)");
+    }
+    if let Some(prefix) = state.signature_search.read().as_deref() {
+        title.push_str(&format!(" matching {}… (/: search)", prefix));
+    }
+    title.push(' ');
+    let samples_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
+
+    f.render_widget(List::new(items).block(samples_block), left[1]);
 
     // Category summary
     let ps = &state.program_stats;
+    let rate_suffix = state.rate_unit.read().suffix();
+    let category_rate = |rate: &RateTracker| {
+        let rate = match rate.rate(CATEGORY_RATE_SECS) {
+            Some(rate) => format!("{:.1}", state.rate_in_unit(rate)),
+            None => "–".to_string(),
+        };
+        Span::styled(format!(" ({}{})", rate, rate_suffix), Style::default().fg(theme.dim))
+    };
     let text = vec![
-        Line::from(Span::styled("── By Category ──", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("── By Category ──", Style::default().fg(theme.warn).add_modifier(Modifier::BOLD))),
         Line::from(""),
         Line::from(vec![
-            Span::styled("🔄 DEX: ", Style::default().fg(Color::Green)),
-            Span::styled(format_number(ps.dex_txn_count.load(Ordering::Relaxed)), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            Span::styled("🔄 DEX: ", Style::default().fg(colors.color(ProgramCategory::Dex))),
+            Span::styled(format_number(ps.dex_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text).add_modifier(Modifier::BOLD)),
+            category_rate(&ps.dex_rate),
         ]),
         Line::from(vec![
-            Span::styled("🏦 Lending: ", Style::default().fg(Color::Blue)),
-            Span::styled(format_number(ps.lending_txn_count.load(Ordering::Relaxed)), Style::default().fg(Color::White)),
+            Span::styled("   Routed / Direct: ", Style::default().fg(theme.label)),
+            Span::styled(
+                format!(
+                    "{} / {}",
+                    format_number(ps.routed_swap_count.load(Ordering::Relaxed), state.locale),
+                    format_number(ps.direct_swap_count.load(Ordering::Relaxed), state.locale),
+                ),
+                Style::default().fg(theme.text),
+            ),
         ]),
         Line::from(vec![
-            Span::styled("⚡ MEV: ", Style::default().fg(Color::Yellow)),
-            Span::styled(format_number(ps.mev_txn_count.load(Ordering::Relaxed)), Style::default().fg(Color::White)),
+            Span::styled("🏦 Lending: ", Style::default().fg(colors.color(ProgramCategory::Lending))),
+            Span::styled(format_number(ps.lending_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
+            category_rate(&ps.lending_rate),
         ]),
         Line::from(vec![
-            Span::styled("🥩 Staking: ", Style::default().fg(Color::Magenta)),
-            Span::styled(format_number(ps.staking_txn_count.load(Ordering::Relaxed)), Style::default().fg(Color::White)),
+            Span::styled("⚡ MEV: ", Style::default().fg(colors.color(ProgramCategory::Mev))),
+            Span::styled(format_number(ps.mev_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
+            category_rate(&ps.mev_rate),
+        ]),
+        Line::from(vec![
+            Span::styled("🥩 Staking: ", Style::default().fg(colors.color(ProgramCategory::Staking))),
+            Span::styled(format_number(ps.staking_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
+            category_rate(&ps.staking_rate),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("💧 wSOL wrap/unwrap: ", Style::default().fg(theme.accent)),
+            Span::styled(
+                format!(
+                    "{} / {}",
+                    format_number(ps.wsol_wrap_count.load(Ordering::Relaxed), state.locale),
+                    format_number(ps.wsol_unwrap_count.load(Ordering::Relaxed), state.locale),
+                ),
+                Style::default().fg(theme.text),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("🔗 LUT txns: ", Style::default().fg(theme.dim)),
+            Span::styled(format_number(ps.lut_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
+            Span::styled(
+                format!(" ({} unresolved accts)", format_number(ps.lut_unresolved_accounts.load(Ordering::Relaxed), state.locale)),
+                Style::default().fg(theme.dim),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("🧪 Probe txns (est.): ", Style::default().fg(theme.dim)),
+            Span::styled(format_number(ps.probe_txn_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.text)),
         ]),
     ];
 
     let block = Block::default()
         .title(" Category Breakdown ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(13), Constraint::Min(3)])
+        .split(chunks[1]);
 
-    f.render_widget(Paragraph::new(text).block(block), chunks[1]);
+    f.render_widget(Paragraph::new(text).block(block), right[0]);
+
+    // Mints referenced by DEX transactions
+    let header = Row::new(vec![
+        Cell::from("Mint").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("DEX Txns").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+    ]);
+
+    let rows: Vec<Row> = ps.get_top_mints(TOP_MINT_ROWS).into_iter().map(|(mint, count)| {
+        Row::new(vec![
+            Cell::from(truncate_pubkey(&mint.to_string())).style(Style::default().fg(theme.text)),
+            Cell::from(format_number(count, state.locale)).style(Style::default().fg(colors.color(ProgramCategory::Dex))),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [Constraint::Length(14), Constraint::Length(12)])
+        .header(header)
+        .block(Block::default().title(" Top Mints ").borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(right[1]);
+
+    f.render_widget(table, bottom[0]);
+
+    // Writable accounts by transaction count: pools, oracles and other contended state
+    let header = Row::new(vec![
+        Cell::from("Account").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Writes").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+    ]);
+
+    let rows: Vec<Row> = state.account_stats.top_accounts(HOT_ACCOUNT_ROWS).into_iter().map(|(account, count)| {
+        Row::new(vec![
+            Cell::from(truncate_pubkey(&account.to_string())).style(Style::default().fg(theme.text)),
+            Cell::from(format_number(count, state.locale)).style(Style::default().fg(theme.warn)),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [Constraint::Length(14), Constraint::Length(12)])
+        .header(header)
+        .block(Block::default().title(" Hot Accounts ").borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
+
+    f.render_widget(table, bottom[1]);
 }
 
 // ============================================================================
@@ -632,103 +1435,308 @@ fn draw_programs_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 // ============================================================================
 
 fn draw_leaders_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
-    let leaders = state.leader_tracker.get_top_leaders(30);
+    let theme = &state.theme;
+    let tracker = &state.leader_tracker;
+    let leaders = tracker.get_top_leaders(TOP_LEADER_ROWS);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(OBSERVED_SLOT_ROWS as u16 + 2)])
+        .split(area);
     
     let header = Row::new(vec![
-        Cell::from("Leader").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Slots").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Skip %").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Total Txns").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Cell::from("Avg Latency").style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Cell::from("Leader").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Slots").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Skip %").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Total Txns").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Last Slot").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Avg Latency").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("1st Shred").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Stake (SOL)").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Pctl").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
     ]);
 
-    let rows: Vec<Row> = leaders.iter().map(|l| {
-        let skip_color = if l.skip_rate() < 5.0 { Color::Green } 
-            else if l.skip_rate() < 15.0 { Color::Yellow } 
-            else { Color::Red };
+    let rows: Vec<Row> = leaders.iter().enumerate().map(|(i, l)| {
+        let stake = tracker.stake_of(&l.leader)
+            .map(|lamports| format_number(lamports / 1_000_000_000, state.locale))
+            .unwrap_or_else(|| "-".to_string());
+        let percentile = tracker.stake_percentile(&l.leader)
+            .map(|p| format!("p{:.0}", p))
+            .unwrap_or_else(|| "-".to_string());
+
+        let skip_color = if l.skip_rate() < 5.0 { theme.success } 
+            else if l.skip_rate() < 15.0 { theme.warn } 
+            else { theme.error };
         
         Row::new(vec![
-            Cell::from(truncate_pubkey(&l.leader.to_string())).style(Style::default().fg(Color::White)),
-            Cell::from(format_number(l.slots_seen)).style(Style::default().fg(Color::Cyan)),
+            Cell::from(leader_label(state, &l.leader)).style(Style::default().fg(theme.text)),
+            Cell::from(format_number(l.slots_seen, state.locale)).style(Style::default().fg(theme.accent)),
             Cell::from(format!("{:.1}%", l.skip_rate())).style(Style::default().fg(skip_color)),
-            Cell::from(format_number(l.total_txns)).style(Style::default().fg(Color::Magenta)),
-            Cell::from(format!("{:.2}ms", l.avg_latency_ms)).style(Style::default().fg(Color::Yellow)),
-        ])
+            Cell::from(format_number(l.total_txns, state.locale)).style(Style::default().fg(theme.highlight)),
+            Cell::from(l.last_slot.map_or_else(|| "-".to_string(), |slot| slot.to_string())).style(Style::default().fg(theme.dim)),
+            Cell::from(format!("{:.2}ms", l.avg_latency_ms)).style(Style::default().fg(theme.warn)),
+            Cell::from(l.avg_first_shred_delay_ms().map_or_else(|| "-".to_string(), |ms| format!("{:+.1}ms", ms)))
+                .style(Style::default().fg(theme.warn)),
+            Cell::from(stake).style(Style::default().fg(theme.success)),
+            Cell::from(percentile).style(Style::default().fg(theme.label)),
+        ]).style(row_style(state, i, leaders.len()))
     }).collect();
 
+    // The leader column takes the spare width on wide terminals, for validator names
     let table = Table::new(rows, [
-        Constraint::Length(14),
+        Constraint::Min(14),
         Constraint::Length(10),
         Constraint::Length(10),
         Constraint::Length(12),
+        Constraint::Length(11),
         Constraint::Length(12),
+        Constraint::Length(11),
+        Constraint::Length(14),
+        Constraint::Length(6),
     ])
     .header(header)
-    .block(Block::default().title(" Leader Performance ").borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+    .block(Block::default().title(" Leader Performance ").borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
+
+    state.set_page_rows(table_page_rows(chunks[0]));
+    f.render_widget(table, chunks[0]);
 
-    f.render_widget(table, area);
+    // Slots are listed as they arrive; leaders fill in once the schedule covers them
+    let history = tracker.slot_history.read();
+    let items: Vec<ListItem> = history.iter().rev().take(OBSERVED_SLOT_ROWS).map(|s| {
+        let mut spans = vec![
+            Span::styled(s.slot.to_string(), Style::default().fg(theme.text)),
+            Span::raw(" │ "),
+            Span::styled(format!("{} txn", format_number(s.txn_count, state.locale)), Style::default().fg(theme.highlight)),
+            Span::raw(" │ "),
+            match &s.leader {
+                Some(leader) => Span::styled(leader_label(state, leader), Style::default().fg(theme.label)),
+                None => Span::styled("leader ?", Style::default().fg(theme.dim)),
+            },
+        ];
+        if s.skip {
+            spans.push(Span::raw(" │ "));
+            spans.push(Span::styled("skipped", Style::default().fg(theme.error)));
+        }
+        ListItem::new(Line::from(spans))
+    }).collect();
+
+    let title = if tracker.upcoming_leaders.read().is_empty() {
+        " Observed Slots (leaders need --rpc-url) "
+    } else {
+        " Observed Slots "
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
+    f.render_widget(List::new(items).block(block), chunks[1]);
 }
 
 // ============================================================================
 // Tab 5: Competition
 // ============================================================================
 
+fn draw_mev_index(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let history = state.mev_index.history.read();
+    let width = area.width.saturating_sub(2) as usize;
+    let skip = history.len().saturating_sub(width);
+    let data: Vec<u64> = history.iter().skip(skip).copied().collect();
+
+    let title = match history.back() {
+        Some(index) => format!(" MEV Index: {}/100 ", index),
+        None => " MEV Index: – ".to_string(),
+    };
+    let block = Block::default()
+        .title(Span::styled(title, Style::default().fg(theme.warn).add_modifier(Modifier::BOLD)))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
+
+    // Fixed scale so the sparkline height reads as the index itself
+    let sparkline = Sparkline::default()
+        .block(block)
+        .data(&data)
+        .max(100)
+        .style(Style::default().fg(theme.warn));
+
+    f.render_widget(sparkline, area);
+}
+
 fn draw_competition_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(10), Constraint::Min(5)])
+        .constraints([Constraint::Length(11), Constraint::Min(5)])
         .split(area);
 
     let competition = &state.competition_stats;
+    let land_rate = match competition.land_rate_pct() {
+        _ if !competition.track_landing => Line::from(vec![
+            Span::styled("Land rate: ", Style::default().fg(theme.label)),
+            Span::styled("needs --rpc-url", Style::default().fg(theme.dim)),
+        ]),
+        Some(pct) => Line::from(vec![
+            Span::styled("Land rate: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.1}%", pct), Style::default().fg(if pct >= 90.0 { theme.success } else { theme.warn })),
+            Span::styled(
+                format!(
+                    " ({} landed, {} failed, {} dropped)",
+                    format_number(competition.bundles_landed.load(Ordering::Relaxed), state.locale),
+                    format_number(competition.bundles_failed.load(Ordering::Relaxed), state.locale),
+                    format_number(competition.bundles_dropped.load(Ordering::Relaxed), state.locale),
+                ),
+                Style::default().fg(theme.dim),
+            ),
+        ]),
+        None => Line::from(vec![
+            Span::styled("Land rate: ", Style::default().fg(theme.label)),
+            Span::styled("–", Style::default().fg(theme.dim)),
+        ]),
+    };
 
     let text = vec![
-        Line::from(Span::styled("── Bundle Activity ──", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("── Bundle Activity ──", Style::default().fg(theme.warn).add_modifier(Modifier::BOLD))),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Total Bundles: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(competition.bundle_count.load(Ordering::Relaxed)), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled("Total Bundles: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(competition.bundle_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.warn).add_modifier(Modifier::BOLD)),
         ]),
         Line::from(vec![
-            Span::styled("Total Tips: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:.6} SOL", competition.total_tips_sol()), Style::default().fg(Color::Green)),
+            Span::styled("Total Tips: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{:.6} SOL", competition.total_tips_sol()), Style::default().fg(theme.success)),
         ]),
+        land_rate,
         Line::from(vec![
-            Span::styled("Duplicates: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(competition.duplicate_count.load(Ordering::Relaxed)), Style::default().fg(Color::Red)),
+            Span::styled("Duplicates: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(competition.duplicate_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.error)),
         ]),
         Line::from(vec![
-            Span::styled("Sandwiches: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(competition.sandwich_count.load(Ordering::Relaxed)), Style::default().fg(Color::Magenta)),
+            Span::styled("Sandwiches: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(competition.sandwich_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.highlight)),
         ]),
+        Line::from(vec![
+            Span::styled("Arbitrage: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(competition.arbitrage_count.load(Ordering::Relaxed), state.locale), Style::default().fg(theme.accent)),
+            Span::styled(" (multi-DEX txns)", Style::default().fg(theme.dim)),
+        ]),
+        match competition.priority_fee_summary() {
+            Some((avg, max, slots)) => Line::from(vec![
+                Span::styled("Avg priority fee: ", Style::default().fg(theme.label)),
+                Span::styled(format!("{} lamports", format_number(avg.round() as u64, state.locale)), Style::default().fg(theme.warn)),
+                Span::styled(
+                    format!(" (max {}, last {} slots)", format_number(max, state.locale), slots),
+                    Style::default().fg(theme.dim),
+                ),
+            ]),
+            None => Line::from(vec![
+                Span::styled("Avg priority fee: ", Style::default().fg(theme.label)),
+                Span::styled("–", Style::default().fg(theme.dim)),
+            ]),
+        },
     ];
 
     let block = Block::default()
         .title(" Competition Summary ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
-    f.render_widget(Paragraph::new(text).block(block), chunks[0]);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    f.render_widget(Paragraph::new(text).block(block), top[0]);
+    draw_mev_index(f, state, top[1]);
 
     // Recent bundles
-    let bundles = competition.bundles.read();
-    let items: Vec<ListItem> = bundles.iter().rev().take(15).map(|b| {
+    let live = competition.bundles.read();
+    let paused = state.paused.read();
+    let bundles = paused.as_ref().map_or(&*live, |f| &f.bundles);
+    let shown: Vec<&BundleInfo> = bundles.iter().rev().filter(|b| state.shows_bundle(b)).take(RECENT_BUNDLE_ROWS).collect();
+    let row_count = shown.len();
+    let items: Vec<ListItem> = shown.into_iter().enumerate().map(|(i, b)| {
         ListItem::new(Line::from(vec![
-            Span::styled(format!("Slot {}", b.slot), Style::default().fg(Color::White)),
+            Span::styled(format!("Slot {}", b.slot), Style::default().fg(theme.text)),
             Span::raw(" │ "),
-            Span::styled(format!("{} txns", b.txn_count), Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{} txns", b.txn_count), Style::default().fg(theme.accent)),
             Span::raw(" │ "),
-            Span::styled(format!("{:.6} SOL tip", b.tip_amount as f64 / 1e9), Style::default().fg(Color::Green)),
+            Span::styled(format!("{:.6} SOL tip", b.tip_amount as f64 / 1e9), Style::default().fg(theme.success)),
             Span::raw(" │ "),
-            Span::styled(b.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(Color::DarkGray)),
-        ]))
+            Span::styled(b.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(theme.dim)),
+        ])).style(row_style(state, i, row_count))
     }).collect();
 
+    let title = match state.active_tip_filter() {
+        Some(min) => format!(" Recent Bundles (tip ≥ {} SOL, t: show all) ", min as f64 / 1e9),
+        None if state.min_tip_lamports.is_some() => " Recent Bundles (t: filter by tip) ".to_string(),
+        None => " Recent Bundles ".to_string(),
+    };
     let bundles_block = Block::default()
-        .title(" Recent Bundles ")
+        .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(chunks[1]);
+
+    state.set_page_rows(list_page_rows(bottom[0]));
+    f.render_widget(List::new(items).block(bundles_block), bottom[0]);
 
-    f.render_widget(List::new(items).block(bundles_block), chunks[1]);
+    // Tip leaderboard
+    let header = Row::new(vec![
+        Cell::from("Tip Account").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Total SOL").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Bundles").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Max SOL").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+    ]);
+
+    let rows: Vec<Row> = competition.get_top_tip_accounts(TOP_TIP_ACCOUNT_ROWS).into_iter().map(|(account, stat)| {
+        Row::new(vec![
+            Cell::from(truncate_pubkey(&account)).style(Style::default().fg(theme.text)),
+            Cell::from(format!("{:.6}", stat.total_lamports as f64 / 1e9)).style(Style::default().fg(theme.success)),
+            Cell::from(format_number(stat.bundle_count, state.locale)).style(Style::default().fg(theme.warn)),
+            Cell::from(format!("{:.6}", stat.max_tip_lamports as f64 / 1e9)).style(Style::default().fg(theme.highlight)),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [
+        Constraint::Length(14),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(10),
+    ])
+    .header(header)
+    .block(Block::default().title(" Tip Leaderboard ").borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(TOP_TIP_ACCOUNT_ROWS as u16 + 3), Constraint::Min(3)])
+        .split(bottom[1]);
+
+    f.render_widget(table, right[0]);
+
+    // Recent duplicates, with the slot each signature first appeared in
+    let live = competition.duplicate_txns.read();
+    let duplicates = paused.as_ref().map_or(&*live, |f| &f.duplicates);
+    let items: Vec<ListItem> = duplicates.iter().rev().take(RECENT_DUPLICATE_ROWS).map(|d| {
+        let slot_style = if d.dup_slot == d.first_slot { theme.dim } else { theme.warn };
+        ListItem::new(Line::from(vec![
+            Span::styled(truncate_pubkey(&d.signature), Style::default().fg(theme.text)),
+            Span::raw(" │ "),
+            Span::styled(format!("{} → {}", d.first_slot, d.dup_slot), Style::default().fg(slot_style)),
+            Span::raw(" │ "),
+            Span::styled(d.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(theme.dim)),
+        ]))
+    }).collect();
+
+    let duplicates_block = Block::default()
+        .title(" Recent Duplicates ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
+
+    f.render_widget(List::new(items).block(duplicates_block), right[1]);
 }
 
 // ============================================================================
@@ -736,29 +1744,73 @@ fn draw_competition_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 // ============================================================================
 
 fn draw_logs_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
-    let logs = state.logs.read();
-    
+    let theme = &state.theme;
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(20), Constraint::Length(CONNECTION_TIMELINE_WIDTH)])
+        .split(area);
+    draw_connection_timeline(f, state, chunks[1]);
+    let area = chunks[0];
+
+    let live = state.logs.read();
+    let paused = state.paused.read();
+    let logs = paused.as_ref().map_or(&*live, |f| &f.logs);
+    let selected = state.selected_row_in(logs.len());
+
     let items: Vec<ListItem> = logs.iter().rev().map(|log| {
         let level_style = match log.level {
-            LogLevel::Info => Style::default().fg(Color::Cyan),
-            LogLevel::Warn => Style::default().fg(Color::Yellow),
-            LogLevel::Error => Style::default().fg(Color::Red),
-            LogLevel::Debug => Style::default().fg(Color::Gray),
+            LogLevel::Info => Style::default().fg(theme.accent),
+            LogLevel::Warn => Style::default().fg(theme.warn),
+            LogLevel::Error => Style::default().fg(theme.error),
+            LogLevel::Debug => Style::default().fg(theme.label),
         };
         
         ListItem::new(Line::from(vec![
-            Span::styled(log.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(Color::DarkGray)),
+            Span::styled(log.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(theme.dim)),
             Span::raw(" "),
             Span::styled(format!("[{}]", log.level), level_style),
             Span::raw(" "),
-            Span::styled(&log.message, Style::default().fg(Color::White)),
+            Span::styled(&log.message, Style::default().fg(theme.text)),
         ]))
     }).collect();
 
     let block = Block::default()
         .title(" Logs ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
+
+    // The list scrolls to keep the selected line in view
+    let list = List::new(items).block(block).highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    state.set_page_rows(list_page_rows(area));
+    f.render_stateful_widget(list, area, &mut ListState::default().with_selected(selected));
+}
+
+/// Connection state changes, newest first, with how long each state lasted
+fn draw_connection_timeline(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let items: Vec<ListItem> = state.connection_timeline().into_iter().map(|(at, conn_state, lasted)| {
+        let color = match conn_state {
+            ConnectionState::Connected => theme.success,
+            ConnectionState::Connecting | ConnectionState::Reconnecting => theme.warn,
+            ConnectionState::Disconnected => theme.label,
+            ConnectionState::Stalled | ConnectionState::Error(_) => theme.error,
+        };
+        let name = match conn_state {
+            ConnectionState::Error(_) => "Error".to_string(),
+            other => other.to_string(),
+        };
+        ListItem::new(Line::from(vec![
+            Span::styled(at.format("%H:%M:%S").to_string(), Style::default().fg(theme.dim)),
+            Span::raw(" "),
+            Span::styled(format!("{:<15}", name), Style::default().fg(color)),
+            Span::styled(format_duration(lasted), Style::default().fg(theme.text)),
+        ]))
+    }).collect();
+
+    let block = Block::default()
+        .title(" Connection Timeline ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dim));
 
     f.render_widget(List::new(items).block(block), area);
 }
@@ -768,65 +1820,119 @@ fn draw_logs_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 // ============================================================================
 
 fn draw_wallet_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
     let wallet = &state.wallet_monitor;
-    let wallet_addr = wallet.wallet.read();
+    let breakdown = wallet.breakdown();
+    let totals = wallet.totals();
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(8), Constraint::Min(5)])
+        .constraints([Constraint::Length(9), Constraint::Min(5)])
         .split(area);
 
-    let wallet_str = wallet_addr.map(|w| w.to_string()).unwrap_or_else(|| "Not configured".to_string());
-    let txn_count = wallet.txn_count.load(Ordering::Relaxed);
-    let success = wallet.success_count.load(Ordering::Relaxed);
-    let fail = wallet.fail_count.load(Ordering::Relaxed);
+    let top_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(WALLET_SUMMARY_WIDTH), Constraint::Min(40)])
+        .split(chunks[0]);
+
+    let wallets_str = match breakdown.len() {
+        0 => "Not configured".to_string(),
+        n => format!("{} watched", n),
+    };
 
     let text = vec![
         Line::from(vec![
-            Span::styled("Wallet: ", Style::default().fg(Color::Gray)),
-            Span::styled(&wallet_str, Style::default().fg(Color::Cyan)),
+            Span::styled("Wallets: ", Style::default().fg(theme.label)),
+            Span::styled(wallets_str, Style::default().fg(theme.accent)),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Transactions: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(txn_count), Style::default().fg(Color::White)),
+            Span::styled("Transactions: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(totals.txn_count, state.locale), Style::default().fg(theme.text)),
         ]),
         Line::from(vec![
-            Span::styled("Success: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(success), Style::default().fg(Color::Green)),
+            Span::styled("Success: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(totals.success_count, state.locale), Style::default().fg(theme.success)),
         ]),
         Line::from(vec![
-            Span::styled("Failed: ", Style::default().fg(Color::Gray)),
-            Span::styled(format_number(fail), Style::default().fg(Color::Red)),
+            Span::styled("Failed: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(totals.fail_count, state.locale), Style::default().fg(theme.error)),
         ]),
+        Line::from(vec![
+            Span::styled("Unknown: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(totals.unknown_count, state.locale), Style::default().fg(theme.dim)),
+        ]),
+        Line::from(Span::styled("(confirmed via --rpc-url when set)", Style::default().fg(theme.dim))),
     ];
 
     let block = Block::default()
         .title(" Wallet Monitor ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
-    f.render_widget(Paragraph::new(text).block(block), chunks[0]);
+    f.render_widget(Paragraph::new(text).block(block), top_chunks[0]);
+
+    // Per-wallet breakdown
+    let header = Row::new(vec![
+        Cell::from("Wallet").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("Txns").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("✓").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("✗").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        Cell::from("?").style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+    ]);
+
+    let rows: Vec<Row> = breakdown.iter().map(|(wallet, stats)| {
+        Row::new(vec![
+            Cell::from(truncate_pubkey(&wallet.to_string())).style(Style::default().fg(theme.text)),
+            Cell::from(format_number(stats.txn_count, state.locale)).style(Style::default().fg(theme.highlight)),
+            Cell::from(format_number(stats.success_count, state.locale)).style(Style::default().fg(theme.success)),
+            Cell::from(format_number(stats.fail_count, state.locale)).style(Style::default().fg(theme.error)),
+            Cell::from(format_number(stats.unknown_count, state.locale)).style(Style::default().fg(theme.dim)),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [
+        Constraint::Length(14),
+        Constraint::Length(10),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(8),
+    ])
+    .header(header)
+    .block(Block::default().title(" By Wallet (w: add/remove) ").borders(Borders::ALL).border_style(Style::default().fg(theme.dim)));
+
+    f.render_widget(table, top_chunks[1]);
 
     // Recent wallet transactions
-    let txns = wallet.transactions.read();
-    let items: Vec<ListItem> = txns.iter().rev().take(15).map(|t| {
+    let live = wallet.transactions.read();
+    let paused = state.paused.read();
+    let txns = paused.as_ref().map_or(&*live, |f| &f.wallet_txns);
+    let row_count = txns.len().min(WALLET_TXN_ROWS);
+    let items: Vec<ListItem> = txns.iter().rev().take(WALLET_TXN_ROWS).enumerate().map(|(i, t)| {
+        let (status, status_color) = match t.status {
+            TxnStatus::Success => ("✓", theme.success),
+            TxnStatus::Failed => ("✗", theme.error),
+            TxnStatus::Unknown => ("?", theme.dim),
+        };
         ListItem::new(Line::from(vec![
-            Span::styled(format!("Slot {}", t.slot), Style::default().fg(Color::White)),
+            Span::styled(format!("Slot {}", t.slot), Style::default().fg(theme.text)),
             Span::raw(" │ "),
-            Span::styled(truncate_pubkey(&t.signature), Style::default().fg(Color::Yellow)),
+            Span::styled(truncate_pubkey(&t.wallet.to_string()), Style::default().fg(theme.accent)),
             Span::raw(" │ "),
-            Span::styled(if t.success { "✓" } else { "✗" }, Style::default().fg(if t.success { Color::Green } else { Color::Red })),
+            Span::styled(truncate_pubkey(&t.signature), Style::default().fg(theme.warn)),
             Span::raw(" │ "),
-            Span::styled(t.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(Color::DarkGray)),
-        ]))
+            Span::styled(status, Style::default().fg(status_color)),
+            Span::raw(" │ "),
+            Span::styled(t.timestamp.format("%H:%M:%S").to_string(), Style::default().fg(theme.dim)),
+        ])).style(row_style(state, i, row_count))
     }).collect();
 
     let txns_block = Block::default()
         .title(" Recent Transactions ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(Style::default().fg(theme.dim));
 
+    state.set_page_rows(list_page_rows(chunks[1]));
     f.render_widget(List::new(items).block(txns_block), chunks[1]);
 }
 
@@ -834,67 +1940,511 @@ fn draw_wallet_tab(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
 // Footer & Help
 // ============================================================================
 
-fn draw_footer(f: &mut Frame, _state: &Arc<AppState>, area: Rect) {
-    let shortcuts = vec![
-        Span::styled(" q", Style::default().fg(Color::Yellow)),
-        Span::styled(" Quit ", Style::default().fg(Color::Gray)),
+fn draw_footer(f: &mut Frame, state: &Arc<AppState>, area: Rect) {
+    let theme = &state.theme;
+    let mut shortcuts = vec![
+        Span::styled(" q", Style::default().fg(theme.warn)),
+        Span::styled(" Quit ", Style::default().fg(theme.label)),
+        Span::raw("│"),
+        Span::styled(" ←/→", Style::default().fg(theme.warn)),
+        Span::styled(" Tab ", Style::default().fg(theme.label)),
+        Span::raw("│"),
+        Span::styled(" ↑/↓", Style::default().fg(theme.warn)),
+        Span::styled(" Scroll ", Style::default().fg(theme.label)),
         Span::raw("│"),
-        Span::styled(" ←/→", Style::default().fg(Color::Yellow)),
-        Span::styled(" Tab ", Style::default().fg(Color::Gray)),
+        Span::styled(" r", Style::default().fg(theme.warn)),
+        Span::styled(" Reset ", Style::default().fg(theme.label)),
         Span::raw("│"),
-        Span::styled(" ↑/↓", Style::default().fg(Color::Yellow)),
-        Span::styled(" Scroll ", Style::default().fg(Color::Gray)),
+        Span::styled(" s", Style::default().fg(theme.warn)),
+        Span::styled(" Snapshot ", Style::default().fg(theme.label)),
         Span::raw("│"),
-        Span::styled(" r", Style::default().fg(Color::Yellow)),
-        Span::styled(" Reset ", Style::default().fg(Color::Gray)),
+        Span::styled(" ?", Style::default().fg(theme.warn)),
+        Span::styled(" Help ", Style::default().fg(theme.label)),
         Span::raw("│"),
-        Span::styled(" ?", Style::default().fg(Color::Yellow)),
-        Span::styled(" Help ", Style::default().fg(Color::Gray)),
+        // Rounded up so it reads 1s, not 0s, in the last second before the reset
+        Span::styled(
+            format!(" window resets in {}s ", state.metrics_window_remaining().as_millis().div_ceil(1000)),
+            Style::default().fg(theme.dim),
+        ),
     ];
+    if state.is_paused() {
+        shortcuts.push(Span::raw("│"));
+        shortcuts.push(Span::styled(" ⏸ PAUSED ", Style::default().fg(theme.bg).bg(theme.warn).add_modifier(Modifier::BOLD)));
+    }
+    if let Some(notice) = state.current_notice() {
+        shortcuts.push(Span::raw("│"));
+        shortcuts.push(Span::styled(format!(" ✓ {} ", notice), Style::default().fg(theme.success).add_modifier(Modifier::BOLD)));
+    }
+    if let Some(alert) = state.active_alerts.read().last() {
+        shortcuts.push(Span::raw("│"));
+        shortcuts.push(Span::styled(
+            format!(" ⚠ {} since {} ", alert.message, alert.raised_at.format("%H:%M:%S")),
+            Style::default().fg(theme.error).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    // Newly raised alerts flash the whole footer
+    let flashing = state.alert_flash_ticks.load(Ordering::Relaxed) > 0;
+    let footer_style = if flashing { Style::default().bg(theme.error) } else { Style::default() };
+    let border_style = Style::default().fg(if flashing { theme.error } else { theme.dim });
 
     let footer = Paragraph::new(Line::from(shortcuts))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+        .style(footer_style)
+        .block(Block::default().borders(Borders::ALL).border_style(border_style));
 
     f.render_widget(footer, area);
 }
 
-fn draw_help_overlay(f: &mut Frame, _state: &Arc<AppState>) {
+fn draw_help_overlay(f: &mut Frame, state: &Arc<AppState>) {
+    let theme = &state.theme;
     let area = f.area();
     
     let popup_width = 60;
-    let popup_height = 18;
-    let popup_area = Rect::new(
-        (area.width.saturating_sub(popup_width)) / 2,
-        (area.height.saturating_sub(popup_height)) / 2,
-        popup_width.min(area.width),
-        popup_height.min(area.height),
-    );
+    let popup_height = 38;
+    let popup_area = centered_popup(area, popup_width, popup_height);
+    if popup_area.is_empty() {
+        return;
+    }
 
     f.render_widget(Clear, popup_area);
 
     let help_text = vec![
-        Line::from(Span::styled("Keyboard Shortcuts", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("Keyboard Shortcuts", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))),
         Line::from(""),
-        Line::from(vec![Span::styled("  q, Ctrl+C  ", Style::default().fg(Color::Yellow)), Span::raw("Quit")]),
-        Line::from(vec![Span::styled("  ←, →, Tab  ", Style::default().fg(Color::Yellow)), Span::raw("Switch tabs")]),
-        Line::from(vec![Span::styled("  ↑, ↓       ", Style::default().fg(Color::Yellow)), Span::raw("Scroll")]),
-        Line::from(vec![Span::styled("  r          ", Style::default().fg(Color::Yellow)), Span::raw("Reset metrics window")]),
-        Line::from(vec![Span::styled("  ?          ", Style::default().fg(Color::Yellow)), Span::raw("Toggle help")]),
+        Line::from(vec![Span::styled("  q, Ctrl+C  ", Style::default().fg(theme.warn)), Span::raw("Quit (q asks first with --confirm-quit)")]),
+        Line::from(vec![Span::styled("  ←, →, Tab  ", Style::default().fg(theme.warn)), Span::raw("Switch tabs")]),
+        Line::from(vec![Span::styled("  1-8        ", Style::default().fg(theme.warn)), Span::raw("Jump to tab")]),
+        Line::from(vec![Span::styled("  ↑, ↓       ", Style::default().fg(theme.warn)), Span::raw("Select row")]),
+        Line::from(vec![Span::styled("  PgUp, PgDn ", Style::default().fg(theme.warn)), Span::raw("Page up / down (Home, End: first / last row)")]),
+        Line::from(vec![Span::styled("  r          ", Style::default().fg(theme.warn)), Span::raw("Reset metrics window")]),
+        Line::from(vec![Span::styled("  R          ", Style::default().fg(theme.warn)), Span::raw("Reset all statistics")]),
+        Line::from(vec![Span::styled("  p          ", Style::default().fg(theme.warn)), Span::raw("Retry the proxy after giving up")]),
+        Line::from(vec![Span::styled("  s          ", Style::default().fg(theme.warn)), Span::raw("Export JSON snapshot")]),
+        Line::from(vec![Span::styled("  u          ", Style::default().fg(theme.warn)), Span::raw("Toggle rates per second / per slot")]),
+        Line::from(vec![Span::styled("  Space      ", Style::default().fg(theme.warn)), Span::raw("Pause / resume lists")]),
+        Line::from(vec![Span::styled("  y          ", Style::default().fg(theme.warn)), Span::raw("Copy selected pubkey / signature")]),
+        Line::from(vec![Span::styled("  i          ", Style::default().fg(theme.warn)), Span::raw("Show runtime configuration")]),
+        Line::from(vec![Span::styled("  w          ", Style::default().fg(theme.warn)), Span::raw("Add / remove a monitored wallet (Wallet tab)")]),
+        Line::from(vec![Span::styled("  n          ", Style::default().fg(theme.warn)), Span::raw("Label selected program (Programs tab)")]),
+        Line::from(vec![Span::styled("  /          ", Style::default().fg(theme.warn)), Span::raw("Search transactions by signature (Programs tab)")]),
+        Line::from(vec![Span::styled("  e          ", Style::default().fg(theme.warn)), Span::raw("Export programs to CSV (Programs tab)")]),
+        Line::from(vec![Span::styled("  o          ", Style::default().fg(theme.warn)), Span::raw("Cycle leader sort (Latency tab)")]),
+        Line::from(vec![Span::styled("  f          ", Style::default().fg(theme.warn)), Span::raw("Cycle leader region (Latency tab)")]),
+        Line::from(vec![Span::styled("  t          ", Style::default().fg(theme.warn)), Span::raw("Toggle bundle tip filter (Competition tab)")]),
+        Line::from(vec![Span::styled("  d          ", Style::default().fg(theme.warn)), Span::raw("Toggle DEX-only slot and transaction lists")]),
+        Line::from(vec![Span::styled("  Enter      ", Style::default().fg(theme.warn)), Span::raw("Show the selected program or bundle")]),
+        Line::from(vec![Span::styled("  c          ", Style::default().fg(theme.warn)), Span::raw("Counters all time / since connect (Overview tab)")]),
+        Line::from(vec![Span::styled("  +, -       ", Style::default().fg(theme.warn)), Span::raw("Redraw faster / slower")]),
+        Line::from(vec![Span::styled("  ?          ", Style::default().fg(theme.warn)), Span::raw("Toggle help")]),
         Line::from(""),
-        Line::from(Span::styled("Tabs", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
-        Line::from("  0: Overview   1: Latency   2: Turbine"),
-        Line::from("  3: Programs   4: Leaders   5: Competition"),
-        Line::from("  6: Logs       7: Wallet"),
+        Line::from(Span::styled("Tabs", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))),
+        Line::from("  1: Overview   2: Latency   3: Turbine"),
+        Line::from("  4: Programs   5: Leaders   6: Competition"),
+        Line::from("  7: Logs       8: Wallet"),
         Line::from(""),
-        Line::from(Span::styled("Press any key to close", Style::default().fg(Color::DarkGray))),
+        Line::from(Span::styled("Press any key to close", Style::default().fg(theme.dim))),
     ];
 
     let block = Block::default()
         .title(" Help ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
-        .style(Style::default().bg(Color::Black));
+        .border_style(Style::default().fg(theme.accent))
+        .style(Style::default().bg(theme.bg));
 
     f.render_widget(Paragraph::new(help_text).block(block), popup_area);
 }
+
+fn draw_text_input_overlay(f: &mut Frame, state: &Arc<AppState>, input: &TextInput) {
+    let theme = &state.theme;
+    let popup_area = centered_popup(f.area(), 60, 8);
+    if popup_area.is_empty() {
+        return;
+    }
+
+    f.render_widget(Clear, popup_area);
+
+    let (title, prompt, action) = match input.target {
+        InputTarget::Wallet => (" Monitor Wallet ".to_string(), "Wallet pubkey:", "monitor"),
+        InputTarget::ProgramLabel(program) => (format!(" Label {} ", truncate_pubkey(&program.to_string())), "Program label:", "save"),
+        InputTarget::SignatureSearch => (" Search Transactions ".to_string(), "Signature prefix (empty clears):", "search"),
+    };
+
+    let text = vec![
+        Line::from(Span::styled(prompt, Style::default().fg(theme.label))),
+        Line::from(vec![
+            Span::styled(input.text.as_str(), Style::default().fg(theme.text)),
+            Span::styled("█", Style::default().fg(theme.accent)),
+        ]),
+        Line::from(""),
+        match &input.error {
+            Some(error) => Line::from(Span::styled(error.as_str(), Style::default().fg(theme.error))),
+            None => Line::from(""),
+        },
+        Line::from(Span::styled(format!("Enter to {} · Esc to cancel", action), Style::default().fg(theme.dim))),
+    ];
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .style(Style::default().bg(theme.bg));
+
+    f.render_widget(Paragraph::new(text).block(block), popup_area);
+}
+
+/// Contents of the bundle detail popup: the exact tip, the full tip account and every signature
+fn bundle_detail_lines(bundle: &BundleInfo, state: &AppState) -> Vec<Line<'static>> {
+    let theme = &state.theme;
+    let tip_account = if bundle.tip_account.is_empty() { "–".to_string() } else { bundle.tip_account.clone() };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Slot: ", Style::default().fg(theme.label)),
+            Span::styled(bundle.slot.to_string(), Style::default().fg(theme.text)),
+            Span::styled(format!("  at {}", bundle.timestamp.format("%H:%M:%S")), Style::default().fg(theme.dim)),
+        ]),
+        Line::from(vec![
+            Span::styled("Tip: ", Style::default().fg(theme.label)),
+            Span::styled(format!("{} lamports", format_number(bundle.tip_amount, state.locale)), Style::default().fg(theme.success)),
+            Span::styled(format!(" ({:.9} SOL)", bundle.tip_amount as f64 / 1e9), Style::default().fg(theme.dim)),
+        ]),
+        Line::from(vec![
+            Span::styled("Tip account: ", Style::default().fg(theme.label)),
+            Span::styled(tip_account, Style::default().fg(theme.text)),
+        ]),
+        Line::from(vec![
+            Span::styled("Status: ", Style::default().fg(theme.label)),
+            Span::styled(bundle.status.to_string(), Style::default().fg(match bundle.status {
+                BundleStatus::Landed => theme.success,
+                BundleStatus::Failed | BundleStatus::Dropped => theme.error,
+                BundleStatus::Pending => theme.dim,
+            })),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Signatures ({}):", bundle.signatures.len()),
+            Style::default().fg(theme.label),
+        )),
+    ];
+    lines.extend(bundle.signatures.iter().map(|sig| Line::from(Span::styled(format!("  {}", sig), Style::default().fg(theme.accent)))));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Esc to close", Style::default().fg(theme.dim))));
+    lines
+}
+
+fn draw_bundle_detail_overlay(f: &mut Frame, state: &Arc<AppState>, bundle: &BundleInfo) {
+    let theme = &state.theme;
+    let text = bundle_detail_lines(bundle, state);
+    // Wide enough for a full signature
+    let popup_area = centered_popup(f.area(), 96, text.len() as u16 + 2);
+    if popup_area.is_empty() {
+        return;
+    }
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Bundle ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .style(Style::default().bg(theme.bg));
+
+    f.render_widget(Paragraph::new(text).block(block), popup_area);
+}
+
+/// Activity of one program: its totals and a sparkline of transactions per slot
+fn draw_program_detail_overlay(f: &mut Frame, state: &Arc<AppState>, program: &Pubkey) {
+    let theme = &state.theme;
+    let popup_area = centered_popup(f.area(), PROGRAM_SLOT_HISTORY as u16 + 4, 12);
+    if popup_area.is_empty() {
+        return;
+    }
+
+    f.render_widget(Clear, popup_area);
+
+    let activity = state.program_stats.activities.read().get(program).cloned();
+    let name = activity.as_ref().map_or_else(|| truncate_pubkey(&program.to_string()), |a| a.name.clone());
+    let block = Block::default()
+        .title(format!(" {} ", name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .style(Style::default().bg(theme.bg));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(3), Constraint::Length(1)])
+        .split(inner);
+
+    let current_slot = state.current_slot.load(Ordering::Relaxed);
+    let counts = state.program_stats.program_slot_counts(program, current_slot);
+    let active_slots = counts.iter().filter(|&&count| count > 0).count();
+    let (category, total) = activity.as_ref().map_or(("–".to_string(), 0), |a| (a.category.to_string(), a.txn_count));
+    let summary = vec![
+        Line::from(Span::styled(program.to_string(), Style::default().fg(theme.text))),
+        Line::from(vec![
+            Span::styled("Category: ", Style::default().fg(theme.label)),
+            Span::styled(category, Style::default().fg(theme.accent)),
+            Span::styled("  Txns: ", Style::default().fg(theme.label)),
+            Span::styled(format_number(total, state.locale), Style::default().fg(theme.text)),
+        ]),
+        Line::from(vec![
+            Span::styled(format!("Active in {} of the last {} slots", active_slots, PROGRAM_SLOT_HISTORY), Style::default().fg(theme.dim)),
+        ]),
+    ];
+    f.render_widget(Paragraph::new(summary), chunks[0]);
+
+    let sparkline = Sparkline::default()
+        .data(&counts)
+        .style(Style::default().fg(theme.success));
+    f.render_widget(sparkline, chunks[1]);
+
+    f.render_widget(Paragraph::new(Span::styled("Esc to close", Style::default().fg(theme.dim))), chunks[2]);
+}
+
+fn draw_quit_prompt(f: &mut Frame, state: &Arc<AppState>) {
+    let theme = &state.theme;
+    let popup_area = centered_popup(f.area(), 30, 5);
+    if popup_area.is_empty() {
+        return;
+    }
+
+    f.render_widget(Clear, popup_area);
+
+    let text = vec![
+        Line::from(Span::styled("Quit? (y/n)", Style::default().fg(theme.text).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(Span::styled("Any other key cancels", Style::default().fg(theme.dim))),
+    ];
+
+    let block = Block::default()
+        .title(" Quit ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.warn))
+        .style(Style::default().bg(theme.bg));
+
+    f.render_widget(Paragraph::new(text).alignment(Alignment::Center).block(block), popup_area);
+}
+
+fn draw_info_overlay(f: &mut Frame, state: &Arc<AppState>) {
+    let theme = &state.theme;
+    let area = f.area();
+
+    let wallets = state.wallet_monitor.breakdown();
+    let wallets = if wallets.is_empty() {
+        "none".to_string()
+    } else {
+        wallets.iter().map(|(w, _)| truncate_pubkey(&w.to_string())).collect::<Vec<_>>().join(", ")
+    };
+    let rows: Vec<(&str, String)> = state.runtime_config.iter()
+        .map(|(label, value)| (*label, value.clone()))
+        .chain(std::iter::once(("Wallets", wallets)))
+        .collect();
+
+    let popup_width = 70;
+    let popup_height = rows.len() as u16 + 6;
+    let popup_area = centered_popup(area, popup_width, popup_height);
+    if popup_area.is_empty() {
+        return;
+    }
+
+    f.render_widget(Clear, popup_area);
+
+    let mut info_text = vec![
+        Line::from(Span::styled("Runtime Configuration", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+    ];
+    for (label, value) in rows {
+        info_text.push(Line::from(vec![
+            Span::styled(format!("  {:<18}", label), Style::default().fg(theme.warn)),
+            Span::raw(value),
+        ]));
+    }
+    info_text.push(Line::from(""));
+    info_text.push(Line::from(Span::styled("Press any key to close", Style::default().fg(theme.dim))));
+
+    let block = Block::default()
+        .title(" Info ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .style(Style::default().bg(theme.bg));
+
+    f.render_widget(Paragraph::new(info_text).block(block), popup_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn test_state() -> Arc<AppState> {
+        Arc::new(AppState::new("http://127.0.0.1:50051".to_string()))
+    }
+
+    /// Draw every tab into a `width` x `height` buffer
+    fn draw_all_tabs(state: &Arc<AppState>, width: u16, height: u16) {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        for tab in 0..TAB_TITLES.len() {
+            state.set_tab(tab);
+            terminal.draw(|f| draw(f, state)).unwrap();
+        }
+    }
+
+    #[test]
+    fn tiny_terminals_render_without_panicking() {
+        let state = test_state();
+        state.add_slot(100, 2, 20, 1, 0);
+        for (width, height) in [(0, 0), (1, 1), (2, 2)] {
+            draw_all_tabs(&state, width, height);
+        }
+        // The smallest size that lays out the full UI, with an overlay on top
+        draw_all_tabs(&state, MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT);
+        *state.show_help.write() = true;
+        draw_all_tabs(&state, MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT);
+    }
+
+    #[test]
+    fn clicks_map_to_the_tab_drawn_under_them() {
+        let state = test_state();
+        let mut terminal = Terminal::new(TestBackend::new(160, MIN_TERMINAL_HEIGHT)).unwrap();
+        terminal.draw(|f| draw(f, &state)).unwrap();
+        let buffer = terminal.backend().buffer();
+
+        let row = HEADER_HEIGHT + 1;
+        let mut dividers = 0;
+        let mut last_divider = 0;
+        for column in 1..buffer.area.width - 1 {
+            let symbol = buffer[(column, row)].symbol();
+            if symbol == symbols::line::VERTICAL {
+                assert_eq!(tab_at(column, row), None, "divider at column {}", column);
+                dividers += 1;
+                last_divider = column;
+            } else if dividers < TAB_TITLES.len() - 1 {
+                assert_eq!(tab_at(column, row), Some(dividers), "column {} ({:?})", column, symbol);
+            }
+        }
+        assert_eq!(dividers, TAB_TITLES.len() - 1);
+        let last_title_width = Line::from(TAB_TITLES[TAB_TITLES.len() - 1]).width() as u16;
+        for column in last_divider + 1..=last_divider + last_title_width + 2 {
+            assert_eq!(tab_at(column, row), Some(TAB_TITLES.len() - 1), "column {}", column);
+        }
+        assert_eq!(tab_at(last_divider + last_title_width + 3, row), None);
+
+        assert_eq!(tab_at(0, row), None);
+        assert_eq!(tab_at(2, HEADER_HEIGHT - 1), None);
+        assert_eq!(tab_at(2, HEADER_HEIGHT + TABS_HEIGHT), None);
+        assert_eq!(tab_at(2, HEADER_HEIGHT), Some(0));
+    }
+
+    #[test]
+    fn txn_sample_renders_slot_signature_bundle_and_programs() {
+        let state = test_state();
+        let theme = &state.theme;
+        let mut sample = TxnSample {
+            slot: 4242,
+            signature: "5KtPn1LGuxhFiwjxErkxTb7XxtLVYUBe6Cn33ej7ATNK".to_string(),
+            received_at: chrono::Local::now(),
+            programs: vec!["Raydium V4".to_string(), "Jupiter".to_string()],
+            is_dex: true,
+            is_bundle: false,
+            tip_amount: None,
+        };
+        let contents = |line: &Line| line.spans.iter().map(|s| s.content.to_string()).collect::<Vec<_>>();
+
+        let line = format_txn_sample(&sample, &state);
+        assert_eq!(contents(&line), vec!["4242", " │ ", "5KtPn1..ATNK", " │ ", "Raydium V4, Jupiter"]);
+        assert_eq!(line.spans[2].style.fg, Some(theme.accent));
+
+        sample.is_bundle = true;
+        sample.tip_amount = Some(1_500_000);
+        sample.programs.clear();
+        let line = format_txn_sample(&sample, &state);
+        assert_eq!(contents(&line), vec!["4242", " │ ", "5KtPn1..ATNK", " │ ", "bundle 0.001500 SOL", " │ ", "–"]);
+        assert_eq!(line.spans[4].style.fg, Some(theme.success));
+
+        // A search prefix is split out and highlighted
+        *state.signature_search.write() = Some("5Kt".to_string());
+        let line = format_txn_sample(&sample, &state);
+        assert_eq!(contents(&line)[2..4], ["5Kt".to_string(), "Pn1..ATNK".to_string()]);
+        assert_eq!(line.spans[2].style.fg, Some(theme.highlight));
+    }
+
+    #[test]
+    fn leader_label_prefers_the_validator_name() {
+        let (named, unnamed) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let state = AppState::new("http://127.0.0.1:50051".to_string());
+        state.leader_tracker.validator_names.write().insert(named, "Example Validator".to_string());
+
+        assert_eq!(leader_label(&state, &named), "Example Validator");
+        let key = unnamed.to_string();
+        assert_eq!(leader_label(&state, &unnamed), format!("{}..{}", &key[..6], &key[key.len() - 4..]));
+    }
+
+    #[test]
+    fn recent_slots_show_the_slot_leader() {
+        let state = test_state();
+        let leader = Pubkey::new_unique();
+        state.leader_tracker.set_schedule(vec![(1_000, leader)]);
+        state.add_slot(1_000, 4, 12, 0, 0);
+        state.add_slot(1_001, 4, 12, 0, 0);
+
+        let mut terminal = Terminal::new(TestBackend::new(200, 60)).unwrap();
+        state.set_tab(0);
+        terminal.draw(|f| draw(f, &state)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        let row_for = |slot: &str| rows.iter().find(|row| row.contains(&format!("{} │ 4 ent", slot))).cloned().unwrap();
+
+        assert!(row_for("1000").contains(&truncate_pubkey(&leader.to_string())));
+        assert!(row_for("1001").contains("leader ?"));
+    }
+
+    #[test]
+    fn bundle_popup_lists_every_signature_and_the_exact_tip() {
+        let state = test_state();
+        let signatures = ["5KtPn1LGuxhFiwjxErkxTb7XxtLVYUBe6Cn33ej7ATNK", "3yZe7d8Wq4hBN1nnSsxuVyfJ7zXfyjHfLbk4J1dXDjFq"];
+        let tip_account = "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5";
+        let bundle = BundleInfo {
+            slot: 4242,
+            txn_count: 2,
+            tip_amount: 1_234_567,
+            tip_account: tip_account.to_string(),
+            signatures: signatures.iter().map(|sig| sig.to_string()).collect(),
+            timestamp: chrono::Local::now(),
+            status: BundleStatus::Landed,
+        };
+        let text: Vec<String> = bundle_detail_lines(&bundle, &state).iter().map(|line| line.to_string()).collect();
+
+        assert!(text[0].starts_with("Slot: 4242"));
+        assert!(text[1].starts_with("Tip: 1,234,567 lamports (0.001234567 SOL)"));
+        assert_eq!(text[2], format!("Tip account: {}", tip_account));
+        assert_eq!(text[3], "Status: landed");
+        let listed = text.iter().position(|line| line == "Signatures (2):").unwrap();
+        assert_eq!(text[listed + 1..listed + 3], [format!("  {}", signatures[0]), format!("  {}", signatures[1])]);
+        assert_eq!(text.last().unwrap(), "Esc to close");
+
+        // A bundle whose tip account wasn't identified
+        let unknown = BundleInfo { tip_account: String::new(), ..bundle };
+        assert_eq!(bundle_detail_lines(&unknown, &state)[2].to_string(), "Tip account: –");
+    }
+
+    #[test]
+    fn ten_by_three_terminal_shows_the_too_small_message() {
+        let state = test_state();
+        state.add_slot(100, 2, 20, 1, 0);
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        for tab in 0..TAB_TITLES.len() {
+            state.set_tab(tab);
+            terminal.draw(|f| draw(f, &state)).unwrap();
+        }
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..3).flat_map(|y| (0..10).map(move |x| (x, y))).map(|pos| buffer[pos].symbol().to_string()).collect();
+        assert!(text.contains("Terminal"), "{:?}", text);
+    }
+}